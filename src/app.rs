@@ -1,35 +1,157 @@
-use crossterm::event::{KeyCode, KeyEvent};
-use matematika_rs::sistem::aljabar::*;
+use crate::editor::{EditorMatriks, IsianIteratif, KalkulatorMatriks, MAX_BARIS, MAX_DATA};
+use crate::grafik::{GarisBilangan, Grafik};
+use crate::solver::daerah::{Daerah, daerah_proses, huruf};
+use crate::solver::dekomposisi::{HasilDekomposisi, JenisDekomposisi, dekomposisi_proses};
+use crate::solver::eigen::{HasilEigen, eigen_proses, periksa_ukuran, vektor_teks};
+use crate::solver::ekspresi::{
+    catat_hampiran, hitung, hitung_parameter, karakter_valid, sistem_linear, sistem_substitusi,
+};
+use crate::solver::format::{Isi, Teks, nilai_akhir};
+use crate::solver::gauss::{JenisSolusi, gauss_proses};
+use crate::solver::iteratif::{Konvergensi, ParameterIteratif, hampiran, iteratif_proses};
+use crate::solver::kalkulator::{HasilMatriks, OperasiMatriks, kalkulator_proses};
+use crate::solver::kondisi::{Kondisi, kondisi_matriks, kondisi_sistem};
+use crate::solver::kuadrat::{AkarKuadrat, kuadrat_proses};
+use crate::solver::kuadrat_terkecil::{
+    KuadratTerkecil, kuadrat_terkecil_proses, norma, persamaan_normal,
+};
+use crate::solver::matriks::{Kurung, tulis as tulis_matriks};
+use crate::solver::metode::Metode;
+use crate::solver::parameter::{Polinom, spldv_parameter_proses};
+use crate::solver::pecahan::{GalatHitung, Hitung, Pecahan, Tampilan};
+use crate::solver::pertidaksamaan::{Himpunan, Relasi, ptlsv_proses};
+use crate::solver::program_linear::{Optimum, nama_variabel, program_linear_proses};
+use crate::solver::regresi::{Regresi, regresi_proses};
+use crate::solver::ruang::{Ruang, ruang_proses, span};
+use crate::solver::spldv::spldv_proses;
+use crate::solver::splkdv::{Perpotongan, Titik, splkdv_proses};
+use crate::solver::splsv::splsv_proses;
+use crate::solver::spltv::spltv_proses;
+use crate::solver::substitusi::{Solusi, substitusi_proses, variabel_asal};
+use crossterm::event::{KeyCode, KeyEvent, KeyModifiers};
 use std::time::{Duration, Instant};
 
 #[derive(Clone, PartialEq)]
 pub enum AppState {
     Menu,
-    InputSPLDV([String; 6], usize),
+    InputSPLDV([String; 6], usize, Metode),
     InputSPLSV([String; 2], usize),
-    Result(String),
+    InputKuadrat([String; 3], usize),
+    InputSPLKDV([String; 6], usize),
+    InputSPLTV([String; 12], usize, Metode),
+    InputPTLSV([String; 2], usize, Relasi),
+    InputSPTLDV(EditorMatriks, Vec<Relasi>),
+    InputPL(EditorMatriks, Vec<Relasi>, bool),
+    InputGauss(EditorMatriks),
+    InputTeks(Vec<String>, usize),
+    InputSubstitusi(Vec<String>, usize),
+    InputMatriks(KalkulatorMatriks),
+    InputKuadratTerkecil(EditorMatriks),
+    InputRegresi(EditorMatriks, String, bool),
+    InputIteratif(IsianIteratif),
+    InputDekomposisi(EditorMatriks, JenisDekomposisi),
+    InputRuang(EditorMatriks),
+    InputEigen(EditorMatriks),
+    KonfirmasiTeks(Vec<String>),
+    Result(Hasil),
     Exit,
 }
 
+/// Teks hasil perhitungan dalam tampilan pecahan dan desimal,
+/// beserta grafik atau garis bilangan jika hasilnya bisa digambarkan
+#[derive(Clone, PartialEq)]
+pub struct Hasil {
+    pub pecahan: Isi,
+    pub desimal: Isi,
+    pub tampil_desimal: bool,
+    pub grafik: Option<Grafik>,
+    pub garis_bilangan: Option<GarisBilangan>,
+    /// Lencana peringatan di atas langkah, mis. sistem berkondisi buruk
+    pub peringatan: Option<String>,
+}
+
+impl Hasil {
+    pub fn baru(teks: Teks) -> Self {
+        Self {
+            pecahan: teks.pecahan,
+            desimal: teks.desimal,
+            tampil_desimal: false,
+            grafik: None,
+            garis_bilangan: None,
+            peringatan: None,
+        }
+    }
+
+    pub fn dengan_grafik(self, grafik: Grafik) -> Self {
+        Self {
+            grafik: Some(grafik),
+            ..self
+        }
+    }
+
+    pub fn dengan_peringatan(self, peringatan: Option<String>) -> Self {
+        Self { peringatan, ..self }
+    }
+
+    /// Hasil dari koefisien yang hanya didekati (mis. √2 atau π): pecahan
+    /// pendekatannya tidak ditampilkan seolah-olah eksak, hanya desimalnya
+    pub fn dengan_hampiran(self) -> Self {
+        let mut desimal = Isi::default();
+        desimal.push_str(
+            "≈ Sebagian koefisien (mis. √2 atau π) hanya dapat didekati,\n  \
+             sehingga semua nilai di bawah adalah hampiran desimal.\n\n",
+        );
+        desimal.sambung(&self.desimal);
+        Self {
+            pecahan: desimal.clone(),
+            desimal,
+            tampil_desimal: true,
+            ..self
+        }
+    }
+
+    pub fn dengan_garis_bilangan(self, garis_bilangan: GarisBilangan) -> Self {
+        Self {
+            garis_bilangan: Some(garis_bilangan),
+            ..self
+        }
+    }
+
+    /// Hasil berupa teks biasa yang sama untuk kedua tampilan
+    pub fn teks(teks: &str) -> Self {
+        Self::baru(Teks::from(teks))
+    }
+
+    pub fn isi(&self) -> &Isi {
+        if self.tampil_desimal {
+            &self.desimal
+        } else {
+            &self.pecahan
+        }
+    }
+}
+
 /// Application state and logic
 pub struct App {
     pub state: AppState,
+    pub result_scroll: u16,
     last_key_time: Instant,
     debounce_duration: Duration,
 }
 
 impl App {
-    pub fn new(splsv: bool, spldv: bool, hasil: bool) -> Self {
+    pub fn new(splsv: bool, spldv: bool, spltv: bool, hasil: bool) -> Self {
         let state = if splsv {
             println!("Running with [--splsv]");
             AppState::InputSPLSV(std::array::from_fn(|_| "".to_string()), 0)
         } else if spldv {
-            AppState::InputSPLDV(std::array::from_fn(|_| "".to_string()), 0)
+            AppState::InputSPLDV(std::array::from_fn(|_| "".to_string()), 0, Metode::Campuran)
+        } else if spltv {
+            AppState::InputSPLTV(std::array::from_fn(|_| "".to_string()), 0, Metode::Campuran)
         } else if hasil {
-            AppState::Result(
-                "Tidak ada hasil dari flag [--hasil]. Gunakan no-flag atau [--splsv/--spldv]"
-                    .to_string(),
-            )
+            AppState::Result(Hasil::teks(
+                "Tidak ada hasil dari flag [--hasil]. Gunakan no-flag atau [--splsv/--spldv/--spltv]",
+            ))
         } else {
             println!("Running default.");
             AppState::Menu
@@ -37,6 +159,7 @@ impl App {
 
         Self {
             state,
+            result_scroll: 0,
             last_key_time: Instant::now(),
             #[cfg(target_os = "windows")]
             debounce_duration: Duration::from_millis(175),
@@ -53,130 +176,1544 @@ impl App {
 
         self.last_key_time = now;
 
+        // Galat hitung di tengah penyelesaian membuat seluruh hasilnya tidak berlaku
+        let (hasil, hampiran) = catat_hampiran(|| self.handle_key(key_event));
+        match &self.state {
+            AppState::Result(h) if hampiran => {
+                self.state = AppState::Result(h.clone().dengan_hampiran())
+            }
+            _ => {}
+        }
+        let Err(galat) = hasil else {
+            return;
+        };
+        let mut teks = match galat {
+            GalatHitung::TerlaluBesar => String::from(
+                "Perhitungan gagal: angka terlalu besar.\n\n\
+                 Hasil antara melampaui batas bilangan bulat 128-bit sehingga\n\
+                 pecahan eksaknya tidak dapat disimpan. Coba perkecil koefisien\n\
+                 atau kurangi angka di belakang koma.",
+            ),
+            GalatHitung::BagiNol => String::from(
+                "Perhitungan gagal: pembagian dengan nol.\n\n\
+                 Salah satu langkah penyelesaian membagi dengan nol sehingga\n\
+                 hasilnya tidak terdefinisi. Periksa kembali koefisiennya.",
+            ),
+        };
+        if hampiran && galat == GalatHitung::TerlaluBesar {
+            teks.push_str(
+                "\n\nKoefisien seperti √2 atau π didekati dengan pecahan\n\
+                     berpenyebut hingga satu juta, sehingga cepat membesar.\n\
+                     Coba tulis hampiran desimalnya yang lebih pendek, mis. 1.414.",
+            );
+        }
+        self.state = AppState::Result(Hasil::teks(&teks));
+    }
+
+    fn handle_key(&mut self, key_event: KeyEvent) -> Hitung<()> {
         match self.state.clone() {
             AppState::Menu => self.handle_menu_key(key_event.code),
-            AppState::InputSPLDV(inputs, selected) => {
-                self.handle_spldv_key(key_event.code, inputs, selected)
+            AppState::InputSPLDV(inputs, selected, metode) => {
+                self.handle_spldv_key(key_event.code, inputs, selected, metode)
             }
             AppState::InputSPLSV(inputs, selected) => {
                 self.handle_splsv_key(key_event.code, inputs, selected)
             }
-            AppState::Result(_) => self.handle_result_key(key_event.code),
-            AppState::Exit => {}
+            AppState::InputKuadrat(inputs, selected) => {
+                self.handle_kuadrat_key(key_event.code, inputs, selected)
+            }
+            AppState::InputSPLKDV(inputs, selected) => {
+                self.handle_splkdv_key(key_event.code, inputs, selected)
+            }
+            AppState::InputSPLTV(inputs, selected, metode) => {
+                self.handle_spltv_key(key_event.code, inputs, selected, metode)
+            }
+            AppState::InputPTLSV(inputs, selected, relasi) => {
+                self.handle_ptlsv_key(key_event.code, inputs, selected, relasi)
+            }
+            AppState::InputSPTLDV(editor, relasi) => {
+                self.handle_sptldv_key(key_event, editor, relasi)
+            }
+            AppState::InputPL(editor, relasi, maksimum) => {
+                self.handle_pl_key(key_event, editor, relasi, maksimum)
+            }
+            AppState::InputGauss(editor) => self.handle_gauss_key(key_event, editor),
+            AppState::InputTeks(baris, selected) => {
+                self.handle_teks_key(key_event, baris, selected)
+            }
+            AppState::KonfirmasiTeks(baris) => {
+                self.handle_konfirmasi_teks_key(key_event.code, baris)
+            }
+            AppState::InputSubstitusi(baris, selected) => {
+                self.handle_substitusi_key(key_event, baris, selected)
+            }
+            AppState::InputMatriks(kalkulator) => self.handle_matriks_key(key_event, kalkulator),
+            AppState::InputKuadratTerkecil(editor) => {
+                self.handle_kuadrat_terkecil_key(key_event, editor)
+            }
+            AppState::InputRegresi(editor, prediksi, di_prediksi) => {
+                self.handle_regresi_key(key_event, editor, prediksi, di_prediksi)
+            }
+            AppState::InputIteratif(isian) => self.handle_iteratif_key(key_event, isian),
+            AppState::InputDekomposisi(editor, jenis) => {
+                self.handle_dekomposisi_key(key_event, editor, jenis)
+            }
+            AppState::InputRuang(editor) => self.handle_ruang_key(key_event, editor),
+            AppState::InputEigen(editor) => self.handle_eigen_key(key_event, editor),
+            AppState::Result(result) => self.handle_result_key(key_event.code, result),
+            AppState::Exit => Ok(()),
         }
     }
 
-    fn handle_menu_key(&mut self, key: KeyCode) {
+    fn handle_menu_key(&mut self, key: KeyCode) -> Hitung<()> {
         match key {
             KeyCode::Char('1') => {
                 self.state = AppState::InputSPLSV(std::array::from_fn(|_| "".to_string()), 0)
             }
             KeyCode::Char('2') => {
-                self.state = AppState::InputSPLDV(std::array::from_fn(|_| "".to_string()), 0)
+                self.state = AppState::InputSPLDV(
+                    std::array::from_fn(|_| "".to_string()),
+                    0,
+                    Metode::Campuran,
+                )
+            }
+            KeyCode::Char('3') => {
+                self.state = AppState::InputSPLTV(
+                    std::array::from_fn(|_| "".to_string()),
+                    0,
+                    Metode::Campuran,
+                )
+            }
+            KeyCode::Char('4') => self.state = AppState::InputGauss(EditorMatriks::new(3, 4, true)),
+            KeyCode::Char('5') => self.state = AppState::InputTeks(vec![String::new(); 2], 0),
+            KeyCode::Char('6') => {
+                self.state =
+                    AppState::InputPTLSV(std::array::from_fn(|_| "".to_string()), 0, Relasi::Kurang)
+            }
+            KeyCode::Char('7') => {
+                self.state = AppState::InputSPTLDV(
+                    EditorMatriks::new(2, 3, true),
+                    vec![Relasi::KurangSama; 2],
+                )
+            }
+            KeyCode::Char('8') => {
+                self.state = AppState::InputPL(
+                    EditorMatriks::new(3, 3, true),
+                    vec![Relasi::KurangSama; 2],
+                    true,
+                )
+            }
+            KeyCode::Char('9') => {
+                self.state = AppState::InputKuadrat(std::array::from_fn(|_| "".to_string()), 0)
+            }
+            KeyCode::Char('0') => {
+                self.state = AppState::InputSPLKDV(std::array::from_fn(|_| "".to_string()), 0)
+            }
+            KeyCode::Char('a') | KeyCode::Char('A') => {
+                self.state = AppState::InputSubstitusi(vec![String::new(); 2], 0)
+            }
+            KeyCode::Char('b') | KeyCode::Char('B') => {
+                self.state = AppState::InputMatriks(KalkulatorMatriks::default())
+            }
+            KeyCode::Char('c') | KeyCode::Char('C') => {
+                self.state = AppState::InputKuadratTerkecil(EditorMatriks::new(4, 3, true))
+            }
+            KeyCode::Char('d') | KeyCode::Char('D') => {
+                self.state =
+                    AppState::InputRegresi(EditorMatriks::new(5, 2, false), String::new(), false)
+            }
+            KeyCode::Char('e') | KeyCode::Char('E') => {
+                self.state = AppState::InputIteratif(IsianIteratif::default())
+            }
+            KeyCode::Char('f') | KeyCode::Char('F') => {
+                self.state =
+                    AppState::InputDekomposisi(EditorMatriks::new(3, 4, true), JenisDekomposisi::Lu)
+            }
+            KeyCode::Char('g') | KeyCode::Char('G') => {
+                self.state = AppState::InputRuang(EditorMatriks::new(3, 4, false))
+            }
+            KeyCode::Char('h') | KeyCode::Char('H') => {
+                self.state = AppState::InputEigen(EditorMatriks::new(2, 2, false))
             }
             KeyCode::Char('q') | KeyCode::Char('Q') => self.state = AppState::Exit,
             _ => {}
         }
+        Ok(())
     }
 
-    fn handle_spldv_key(&mut self, key: KeyCode, mut inputs: [String; 6], selected: usize) {
-        match key {
-            KeyCode::Char('q') | KeyCode::Char('Q') => self.state = AppState::Exit,
-            KeyCode::Char(c) if c.is_ascii_digit() || c == '-' || c == '.' => {
-                inputs[selected].push(c);
-                self.state = AppState::InputSPLDV(inputs, selected);
-            }
-            KeyCode::Backspace => {
-                inputs[selected].pop();
-                self.state = AppState::InputSPLDV(inputs, selected);
+    fn handle_spldv_key(
+        &mut self,
+        key: KeyCode,
+        mut inputs: [String; 6],
+        mut selected: usize,
+        metode: Metode,
+    ) -> Hitung<()> {
+        match (edit_fields(&mut inputs, &mut selected, key), key) {
+            (Suntingan::Keluar, _) => self.state = AppState::Exit,
+            (Suntingan::Berubah, _) => self.state = AppState::InputSPLDV(inputs, selected, metode),
+            (_, KeyCode::Enter) => match parse_pecahan(&inputs) {
+                Ok(k) => {
+                    let k: [Pecahan; 6] = std::array::from_fn(|i| k[i]);
+                    let (jenis, titik, umum, mut steps) = spldv_proses(k, metode)?;
+                    let m = vec![k[..3].to_vec(), k[3..].to_vec()];
+                    let kondisi = titik.and_then(|(x, y)| kondisi_sistem(&m, &[x, y]));
+                    steps.tulis(|t| match titik {
+                        Some((x, y)) => format!(
+                            "{}\n{}",
+                            langkah_kondisi(&kondisi, t),
+                            hasil_akhir(&[("x", x), ("y", y)], t)
+                        ),
+                        None => format!("\n{}", tanpa_solusi_tunggal(jenis, umum.as_ref(), t)),
+                    });
+                    self.state = AppState::Result(
+                        Hasil::baru(steps)
+                            .dengan_grafik(Grafik::spldv(k, jenis, titik))
+                            .dengan_peringatan(peringatan_kondisi(&kondisi)),
+                    );
+                }
+                // Koefisien yang memuat parameter k atau m dianalisis per kasus
+                Err(_) => match parse_parameter(&inputs) {
+                    Ok((nama, k)) => {
+                        let (kasus, mut steps) = spldv_parameter_proses(k, &nama)?;
+                        steps.tulis(|t| format!("\n{}", parameter_akhir(&kasus, t)));
+                        self.state = AppState::Result(Hasil::baru(steps));
+                    }
+                    // Tetap di layar input dan pindah ke isian pertama yang tidak valid
+                    Err(i) => self.state = AppState::InputSPLDV(inputs, i, metode),
+                },
+            },
+            (_, KeyCode::Tab | KeyCode::BackTab) => {
+                let metode = metode.geser(&Metode::SPLDV, key == KeyCode::BackTab);
+                self.state = AppState::InputSPLDV(inputs, selected, metode);
             }
-            KeyCode::Left => {
-                let new_selected = if selected > 0 { selected - 1 } else { selected };
-                self.state = AppState::InputSPLDV(inputs, new_selected);
+            (_, KeyCode::Esc) => self.state = AppState::Menu,
+            _ => {}
+        }
+        Ok(())
+    }
+
+    fn handle_splsv_key(
+        &mut self,
+        key: KeyCode,
+        mut inputs: [String; 2],
+        mut selected: usize,
+    ) -> Hitung<()> {
+        match (edit_fields(&mut inputs, &mut selected, key), key) {
+            (Suntingan::Keluar, _) => self.state = AppState::Exit,
+            (Suntingan::Berubah, _) => self.state = AppState::InputSPLSV(inputs, selected),
+            (_, KeyCode::Enter) => match parse_pecahan(&inputs) {
+                Ok(k) => {
+                    let (a, b) = (k[0], k[1]);
+                    let (jenis, result, mut steps) = splsv_proses(a, b)?;
+                    let garis_bilangan = match (jenis, result) {
+                        (_, Some(x)) => GarisBilangan::titik(x),
+                        (JenisSolusi::TakHingga, _) => GarisBilangan::semua(),
+                        _ => GarisBilangan::kosong(),
+                    };
+                    steps.tulis(|t| match result {
+                        Some(x) => format!("\n{}", hasil_akhir(&[("x", x)], t)),
+                        None => format!("\n{}", tanpa_solusi_tunggal(jenis, None, t)),
+                    });
+                    self.state =
+                        AppState::Result(Hasil::baru(steps).dengan_garis_bilangan(garis_bilangan));
+                }
+                // Tetap di layar input dan pindah ke isian pertama yang tidak valid
+                Err(i) => self.state = AppState::InputSPLSV(inputs, i),
+            },
+            (_, KeyCode::Esc) => self.state = AppState::Menu,
+            _ => {}
+        }
+        Ok(())
+    }
+
+    fn handle_kuadrat_key(
+        &mut self,
+        key: KeyCode,
+        mut inputs: [String; 3],
+        mut selected: usize,
+    ) -> Hitung<()> {
+        match (edit_fields(&mut inputs, &mut selected, key), key) {
+            (Suntingan::Keluar, _) => self.state = AppState::Exit,
+            (Suntingan::Berubah, _) => self.state = AppState::InputKuadrat(inputs, selected),
+            (_, KeyCode::Enter) => match parse_pecahan(&inputs) {
+                Ok(k) => {
+                    let (a, b, c) = (k[0], k[1], k[2]);
+                    let (akar, mut steps) = kuadrat_proses(a, b, c)?;
+                    steps.coba_tulis(|t| Ok(format!("\n{}", kuadrat_akhir(akar, t)?)))?;
+                    self.state = AppState::Result(
+                        Hasil::baru(steps).dengan_grafik(Grafik::parabola([a, b, c], akar.real())),
+                    );
+                }
+                // Tetap di layar input dan pindah ke isian pertama yang tidak valid
+                Err(i) => self.state = AppState::InputKuadrat(inputs, i),
+            },
+            (_, KeyCode::Esc) => self.state = AppState::Menu,
+            _ => {}
+        }
+        Ok(())
+    }
+
+    fn handle_splkdv_key(
+        &mut self,
+        key: KeyCode,
+        mut inputs: [String; 6],
+        mut selected: usize,
+    ) -> Hitung<()> {
+        match (edit_fields(&mut inputs, &mut selected, key), key) {
+            (Suntingan::Keluar, _) => self.state = AppState::Exit,
+            (Suntingan::Berubah, _) => self.state = AppState::InputSPLKDV(inputs, selected),
+            (_, KeyCode::Enter) => match parse_pecahan(&inputs) {
+                Ok(k) => {
+                    let k1 = [k[0], k[1], k[2]];
+                    let k2 = [k[3], k[4], k[5]];
+                    let (perpotongan, titik, mut steps) = splkdv_proses(k1, k2)?;
+                    steps.coba_tulis(|t| {
+                        Ok(format!("\n{}", splkdv_akhir(perpotongan, &titik, t)?))
+                    })?;
+                    self.state =
+                        AppState::Result(Hasil::baru(steps).dengan_grafik(Grafik::splkdv(
+                            [k1, k2],
+                            perpotongan,
+                            &titik,
+                        )));
+                }
+                // Tetap di layar input dan pindah ke isian pertama yang tidak valid
+                Err(i) => self.state = AppState::InputSPLKDV(inputs, i),
+            },
+            (_, KeyCode::Esc) => self.state = AppState::Menu,
+            _ => {}
+        }
+        Ok(())
+    }
+
+    fn handle_ptlsv_key(
+        &mut self,
+        key: KeyCode,
+        mut inputs: [String; 2],
+        mut selected: usize,
+        relasi: Relasi,
+    ) -> Hitung<()> {
+        match (edit_fields(&mut inputs, &mut selected, key), key) {
+            (Suntingan::Keluar, _) => self.state = AppState::Exit,
+            (Suntingan::Berubah, _) => self.state = AppState::InputPTLSV(inputs, selected, relasi),
+            (_, KeyCode::Enter) => match parse_pecahan(&inputs) {
+                Ok(k) => {
+                    let (a, b) = (k[0], k[1]);
+                    let (himpunan, mut steps) = ptlsv_proses(a, b, relasi)?;
+                    steps.tulis(|t| format!("\n{}", himpunan_akhir(himpunan, t)));
+                    self.state = AppState::Result(
+                        Hasil::baru(steps)
+                            .dengan_garis_bilangan(GarisBilangan::pertidaksamaan(himpunan)),
+                    );
+                }
+                // Tetap di layar input dan pindah ke isian pertama yang tidak valid
+                Err(i) => self.state = AppState::InputPTLSV(inputs, i, relasi),
+            },
+            (_, KeyCode::Tab | KeyCode::BackTab) => {
+                let relasi = relasi.geser(key == KeyCode::BackTab);
+                self.state = AppState::InputPTLSV(inputs, selected, relasi);
             }
-            KeyCode::Right => {
-                let new_selected = if selected < inputs.len() - 1 {
-                    selected + 1
+            (_, KeyCode::Esc) => self.state = AppState::Menu,
+            _ => {}
+        }
+        Ok(())
+    }
+
+    fn handle_spltv_key(
+        &mut self,
+        key: KeyCode,
+        mut inputs: [String; 12],
+        mut selected: usize,
+        metode: Metode,
+    ) -> Hitung<()> {
+        match (edit_fields(&mut inputs, &mut selected, key), key) {
+            (Suntingan::Keluar, _) => self.state = AppState::Exit,
+            (Suntingan::Berubah, _) => self.state = AppState::InputSPLTV(inputs, selected, metode),
+            (_, KeyCode::Up) => {
+                let new_selected = if selected >= 4 {
+                    selected - 4
                 } else {
                     selected
                 };
-                self.state = AppState::InputSPLDV(inputs, new_selected);
+                self.state = AppState::InputSPLTV(inputs, new_selected, metode);
             }
-            KeyCode::Enter => {
-                if let (Ok(a1), Ok(b1), Ok(c1), Ok(a2), Ok(b2), Ok(c2)) = (
-                    inputs[0].parse(),
-                    inputs[1].parse(),
-                    inputs[2].parse(),
-                    inputs[3].parse(),
-                    inputs[4].parse(),
-                    inputs[5].parse(),
-                ) {
-                    let (result, steps) =
-                        SistemPersamaan::spldv_proses_frac(a1, b1, c1, a2, b2, c2);
-                    let result_str = result
-                        .map(|(x, y)| {
-                            format!("{steps}\n\nHasil Akhir:\n  x = {:.2}, y = {:.2}", x, y)
-                        })
-                        .unwrap_or_else(|| format!("{steps}\n\nTidak ada solusi"));
-                    self.state = AppState::Result(result_str);
+            (_, KeyCode::Down) => {
+                let new_selected = if selected + 4 < inputs.len() {
+                    selected + 4
                 } else {
-                    self.state = AppState::Result("Input tidak valid".to_string());
+                    selected
+                };
+                self.state = AppState::InputSPLTV(inputs, new_selected, metode);
+            }
+            (_, KeyCode::Enter) => match parse_pecahan(&inputs) {
+                Ok(k) => {
+                    let k: [Pecahan; 12] = std::array::from_fn(|i| k[i]);
+                    let m: Vec<Vec<Pecahan>> = k.chunks(4).map(<[Pecahan]>::to_vec).collect();
+                    let (jenis, result, umum, mut steps) = spltv_proses(k, metode)?;
+                    let kondisi = result.and_then(|(x, y, z)| kondisi_sistem(&m, &[x, y, z]));
+                    steps.tulis(|t| match result {
+                        Some((x, y, z)) => format!(
+                            "{}\n{}",
+                            langkah_kondisi(&kondisi, t),
+                            hasil_akhir(&[("x", x), ("y", y), ("z", z)], t)
+                        ),
+                        None => format!("\n{}", tanpa_solusi_tunggal(jenis, umum.as_ref(), t)),
+                    });
+                    self.state = AppState::Result(
+                        Hasil::baru(steps).dengan_peringatan(peringatan_kondisi(&kondisi)),
+                    );
                 }
+                // Tetap di layar input dan pindah ke isian pertama yang tidak valid
+                Err(i) => self.state = AppState::InputSPLTV(inputs, i, metode),
+            },
+            (_, KeyCode::Tab | KeyCode::BackTab) => {
+                let metode = metode.geser(&Metode::SPLTV, key == KeyCode::BackTab);
+                self.state = AppState::InputSPLTV(inputs, selected, metode);
             }
+            (_, KeyCode::Esc) => self.state = AppState::Menu,
+            _ => {}
+        }
+        Ok(())
+    }
+
+    fn handle_gauss_key(&mut self, key: KeyEvent, mut editor: EditorMatriks) -> Hitung<()> {
+        match key.code {
+            KeyCode::Char('q') | KeyCode::Char('Q') if editor.sel_aktif().is_empty() => {
+                self.state = AppState::Exit
+            }
+            KeyCode::Enter => match editor.angka() {
+                Ok(m) => {
+                    let nama: Vec<String> = (1..=editor.jumlah_variabel())
+                        .map(|i| format!("x{}", i))
+                        .collect();
+                    let (jenis, result, umum, mut steps) = gauss_proses(m.clone(), &nama)?;
+                    let kondisi = result.as_ref().and_then(|x| kondisi_sistem(&m, x));
+                    steps.tulis(|t| match &result {
+                        Some(x) => {
+                            let hasil: Vec<(&str, Pecahan)> =
+                                nama.iter().map(String::as_str).zip(x.clone()).collect();
+                            format!(
+                                "{}\n{}",
+                                langkah_kondisi(&kondisi, t),
+                                hasil_akhir(&hasil, t)
+                            )
+                        }
+                        None => format!("\n{}", tanpa_solusi_tunggal(jenis, umum.as_ref(), t)),
+                    });
+                    self.state = AppState::Result(
+                        Hasil::baru(steps).dengan_peringatan(peringatan_kondisi(&kondisi)),
+                    );
+                }
+                Err(posisi) => {
+                    editor.kursor = posisi;
+                    self.state = AppState::InputGauss(editor);
+                }
+            },
             KeyCode::Esc => self.state = AppState::Menu,
+            _ => {
+                if editor.on_key(key) {
+                    self.state = AppState::InputGauss(editor);
+                }
+            }
+        }
+        Ok(())
+    }
+
+    fn handle_sptldv_key(
+        &mut self,
+        key: KeyEvent,
+        mut editor: EditorMatriks,
+        mut relasi: Vec<Relasi>,
+    ) -> Hitung<()> {
+        let ctrl = key.modifiers.contains(KeyModifiers::CONTROL);
+        match key.code {
+            KeyCode::Char('q') | KeyCode::Char('Q') if editor.sel_aktif().is_empty() => {
+                self.state = AppState::Exit
+            }
+            KeyCode::Enter => match editor.angka() {
+                Ok(m) => {
+                    let batasan: Vec<_> = m
+                        .iter()
+                        .zip(&relasi)
+                        .map(|(k, r)| ([k[0], k[1], k[2]], *r))
+                        .collect();
+                    let (daerah, mut steps) = daerah_proses(&batasan)?;
+                    steps.tulis(|t| format!("\n{}", daerah_akhir(&daerah, t)));
+                    self.state =
+                        AppState::Result(Hasil::baru(steps).dengan_grafik(Grafik::daerah(daerah)));
+                }
+                Err(posisi) => {
+                    editor.kursor = posisi;
+                    self.state = AppState::InputSPTLDV(editor, relasi);
+                }
+            },
+            KeyCode::Tab | KeyCode::BackTab => {
+                let i = editor.kursor.0;
+                relasi[i] = relasi[i].geser(key.code == KeyCode::BackTab);
+                self.state = AppState::InputSPTLDV(editor, relasi);
+            }
+            // Jumlah variabel selalu dua
+            KeyCode::Left | KeyCode::Right if ctrl => {}
+            KeyCode::Esc => self.state = AppState::Menu,
+            _ => {
+                if editor.on_key(key) {
+                    relasi.resize(editor.jumlah_baris(), Relasi::KurangSama);
+                    self.state = AppState::InputSPTLDV(editor, relasi);
+                }
+            }
+        }
+        Ok(())
+    }
+
+    fn handle_pl_key(
+        &mut self,
+        key: KeyEvent,
+        mut editor: EditorMatriks,
+        mut relasi: Vec<Relasi>,
+        mut maksimum: bool,
+    ) -> Hitung<()> {
+        let ctrl = key.modifiers.contains(KeyModifiers::CONTROL);
+        match key.code {
+            KeyCode::Char('q') | KeyCode::Char('Q') if editor.sel_aktif().is_empty() => {
+                self.state = AppState::Exit
+            }
+            KeyCode::Enter => match editor.angka() {
+                Ok(m) => {
+                    // Baris pertama fungsi tujuan, baris berikutnya kendala
+                    let tujuan = m[0].clone();
+                    let kendala: Vec<_> = m[1..].iter().cloned().zip(relasi.clone()).collect();
+                    let (optimum, daerah, mut steps) =
+                        program_linear_proses(&tujuan, &kendala, maksimum)?;
+                    steps.tulis(|t| format!("\n{}", optimum_akhir(&optimum, maksimum, t)));
+                    let hasil = Hasil::baru(steps);
+                    self.state = AppState::Result(match daerah {
+                        Some(daerah) => hasil.dengan_grafik(Grafik::daerah(daerah)),
+                        None => hasil,
+                    });
+                }
+                Err(posisi) => {
+                    editor.kursor = posisi;
+                    self.state = AppState::InputPL(editor, relasi, maksimum);
+                }
+            },
+            KeyCode::Tab | KeyCode::BackTab => {
+                match editor.kursor.0 {
+                    0 => maksimum = !maksimum,
+                    i => relasi[i - 1] = relasi[i - 1].balik(),
+                }
+                self.state = AppState::InputPL(editor, relasi, maksimum);
+            }
+            // Paling sedikit dua variabel dan satu kendala
+            KeyCode::Left if ctrl && editor.jumlah_variabel() <= 2 => {}
+            KeyCode::Up if ctrl && editor.jumlah_baris() <= 2 => {}
+            KeyCode::Esc => self.state = AppState::Menu,
+            _ => {
+                if editor.on_key(key) {
+                    relasi.resize(editor.jumlah_baris() - 1, Relasi::KurangSama);
+                    self.state = AppState::InputPL(editor, relasi, maksimum);
+                }
+            }
+        }
+        Ok(())
+    }
+
+    fn handle_teks_key(
+        &mut self,
+        key: KeyEvent,
+        mut baris: Vec<String>,
+        selected: usize,
+    ) -> Hitung<()> {
+        let ctrl = key.modifiers.contains(KeyModifiers::CONTROL);
+        let mut selected = selected;
+        match key.code {
+            // Huruf q boleh menjadi nama variabel, jadi keluar memakai Ctrl+Q
+            KeyCode::Char('q') | KeyCode::Char('Q') if ctrl => {
+                self.state = AppState::Exit;
+                return Ok(());
+            }
+            KeyCode::Esc => {
+                self.state = AppState::Menu;
+                return Ok(());
+            }
+            KeyCode::Enter => {
+                match sistem_linear(&baris) {
+                    Ok(_) => {
+                        self.state = AppState::KonfirmasiTeks(baris);
+                        return Ok(());
+                    }
+                    // Pindah ke persamaan pertama yang tidak valid
+                    Err((i, _)) => selected = i,
+                }
+            }
+            KeyCode::Down if ctrl && baris.len() < MAX_BARIS => {
+                baris.push(String::new());
+                selected = baris.len() - 1;
+            }
+            KeyCode::Up if ctrl && baris.len() > 1 => {
+                baris.pop();
+                selected = selected.min(baris.len() - 1);
+            }
+            KeyCode::Up if !ctrl => selected = selected.saturating_sub(1),
+            KeyCode::Down if !ctrl => selected = (selected + 1).min(baris.len() - 1),
+            KeyCode::Backspace => {
+                baris[selected].pop();
+            }
+            KeyCode::Char(c) if c == '=' || karakter_valid(c) => baris[selected].push(c),
             _ => {}
         }
+        self.state = AppState::InputTeks(baris, selected);
+        Ok(())
     }
 
-    fn handle_splsv_key(&mut self, key: KeyCode, mut inputs: [String; 2], selected: usize) {
+    fn handle_konfirmasi_teks_key(&mut self, key: KeyCode, baris: Vec<String>) -> Hitung<()> {
         match key {
             KeyCode::Char('q') | KeyCode::Char('Q') => self.state = AppState::Exit,
-            KeyCode::Char(c) if c.is_ascii_digit() || c == '-' || c == '.' => {
-                inputs[selected].push(c);
-                self.state = AppState::InputSPLSV(inputs, selected);
+            KeyCode::Enter => {
+                if let Ok(sistem) = sistem_linear(&baris) {
+                    let mut steps = Teks::from("Bentuk baku:\n");
+                    for i in 0..sistem.matriks.len() {
+                        steps.tulis(|t| format!("  P{}: {}\n", i + 1, sistem.persamaan(i, t)));
+                    }
+                    let (jenis, result, umum, langkah) =
+                        gauss_proses(sistem.matriks.clone(), &sistem.nama)?;
+                    steps.push('\n');
+                    steps.sambung(&langkah);
+                    let kondisi = result
+                        .as_ref()
+                        .and_then(|x| kondisi_sistem(&sistem.matriks, x));
+                    steps.tulis(|t| match &result {
+                        Some(x) => {
+                            let hasil: Vec<(&str, Pecahan)> = sistem
+                                .nama
+                                .iter()
+                                .map(String::as_str)
+                                .zip(x.clone())
+                                .collect();
+                            format!(
+                                "{}\n{}",
+                                langkah_kondisi(&kondisi, t),
+                                hasil_akhir(&hasil, t)
+                            )
+                        }
+                        None => format!("\n{}", tanpa_solusi_tunggal(jenis, umum.as_ref(), t)),
+                    });
+                    self.state = AppState::Result(
+                        Hasil::baru(steps).dengan_peringatan(peringatan_kondisi(&kondisi)),
+                    );
+                }
+            }
+            KeyCode::Esc => {
+                let selected = baris.len() - 1;
+                self.state = AppState::InputTeks(baris, selected);
             }
+            _ => {}
+        }
+        Ok(())
+    }
+
+    fn handle_substitusi_key(
+        &mut self,
+        key: KeyEvent,
+        mut baris: Vec<String>,
+        selected: usize,
+    ) -> Hitung<()> {
+        let ctrl = key.modifiers.contains(KeyModifiers::CONTROL);
+        let mut selected = selected;
+        match key.code {
+            // Huruf q boleh menjadi nama variabel, jadi keluar memakai Ctrl+Q
+            KeyCode::Char('q') | KeyCode::Char('Q') if ctrl => {
+                self.state = AppState::Exit;
+                return Ok(());
+            }
+            KeyCode::Esc => {
+                self.state = AppState::Menu;
+                return Ok(());
+            }
+            KeyCode::Enter => match sistem_substitusi(&baris) {
+                Ok(sistem) if variabel_asal(&sistem).is_ok() => {
+                    let (jenis, nilai, umum, asal, solusi, mut steps) = substitusi_proses(&sistem)?;
+                    // Kondisi sistem linear dalam variabel bantu
+                    let kondisi = nilai.and_then(|x| kondisi_sistem(&sistem.matriks, &x));
+                    steps.coba_tulis(|t| {
+                        Ok(format!(
+                            "{}\n{}",
+                            langkah_kondisi(&kondisi, t),
+                            substitusi_akhir(jenis, umum.as_ref(), &asal, &solusi, t)?
+                        ))
+                    })?;
+                    self.state = AppState::Result(
+                        Hasil::baru(steps).dengan_peringatan(peringatan_kondisi(&kondisi)),
+                    );
+                    return Ok(());
+                }
+                // Variabel yang muncul dalam dua bentuk diperiksa di persamaan terakhir
+                Ok(_) => selected = baris.len() - 1,
+                // Pindah ke persamaan pertama yang tidak valid
+                Err((i, _)) => selected = i,
+            },
+            KeyCode::Up => selected = selected.saturating_sub(1),
+            KeyCode::Down => selected = (selected + 1).min(baris.len() - 1),
             KeyCode::Backspace => {
-                inputs[selected].pop();
-                self.state = AppState::InputSPLSV(inputs, selected);
+                baris[selected].pop();
+            }
+            KeyCode::Char(c) if c == '=' || karakter_valid(c) => baris[selected].push(c),
+            _ => {}
+        }
+        self.state = AppState::InputSubstitusi(baris, selected);
+        Ok(())
+    }
+
+    fn handle_matriks_key(
+        &mut self,
+        key: KeyEvent,
+        mut kalkulator: KalkulatorMatriks,
+    ) -> Hitung<()> {
+        match key.code {
+            KeyCode::Char('q') | KeyCode::Char('Q') if kalkulator.kosong() => {
+                self.state = AppState::Exit;
+                return Ok(());
             }
-            KeyCode::Left => {
-                let new_selected = if selected > 0 { selected - 1 } else { selected };
-                self.state = AppState::InputSPLSV(inputs, new_selected);
+            KeyCode::Esc => {
+                self.state = AppState::Menu;
+                return Ok(());
             }
-            KeyCode::Right => {
-                let new_selected = if selected < inputs.len() - 1 {
-                    selected + 1
+            KeyCode::Tab | KeyCode::BackTab => {
+                kalkulator.operasi = kalkulator.operasi.geser(key.code == KeyCode::BackTab);
+                if !kalkulator.pakai_kedua() {
+                    kalkulator.aktif = 0;
+                }
+            }
+            // Ukuran yang tidak cocok sudah ditampilkan di layar isian
+            KeyCode::Enter if kalkulator.galat_ukuran().is_some() => {}
+            KeyCode::Enter => {
+                let operasi = kalkulator.operasi;
+                let a = match kalkulator.a.angka() {
+                    Ok(a) => a,
+                    Err(posisi) => {
+                        kalkulator.aktif = 0;
+                        kalkulator.a.kursor = posisi;
+                        self.state = AppState::InputMatriks(kalkulator);
+                        return Ok(());
+                    }
+                };
+                let b = if operasi.pakai_b() {
+                    match kalkulator.b.angka() {
+                        Ok(b) => b,
+                        Err(posisi) => {
+                            kalkulator.aktif = 1;
+                            kalkulator.b.kursor = posisi;
+                            self.state = AppState::InputMatriks(kalkulator);
+                            return Ok(());
+                        }
+                    }
                 } else {
-                    selected
+                    Vec::new()
                 };
-                self.state = AppState::InputSPLSV(inputs, new_selected);
+                let k = if operasi.pakai_k() {
+                    match hitung(&kalkulator.k) {
+                        Ok(k) => k.nilai,
+                        Err(_) => {
+                            kalkulator.aktif = 1;
+                            self.state = AppState::InputMatriks(kalkulator);
+                            return Ok(());
+                        }
+                    }
+                } else {
+                    Pecahan::SATU
+                };
+                let kondisi = match operasi {
+                    OperasiMatriks::Invers => kondisi_matriks(&a),
+                    _ => None,
+                };
+                let (hasil, mut steps) = kalkulator_proses(operasi, &a, &b, k)?;
+                steps.tulis(|t| format!("{}\n", langkah_kondisi(&kondisi, t)));
+                steps.sambung(&matriks_akhir(operasi.nama(), &hasil));
+                self.state = AppState::Result(
+                    Hasil::baru(steps).dengan_peringatan(peringatan_kondisi(&kondisi)),
+                );
+                return Ok(());
             }
-            KeyCode::Enter => {
-                if let (Ok(a), Ok(b)) = (inputs[0].parse(), inputs[1].parse()) {
-                    let (result, steps) = SistemPersamaan::splsv_proses_frac(a, b);
-                    let result_str = match result {
-                        Some(x) => format!("{steps}\nHasil Akhir:\n  x = {:.2}", x),
-                        None => format!("{steps}\nTidak ada solusi"),
+            _ => {
+                kalkulator.on_key(key);
+            }
+        }
+        self.state = AppState::InputMatriks(kalkulator);
+        Ok(())
+    }
+
+    fn handle_kuadrat_terkecil_key(
+        &mut self,
+        key: KeyEvent,
+        mut editor: EditorMatriks,
+    ) -> Hitung<()> {
+        match key.code {
+            KeyCode::Char('q') | KeyCode::Char('Q') if editor.sel_aktif().is_empty() => {
+                self.state = AppState::Exit
+            }
+            KeyCode::Enter => match editor.angka() {
+                Ok(m) => {
+                    let nama: Vec<String> = (1..=editor.jumlah_variabel())
+                        .map(|i| format!("x{}", i))
+                        .collect();
+                    // Kondisi persamaan normal AᵀA x = Aᵀb, presisi dari koefisien [A | b]
+                    let (hasil, mut steps) = kuadrat_terkecil_proses(&m, &nama)?;
+                    let normal = persamaan_normal(&m, nama.len())?;
+                    let kondisi = hasil
+                        .x
+                        .as_ref()
+                        .and_then(|x| kondisi_sistem(&normal, x))
+                        .map(|k| k.untuk("AᵀA", "Aᵀb", &m));
+                    steps.coba_tulis(|t| {
+                        Ok(format!(
+                            "{}\n{}",
+                            langkah_kondisi(&kondisi, t),
+                            kuadrat_terkecil_akhir(&hasil, &nama, t)?
+                        ))
+                    })?;
+                    self.state = AppState::Result(
+                        Hasil::baru(steps).dengan_peringatan(peringatan_kondisi(&kondisi)),
+                    );
+                }
+                Err(posisi) => {
+                    editor.kursor = posisi;
+                    self.state = AppState::InputKuadratTerkecil(editor);
+                }
+            },
+            KeyCode::Esc => self.state = AppState::Menu,
+            _ => {
+                if editor.on_key(key) {
+                    self.state = AppState::InputKuadratTerkecil(editor);
+                }
+            }
+        }
+        Ok(())
+    }
+
+    fn handle_regresi_key(
+        &mut self,
+        key: KeyEvent,
+        mut editor: EditorMatriks,
+        mut prediksi: String,
+        mut di_prediksi: bool,
+    ) -> Hitung<()> {
+        let ctrl = key.modifiers.contains(KeyModifiers::CONTROL);
+        let kosong = if di_prediksi {
+            prediksi.is_empty()
+        } else {
+            editor.sel_aktif().is_empty()
+        };
+        match key.code {
+            KeyCode::Char('q') | KeyCode::Char('Q') if kosong => {
+                self.state = AppState::Exit;
+                return Ok(());
+            }
+            KeyCode::Esc => {
+                self.state = AppState::Menu;
+                return Ok(());
+            }
+            KeyCode::Tab | KeyCode::BackTab => di_prediksi = !di_prediksi,
+            KeyCode::Enter => match data_regresi(&editor) {
+                Err(posisi) => {
+                    editor.kursor = posisi;
+                    di_prediksi = false;
+                }
+                Ok(data) => {
+                    let x = if prediksi.trim().is_empty() {
+                        Ok(None)
+                    } else {
+                        hitung(&prediksi).map(|n| Some(n.nilai))
                     };
-                    self.state = AppState::Result(result_str);
-                } else {
-                    self.state = AppState::Result("Input tidak valid".to_string());
+                    match x {
+                        Ok(x) => {
+                            let (regresi, mut steps) = regresi_proses(&data, x)?;
+                            steps.coba_tulis(|t| {
+                                Ok(format!("\n{}", regresi_akhir(regresi.as_ref(), t)?))
+                            })?;
+                            let hasil = Hasil::baru(steps);
+                            self.state = AppState::Result(match regresi {
+                                Some(r) => hasil
+                                    .dengan_grafik(Grafik::regresi(&data, r.m, r.c, r.prediksi)),
+                                None => hasil,
+                            });
+                            return Ok(());
+                        }
+                        Err(_) => di_prediksi = true,
+                    }
+                }
+            },
+            // Tabel data selalu dua kolom (x, y)
+            KeyCode::Left | KeyCode::Right if ctrl => {}
+            KeyCode::Down if ctrl && !di_prediksi => {
+                if editor.jumlah_baris() < MAX_DATA {
+                    editor.sel.push(vec![String::new(); 2]);
                 }
             }
-            KeyCode::Esc => self.state = AppState::Menu,
-            _ => {}
+            KeyCode::Backspace if di_prediksi => {
+                prediksi.pop();
+            }
+            KeyCode::Char(c) if di_prediksi && karakter_valid(c) => prediksi.push(c),
+            _ if di_prediksi => {}
+            _ => {
+                editor.on_key(key);
+            }
+        }
+        self.state = AppState::InputRegresi(editor, prediksi, di_prediksi);
+        Ok(())
+    }
+
+    fn handle_iteratif_key(&mut self, key: KeyEvent, mut isian: IsianIteratif) -> Hitung<()> {
+        match key.code {
+            KeyCode::Char('q') | KeyCode::Char('Q') if isian.kosong() => {
+                self.state = AppState::Exit;
+                return Ok(());
+            }
+            KeyCode::Esc => {
+                self.state = AppState::Menu;
+                return Ok(());
+            }
+            KeyCode::Tab | KeyCode::BackTab => {
+                isian.metode = isian.metode.geser(key.code == KeyCode::BackTab)
+            }
+            // Ukuran yang tidak persegi sudah ditampilkan di bawah isian
+            KeyCode::Enter if isian.matriks.galat_persegi().is_some() => {}
+            KeyCode::Enter => {
+                let n = isian.matriks.jumlah_variabel();
+                match (
+                    isian.matriks.angka(),
+                    ParameterIteratif::baca(&isian.parameter, n, isian.metode),
+                ) {
+                    (Err(posisi), _) => {
+                        isian.aktif = None;
+                        isian.matriks.kursor = posisi;
+                    }
+                    (_, Err((i, _))) => isian.aktif = Some(i),
+                    (Ok(m), Ok(parameter)) => {
+                        let nama: Vec<String> = (1..=n).map(|i| format!("x{}", i)).collect();
+                        let metode = isian.metode;
+                        let a: Vec<Vec<Pecahan>> = m.iter().map(|b| b[..n].to_vec()).collect();
+                        let kondisi = kondisi_matriks(&a);
+                        let (hasil, x, mut steps) = iteratif_proses(&m, &nama, metode, &parameter)?;
+                        steps.tulis(|t| {
+                            format!(
+                                "{}\n{}",
+                                langkah_kondisi(&kondisi, t),
+                                iteratif_akhir(hasil, &nama, &x, parameter.maks)
+                            )
+                        });
+                        self.state = AppState::Result(
+                            Hasil::baru(steps).dengan_peringatan(peringatan_kondisi(&kondisi)),
+                        );
+                        return Ok(());
+                    }
+                }
+            }
+            _ => {
+                isian.on_key(key);
+            }
+        }
+        self.state = AppState::InputIteratif(isian);
+        Ok(())
+    }
+
+    fn handle_dekomposisi_key(
+        &mut self,
+        key: KeyEvent,
+        mut editor: EditorMatriks,
+        mut jenis: JenisDekomposisi,
+    ) -> Hitung<()> {
+        let n = editor.jumlah_variabel();
+        match key.code {
+            KeyCode::Char('q') | KeyCode::Char('Q') if editor.sel_aktif().is_empty() => {
+                self.state = AppState::Exit;
+                return Ok(());
+            }
+            KeyCode::Esc => {
+                self.state = AppState::Menu;
+                return Ok(());
+            }
+            KeyCode::Tab | KeyCode::BackTab => jenis = jenis.geser(key.code == KeyCode::BackTab),
+            // Ukuran yang tidak persegi sudah ditampilkan di bawah isian
+            KeyCode::Enter if editor.galat_persegi().is_some() => {}
+            KeyCode::Enter => {
+                // Kolom b yang kosong seluruhnya berarti hanya faktorisasi
+                let tanpa_b = editor.sel.iter().all(|b| b[n].trim().is_empty());
+                let mut isian = editor.clone();
+                if tanpa_b {
+                    for b in isian.sel.iter_mut() {
+                        b[n] = "0".to_string();
+                    }
+                }
+                match isian.angka() {
+                    Ok(m) => {
+                        let a: Vec<Vec<Pecahan>> = m.iter().map(|b| b[..n].to_vec()).collect();
+                        let b: Option<Vec<Pecahan>> =
+                            (!tanpa_b).then(|| m.iter().map(|b| b[n]).collect());
+                        let nama: Vec<String> = (1..=n).map(|i| format!("x{}", i)).collect();
+                        let (hasil, mut steps) =
+                            dekomposisi_proses(jenis, &a, b.as_deref(), &nama)?;
+                        let kondisi = match &hasil {
+                            HasilDekomposisi::Berhasil(Some(x)) => kondisi_sistem(&m, x),
+                            _ => None,
+                        };
+                        steps.tulis(|t| {
+                            format!(
+                                "{}\n{}",
+                                langkah_kondisi(&kondisi, t),
+                                dekomposisi_akhir(jenis, &hasil, &nama, t)
+                            )
+                        });
+                        self.state = AppState::Result(
+                            Hasil::baru(steps).dengan_peringatan(peringatan_kondisi(&kondisi)),
+                        );
+                        return Ok(());
+                    }
+                    Err(posisi) => editor.kursor = posisi,
+                }
+            }
+            _ => {
+                editor.on_key(key);
+            }
         }
+        self.state = AppState::InputDekomposisi(editor, jenis);
+        Ok(())
     }
 
-    fn handle_result_key(&mut self, key: KeyCode) {
+    fn handle_ruang_key(&mut self, key: KeyEvent, mut editor: EditorMatriks) -> Hitung<()> {
+        match key.code {
+            KeyCode::Char('q') | KeyCode::Char('Q') if editor.sel_aktif().is_empty() => {
+                self.state = AppState::Exit;
+                return Ok(());
+            }
+            KeyCode::Esc => {
+                self.state = AppState::Menu;
+                return Ok(());
+            }
+            KeyCode::Enter => match editor.angka() {
+                Ok(a) => {
+                    let (ruang, mut steps) = ruang_proses(&a)?;
+                    steps.tulis(|t| format!("\n{}", ruang_akhir(&ruang, t)));
+                    self.state = AppState::Result(Hasil::baru(steps));
+                    return Ok(());
+                }
+                Err(posisi) => editor.kursor = posisi,
+            },
+            _ => {
+                editor.on_key(key);
+            }
+        }
+        self.state = AppState::InputRuang(editor);
+        Ok(())
+    }
+
+    fn handle_eigen_key(&mut self, key: KeyEvent, mut editor: EditorMatriks) -> Hitung<()> {
+        let n = editor.jumlah_kolom();
+        match key.code {
+            KeyCode::Char('q') | KeyCode::Char('Q') if editor.sel_aktif().is_empty() => {
+                self.state = AppState::Exit;
+                return Ok(());
+            }
+            KeyCode::Esc => {
+                self.state = AppState::Menu;
+                return Ok(());
+            }
+            // Ukuran selain 2 × 2 atau 3 × 3 sudah ditampilkan di bawah isian
+            KeyCode::Enter if periksa_ukuran(editor.jumlah_baris(), n).is_err() => {}
+            KeyCode::Enter => match editor.angka() {
+                Ok(a) => {
+                    let (hasil, mut steps) = eigen_proses(&a)?;
+                    steps.coba_tulis(|t| Ok(format!("\n{}", eigen_akhir(&hasil, t)?)))?;
+                    self.state = AppState::Result(Hasil::baru(steps));
+                    return Ok(());
+                }
+                Err(posisi) => editor.kursor = posisi,
+            },
+            _ => {
+                editor.on_key(key);
+            }
+        }
+        self.state = AppState::InputEigen(editor);
+        Ok(())
+    }
+
+    fn handle_result_key(&mut self, key: KeyCode, mut result: Hasil) -> Hitung<()> {
+        let max_scroll = result.isi().tinggi() as u16;
         match key {
             KeyCode::Char('q') | KeyCode::Char('Q') => self.state = AppState::Exit,
-            KeyCode::Esc => self.state = AppState::Menu,
+            KeyCode::Char('p') | KeyCode::Char('P') => {
+                result.tampil_desimal = !result.tampil_desimal;
+                self.state = AppState::Result(result);
+            }
+            KeyCode::Up => self.result_scroll = self.result_scroll.saturating_sub(1),
+            KeyCode::Down => self.result_scroll = (self.result_scroll + 1).min(max_scroll),
+            KeyCode::PageUp => self.result_scroll = self.result_scroll.saturating_sub(10),
+            KeyCode::PageDown => self.result_scroll = (self.result_scroll + 10).min(max_scroll),
+            KeyCode::Char(c) if result.grafik.is_some() && "wasdWASD+=-0".contains(c) => {
+                if let Some(g) = result.grafik.as_mut() {
+                    match c.to_ascii_lowercase() {
+                        'w' => g.geser(0.0, 1.0),
+                        's' => g.geser(0.0, -1.0),
+                        'a' => g.geser(-1.0, 0.0),
+                        'd' => g.geser(1.0, 0.0),
+                        '+' | '=' => g.zoom(0.8),
+                        '-' => g.zoom(1.25),
+                        _ => g.reset(),
+                    }
+                }
+                self.state = AppState::Result(result);
+            }
+            KeyCode::Esc => {
+                self.result_scroll = 0;
+                self.state = AppState::Menu
+            }
             _ => {}
         }
+        Ok(())
     }
 
     pub fn should_exit(&self) -> bool {
         matches!(self.state, AppState::Exit)
     }
 }
+
+// Ringkasan hasil akhir untuk sistem yang tidak memiliki solusi tunggal
+fn tanpa_solusi_tunggal(jenis: JenisSolusi, umum: Option<&Teks>, t: Tampilan) -> String {
+    match (jenis, umum) {
+        (JenisSolusi::TidakAda, _) => {
+            "Hasil Akhir:\n  Tidak ada solusi (sistem tidak konsisten)".to_string()
+        }
+        (_, Some(umum)) => format!(
+            "Hasil Akhir:\n  Tak hingga banyak solusi (sistem bergantung)\n  {}",
+            umum.tampil(t)
+        ),
+        _ => "Hasil Akhir:\n  Tak hingga banyak solusi (sistem bergantung)".to_string(),
+    }
+}
+
+// Ringkasan akar-akar persamaan kuadrat
+fn kuadrat_akhir(akar: AkarKuadrat, t: Tampilan) -> Hitung<String> {
+    Ok(match akar {
+        AkarKuadrat::Dua { imajiner, .. } => format!(
+            "Hasil Akhir:\n  {}\n  x₁ = {}\n  x₂ = {}",
+            if imajiner {
+                "Dua akar kompleks sekawan"
+            } else {
+                "Dua akar real berbeda"
+            },
+            akar.nilai("+", t)?,
+            akar.nilai("-", t)?
+        ),
+        AkarKuadrat::Kembar(_) => format!(
+            "Hasil Akhir:\n  Akar kembar\n  x₁ = x₂ = {}",
+            akar.nilai("+", t)?
+        ),
+        AkarKuadrat::Linear(_, Some(x)) => hasil_akhir(&[("x", x)], t),
+        AkarKuadrat::Linear(jenis, None) => tanpa_solusi_tunggal(jenis, None, t),
+    })
+}
+
+// Ringkasan titik potong SPLKDV. Koordinat yang tidak bulat pada tampilan
+// pecahan diberi nilai desimalnya di samping.
+fn splkdv_akhir(perpotongan: Perpotongan, titik: &[Titik], t: Tampilan) -> Hitung<String> {
+    let mut teks = format!("Hasil Akhir:\n  {}", perpotongan.nama());
+    for (i, (x, y)) in titik.iter().enumerate() {
+        teks.push_str(&format!(
+            "\n  {}({}, {})",
+            huruf(i),
+            x.tulis(t)?,
+            y.tulis(t)?
+        ));
+        if t == Tampilan::Pecahan && !(x.is_bulat() && y.is_bulat()) {
+            teks.push_str(&format!(
+                " ≈ ({}, {})",
+                x.tulis(Tampilan::Desimal)?,
+                y.tulis(Tampilan::Desimal)?
+            ));
+        }
+    }
+    Ok(teks)
+}
+
+// Ringkasan penyelesaian sistem dengan pemisalan, satu baris per pasangan nilai
+fn substitusi_akhir(
+    jenis: JenisSolusi,
+    umum: Option<&Teks>,
+    asal: &[String],
+    solusi: &Solusi,
+    t: Tampilan,
+) -> Hitung<String> {
+    if jenis != JenisSolusi::Tunggal {
+        return Ok(tanpa_solusi_tunggal(jenis, umum, t));
+    }
+    if solusi.is_empty() {
+        return Ok(
+            "Hasil Akhir:\n  Tidak ada penyelesaian (nilai pemisalan tidak mungkin)".to_string(),
+        );
+    }
+    let baris: Vec<String> = solusi
+        .iter()
+        .map(|nilai| {
+            let isi: Vec<String> = asal
+                .iter()
+                .zip(nilai)
+                .map(|(n, v)| {
+                    Ok(if t == Tampilan::Pecahan && !v.is_bulat() {
+                        let hampiran = v.tulis(Tampilan::Desimal)?;
+                        format!("{} = {} ≈ {}", n, v.tulis(t)?, hampiran)
+                    } else {
+                        format!("{} = {}", n, v.tulis(t)?)
+                    })
+                })
+                .collect::<Hitung<_>>()?;
+            Ok(format!("  {}", isi.join(", ")))
+        })
+        .collect::<Hitung<_>>()?;
+    Ok(format!("Hasil Akhir:\n{}", baris.join("\n")))
+}
+
+// Ringkasan himpunan penyelesaian pertidaksamaan dalam dua notasi
+fn himpunan_akhir(himpunan: Himpunan, t: Tampilan) -> String {
+    format!(
+        "Hasil Akhir:\n  Notasi selang: {}\n  Notasi pembentuk himpunan: {}",
+        himpunan.notasi_selang(t),
+        himpunan.notasi_himpunan(t)
+    )
+}
+
+// Ringkasan daerah penyelesaian beserta titik pojoknya
+fn daerah_akhir(daerah: &Daerah, t: Tampilan) -> String {
+    let mut teks = format!("Hasil Akhir:\n  {}", daerah.jenis.nama());
+    if !daerah.pojok.is_empty() {
+        let pojok: Vec<String> = daerah
+            .pojok
+            .iter()
+            .enumerate()
+            .map(|(i, ((x, y), _))| format!("{}({}, {})", huruf(i), x.tampil(t), y.tampil(t)))
+            .collect();
+        teks.push_str(&format!("\n  Titik pojok: {}", pojok.join(", ")));
+    }
+    teks
+}
+
+// Ringkasan nilai optimum program linear
+fn optimum_akhir(optimum: &Optimum, maksimum: bool, t: Tampilan) -> String {
+    let kata = if maksimum { "maksimum" } else { "minimum" };
+    match optimum {
+        Optimum::Ada {
+            nilai,
+            titik,
+            sinar,
+        } => {
+            let nama = nama_variabel(titik[0].len());
+            let tulis = |titik: &Vec<Pecahan>| {
+                let isi: Vec<String> = nama
+                    .iter()
+                    .zip(titik)
+                    .map(|(n, v)| format!("{} = {}", n, nilai_akhir(*v, t)))
+                    .collect();
+                isi.join(", ")
+            };
+            let mut teks = format!(
+                "Hasil Akhir:\n  Nilai {} Z = {}\n  Dicapai di {}",
+                kata,
+                nilai_akhir(*nilai, t),
+                tulis(&titik[0])
+            );
+            for p in &titik[1..] {
+                teks.push_str(&format!("\n  dan di {}", tulis(p)));
+            }
+            if titik.len() > 1 || sinar.is_some() {
+                teks.push_str("\n  Optimum ganda (tidak tunggal)");
+            }
+            teks
+        }
+        Optimum::TidakTerbatas => {
+            format!("Hasil Akhir:\n  Tidak ada nilai {} (tidak terbatas)", kata)
+        }
+        Optimum::TidakLayak => "Hasil Akhir:\n  Tidak ada penyelesaian layak".to_string(),
+        Optimum::TidakKonvergen => format!(
+            "Hasil Akhir:\n  Simpleks tidak konvergen: batas iterasi tercapai\n  sebelum nilai {} ditemukan",
+            kata
+        ),
+    }
+}
+
+// Akibat tombol pada deretan isian koefisien
+enum Suntingan {
+    /// Isi atau kursor isian berubah
+    Berubah,
+    /// `q` pada isian yang kosong
+    Keluar,
+    /// Tombol khusus mode, ditangani oleh pemanggil
+    Lewat,
+}
+
+// Penyuntingan bersama untuk mode berisian koefisien: mengetik, menghapus,
+// berpindah isian dengan ←/→, dan keluar dengan `q` pada isian kosong
+fn edit_fields(inputs: &mut [String], selected: &mut usize, key: KeyCode) -> Suntingan {
+    match key {
+        KeyCode::Char('q') | KeyCode::Char('Q') if inputs[*selected].is_empty() => {
+            return Suntingan::Keluar;
+        }
+        KeyCode::Char(c) if karakter_valid(c) => inputs[*selected].push(c),
+        KeyCode::Backspace => {
+            inputs[*selected].pop();
+        }
+        KeyCode::Left => *selected = selected.saturating_sub(1),
+        KeyCode::Right => *selected = (*selected + 1).min(inputs.len() - 1),
+        _ => return Suntingan::Lewat,
+    }
+    Suntingan::Berubah
+}
+
+// Menghitung isian koefisien menjadi pecahan, atau indeks isian pertama yang tidak valid
+fn parse_pecahan(inputs: &[String]) -> Result<Vec<Pecahan>, usize> {
+    inputs
+        .iter()
+        .enumerate()
+        .map(|(i, s)| hitung(s).map(|n| n.nilai).map_err(|_| i))
+        .collect()
+}
+
+// Isian SPLDV dengan satu parameter yang sama di semua kotak,
+// atau indeks isian pertama yang tidak valid
+fn parse_parameter(inputs: &[String; 6]) -> Result<(String, [Polinom; 6]), usize> {
+    let mut nama: Option<String> = None;
+    let mut k = [[Pecahan::NOL; 3]; 6];
+    for (i, s) in inputs.iter().enumerate() {
+        let (p, [c0, c1]) = hitung_parameter(s).map_err(|_| i)?;
+        if let Some(p) = p {
+            if nama.as_ref().is_some_and(|n| *n != p) {
+                return Err(i);
+            }
+            nama = Some(p);
+        }
+        k[i] = [c0, c1, Pecahan::NOL];
+    }
+    nama.map(|n| (n, k)).ok_or(0)
+}
+
+// Syarat parameter untuk setiap jenis penyelesaian
+fn parameter_akhir(kasus: &[(JenisSolusi, Teks)], t: Tampilan) -> String {
+    let baris: Vec<String> = kasus
+        .iter()
+        .map(|(jenis, syarat)| {
+            let nama = match jenis {
+                JenisSolusi::Tunggal => "Penyelesaian tunggal",
+                JenisSolusi::TidakAda => "Tidak ada penyelesaian",
+                JenisSolusi::TakHingga => "Tak hingga banyak penyelesaian",
+            };
+            format!("  {:<31}: {}", nama, syarat.tampil(t))
+        })
+        .collect();
+    format!("Hasil Akhir:\n{}", baris.join("\n"))
+}
+
+// Ringkasan penyelesaian kuadrat terkecil beserta panjang vektor sisanya
+fn kuadrat_terkecil_akhir(hasil: &KuadratTerkecil, nama: &[String], t: Tampilan) -> Hitung<String> {
+    let Some(x) = &hasil.x else {
+        return Ok("Hasil Akhir:\n  Penyelesaian kuadrat terkecil tidak tunggal (kolom A bergantung linear)"
+            .to_string());
+    };
+    let nilai: Vec<(&str, Pecahan)> = nama.iter().map(String::as_str).zip(x.clone()).collect();
+    Ok(format!(
+        "{}\n  ‖r‖ = {}\n  {}",
+        hasil_akhir(&nilai, t),
+        norma(&hasil.sisa, t)?,
+        if hasil.konsisten() {
+            "Sistem konsisten (penyelesaian eksak)"
+        } else {
+            "Sistem tidak konsisten (penyelesaian hampiran kuadrat terkecil)"
+        }
+    ))
+}
+
+// Ringkasan dekomposisi: penyelesaian Ax = b jika b diisi
+fn dekomposisi_akhir(
+    jenis: JenisDekomposisi,
+    hasil: &HasilDekomposisi,
+    nama: &[String],
+    t: Tampilan,
+) -> String {
+    match hasil {
+        HasilDekomposisi::Berhasil(Some(x)) => {
+            let nilai: Vec<(&str, Pecahan)> =
+                nama.iter().map(String::as_str).zip(x.clone()).collect();
+            hasil_akhir(&nilai, t)
+        }
+        HasilDekomposisi::Berhasil(None) => {
+            format!("Hasil Akhir:\n  Faktorisasi {} berhasil", jenis.nama())
+        }
+        HasilDekomposisi::Singular => "Hasil Akhir:\n  Faktorisasi LU berhasil, tetapi A singular;\n  Ax = b tidak memiliki penyelesaian tunggal".to_string(),
+        HasilDekomposisi::Gagal(alasan) => format!("Hasil Akhir:\n  {}", alasan),
+    }
+}
+
+// Ringkasan ruang fundamental: rank, nulitas, dan basis setiap ruang
+fn ruang_akhir(ruang: &Ruang, t: Tampilan) -> String {
+    format!(
+        "Hasil Akhir:\n  rank(A) = {}\n  nulitas(A) = {}\n  Row(A) = {}\n  Col(A) = {}\n  Null(A) = {}",
+        ruang.rank,
+        ruang.nulitas,
+        span(&ruang.basis_baris, false, t),
+        span(&ruang.basis_kolom, true, t),
+        span(&ruang.basis_nol, true, t)
+    )
+}
+
+// Ringkasan nilai eigen beserta vektor eigen dan keterdiagonalannya
+fn eigen_akhir(hasil: &HasilEigen, t: Tampilan) -> Hitung<String> {
+    let mut baris: Vec<String> = Vec::new();
+    for (i, e) in hasil.eigen.iter().enumerate() {
+        let vektor: Vec<String> = e
+            .vektor
+            .iter()
+            .map(|v| vektor_teks(v, t))
+            .collect::<Hitung<_>>()?;
+        baris.push(format!(
+            "  λ{} {} {} (aljabar {}, geometri {}): {}",
+            i + 1,
+            e.nilai.relasi(),
+            e.nilai.tulis(t)?,
+            e.aljabar,
+            e.vektor.len(),
+            vektor.join(", ")
+        ));
+    }
+    if let Some(kompleks) = &hasil.kompleks {
+        baris.push(format!("  λ = {} (kompleks)", kompleks.tampil(t)));
+    }
+    baris.push(if hasil.diagonal {
+        "  A dapat didiagonalkan: A = PDP⁻¹".to_string()
+    } else if hasil.kompleks.is_some() {
+        "  A tidak dapat didiagonalkan atas ℝ".to_string()
+    } else {
+        "  A tidak dapat didiagonalkan".to_string()
+    });
+    Ok(format!("Hasil Akhir:\n{}", baris.join("\n")))
+}
+
+// Ringkasan metode iterasi: hampiran terakhir beserta status konvergensinya
+fn iteratif_akhir(hasil: Konvergensi, nama: &[String], x: &[f64], maks: usize) -> String {
+    let status = match hasil {
+        Konvergensi::Konvergen(k) => format!("Konvergen setelah {} iterasi", k),
+        Konvergensi::Divergen(k) => {
+            return format!(
+                "Hasil Akhir:\n  Divergen (nilai membesar tanpa batas pada iterasi ke-{})",
+                k
+            );
+        }
+        Konvergensi::DiagonalNol(i) => {
+            return format!(
+                "Hasil Akhir:\n  Iterasi tidak dapat dilakukan (a{}{} = 0)",
+                i + 1,
+                i + 1
+            );
+        }
+        Konvergensi::BelumKonvergen => format!("Belum konvergen setelah {} iterasi", maks),
+    };
+    let baris: Vec<String> = nama
+        .iter()
+        .zip(x)
+        .map(|(n, v)| format!("  {} ≈ {}", n, hampiran(*v)))
+        .collect();
+    format!("Hasil Akhir:\n  {}\n{}", status, baris.join("\n"))
+}
+
+// Titik data (x, y) dari tabel isian; baris yang kosong seluruhnya dilewati.
+// Mengembalikan posisi sel pertama yang tidak valid.
+fn data_regresi(editor: &EditorMatriks) -> Result<Vec<(Pecahan, Pecahan)>, (usize, usize)> {
+    editor
+        .sel
+        .iter()
+        .enumerate()
+        .filter(|(_, b)| b.iter().any(|s| !s.trim().is_empty()))
+        .map(|(i, b)| {
+            let nilai = |j: usize| hitung(&b[j]).map(|n| n.nilai).map_err(|_| (i, j));
+            Ok((nilai(0)?, nilai(1)?))
+        })
+        .collect()
+}
+
+// Ringkasan garis regresi, koefisien korelasi, dan prediksi
+fn regresi_akhir(regresi: Option<&Regresi>, t: Tampilan) -> Hitung<String> {
+    let Some(regresi) = regresi else {
+        return Ok(
+            "Hasil Akhir:\n  Garis regresi tidak dapat ditentukan (perlu dua nilai x berbeda)"
+                .to_string(),
+        );
+    };
+    let mut teks = format!(
+        "Hasil Akhir:\n  {}\n  m = {}\n  c = {}",
+        regresi.persamaan(t),
+        nilai_akhir(regresi.m, t),
+        nilai_akhir(regresi.c, t)
+    );
+    match (regresi.r(t)?, regresi.r2_teks(t)) {
+        (Some(r), Some(r2)) => teks.push_str(&format!("\n  r = {}\n  R² = {}", r, r2)),
+        _ => teks.push_str("\n  r dan R² tidak terdefinisi (semua nilai y sama)"),
+    }
+    if let Some((x, y)) = regresi.prediksi {
+        teks.push_str(&format!(
+            "\n  Prediksi: x = {} → ŷ = {}",
+            x.tampil(t),
+            nilai_akhir(y, t)
+        ));
+    }
+    Ok(teks)
+}
+
+// Ringkasan hasil kalkulator matriks
+fn matriks_akhir(nama: &str, hasil: &HasilMatriks) -> Teks {
+    match hasil {
+        HasilMatriks::Matriks(m) => {
+            let mut teks = Teks::from("Hasil Akhir:\n");
+            teks.gambar(|t| tulis_matriks(&format!("{} = ", nama), m, Kurung::Siku, "", t));
+            teks
+        }
+        HasilMatriks::Skalar(v) => {
+            Teks::dari(|t| format!("Hasil Akhir:\n  {} = {}", nama, nilai_akhir(*v, t)))
+        }
+        HasilMatriks::Singular => Teks::from("Hasil Akhir:\n  A singular, tidak memiliki invers"),
+    }
+}
+
+// Langkah bilangan kondisi, kosong jika penyelesaiannya tidak tunggal
+fn langkah_kondisi(kondisi: &Option<Kondisi>, t: Tampilan) -> String {
+    kondisi.as_ref().map(|k| k.langkah(t)).unwrap_or_default()
+}
+
+fn peringatan_kondisi(kondisi: &Option<Kondisi>) -> Option<String> {
+    kondisi.as_ref().and_then(Kondisi::peringatan)
+}
+
+// Ringkasan hasil akhir untuk sistem dengan solusi tunggal
+fn hasil_akhir(nilai: &[(&str, Pecahan)], t: Tampilan) -> String {
+    let baris: Vec<String> = nilai
+        .iter()
+        .map(|(nama, v)| format!("  {} = {}", nama, nilai_akhir(*v, t)))
+        .collect();
+    format!("Hasil Akhir:\n{}", baris.join("\n"))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn edit_fields_menyunting_dan_berpindah() {
+        let mut inputs = [String::new(), String::new()];
+        let mut selected = 0;
+        let mut tekan = |key| edit_fields(&mut inputs, &mut selected, key);
+        assert!(matches!(tekan(KeyCode::Char('q')), Suntingan::Keluar));
+        assert!(matches!(tekan(KeyCode::Char('2')), Suntingan::Berubah));
+        // q pada isian yang tidak kosong ditulis seperti huruf lain
+        assert!(matches!(tekan(KeyCode::Char('q')), Suntingan::Berubah));
+        assert!(matches!(tekan(KeyCode::Backspace), Suntingan::Berubah));
+        assert!(matches!(tekan(KeyCode::Left), Suntingan::Berubah));
+        assert!(matches!(tekan(KeyCode::Right), Suntingan::Berubah));
+        assert!(matches!(tekan(KeyCode::Right), Suntingan::Berubah));
+        assert!(matches!(tekan(KeyCode::Enter), Suntingan::Lewat));
+        assert_eq!(inputs, ["2".to_string(), String::new()]);
+        assert_eq!(selected, 1);
+    }
+}
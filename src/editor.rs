@@ -0,0 +1,356 @@
+use crate::solver::ekspresi::{hitung, karakter_valid};
+use crate::solver::iteratif::{MetodeIteratif, parameter_awal};
+use crate::solver::kalkulator::OperasiMatriks;
+use crate::solver::pecahan::Pecahan;
+use crossterm::event::{KeyCode, KeyEvent, KeyModifiers};
+
+pub const MAX_BARIS: usize = 6;
+pub const MAX_KOLOM: usize = 7;
+/// Banyak titik data maksimum pada mode regresi linear
+pub const MAX_DATA: usize = 20;
+
+/// Editor matriks yang ukurannya bisa diubah (baris dan kolom).
+/// Jika `diperbesar`, kolom terakhir adalah ruas kanan (b) dan
+/// kolom baru selalu disisipkan sebelum kolom tersebut.
+#[derive(Clone, PartialEq)]
+pub struct EditorMatriks {
+    pub sel: Vec<Vec<String>>,
+    pub kursor: (usize, usize),
+    pub diperbesar: bool,
+}
+
+impl EditorMatriks {
+    pub fn new(baris: usize, kolom: usize, diperbesar: bool) -> Self {
+        Self {
+            sel: vec![vec![String::new(); kolom]; baris],
+            kursor: (0, 0),
+            diperbesar,
+        }
+    }
+
+    pub fn jumlah_baris(&self) -> usize {
+        self.sel.len()
+    }
+
+    pub fn jumlah_kolom(&self) -> usize {
+        self.sel.first().map_or(0, |b| b.len())
+    }
+
+    /// Jumlah variabel (kolom koefisien) untuk matriks diperbesar
+    pub fn jumlah_variabel(&self) -> usize {
+        self.jumlah_kolom() - usize::from(self.diperbesar)
+    }
+
+    /// Pesan galat jika matriks koefisien A tidak persegi, ditampilkan di
+    /// bawah isian dan membuat Enter tidak menghitung
+    pub fn galat_persegi(&self) -> Option<String> {
+        let (baris, n) = (self.jumlah_baris(), self.jumlah_variabel());
+        (baris != n).then(|| format!("A berukuran {}×{} — matriks harus persegi (n×n)", baris, n))
+    }
+
+    fn min_kolom(&self) -> usize {
+        1 + usize::from(self.diperbesar)
+    }
+
+    pub fn tambah_baris(&mut self) {
+        if self.jumlah_baris() < MAX_BARIS {
+            let kolom = self.jumlah_kolom();
+            self.sel.push(vec![String::new(); kolom]);
+        }
+    }
+
+    pub fn hapus_baris(&mut self) {
+        if self.jumlah_baris() > 1 {
+            self.sel.pop();
+            self.kursor.0 = self.kursor.0.min(self.jumlah_baris() - 1);
+        }
+    }
+
+    pub fn tambah_kolom(&mut self) {
+        if self.jumlah_kolom() < MAX_KOLOM {
+            let posisi = self.jumlah_variabel();
+            for b in &mut self.sel {
+                b.insert(posisi, String::new());
+            }
+        }
+    }
+
+    pub fn hapus_kolom(&mut self) {
+        if self.jumlah_kolom() > self.min_kolom() {
+            let posisi = self.jumlah_variabel() - 1;
+            for b in &mut self.sel {
+                b.remove(posisi);
+            }
+            self.kursor.1 = self.kursor.1.min(self.jumlah_kolom() - 1);
+        }
+    }
+
+    pub fn sel_aktif(&mut self) -> &mut String {
+        &mut self.sel[self.kursor.0][self.kursor.1]
+    }
+
+    /// Menangani tombol navigasi dan pengubah ukuran.
+    /// Mengembalikan `true` jika tombol sudah ditangani.
+    pub fn on_key(&mut self, key: KeyEvent) -> bool {
+        let ctrl = key.modifiers.contains(KeyModifiers::CONTROL);
+        match key.code {
+            KeyCode::Down if ctrl => self.tambah_baris(),
+            KeyCode::Up if ctrl => self.hapus_baris(),
+            KeyCode::Right if ctrl => self.tambah_kolom(),
+            KeyCode::Left if ctrl => self.hapus_kolom(),
+            KeyCode::Up => self.kursor.0 = self.kursor.0.saturating_sub(1),
+            KeyCode::Down => self.kursor.0 = (self.kursor.0 + 1).min(self.jumlah_baris() - 1),
+            KeyCode::Left => self.kursor.1 = self.kursor.1.saturating_sub(1),
+            KeyCode::Right => self.kursor.1 = (self.kursor.1 + 1).min(self.jumlah_kolom() - 1),
+            KeyCode::Backspace => {
+                self.sel_aktif().pop();
+            }
+            KeyCode::Char(c) if karakter_valid(c) => self.sel_aktif().push(c),
+            _ => return false,
+        }
+        true
+    }
+
+    /// Menghitung semua sel menjadi pecahan, atau posisi sel pertama yang tidak valid
+    pub fn angka(&self) -> Result<Vec<Vec<Pecahan>>, (usize, usize)> {
+        self.sel
+            .iter()
+            .enumerate()
+            .map(|(i, b)| {
+                b.iter()
+                    .enumerate()
+                    .map(|(j, s)| hitung(s).map(|n| n.nilai).map_err(|_| (i, j)))
+                    .collect()
+            })
+            .collect()
+    }
+}
+
+/// Isian kalkulator matriks: matriks A, matriks B atau skalar k
+/// (tergantung operasi), dan bagian yang sedang disunting
+/// (0 = A, 1 = B atau k).
+#[derive(Clone, PartialEq)]
+pub struct KalkulatorMatriks {
+    pub a: EditorMatriks,
+    pub b: EditorMatriks,
+    pub k: String,
+    pub aktif: usize,
+    pub operasi: OperasiMatriks,
+}
+
+impl Default for KalkulatorMatriks {
+    fn default() -> Self {
+        Self {
+            a: EditorMatriks::new(2, 2, false),
+            b: EditorMatriks::new(2, 2, false),
+            k: String::new(),
+            aktif: 0,
+            operasi: OperasiMatriks::Jumlah,
+        }
+    }
+}
+
+impl KalkulatorMatriks {
+    /// Ukuran A dan B (baris, kolom)
+    pub fn ukuran(&self) -> ((usize, usize), (usize, usize)) {
+        (
+            (self.a.jumlah_baris(), self.a.jumlah_kolom()),
+            (self.b.jumlah_baris(), self.b.jumlah_kolom()),
+        )
+    }
+
+    /// Pesan kesalahan jika ukuran A dan B tidak cocok untuk operasi terpilih
+    pub fn galat_ukuran(&self) -> Option<String> {
+        let (a, b) = self.ukuran();
+        self.operasi.periksa(a, b).err()
+    }
+
+    /// Apakah operasi terpilih memakai isian kedua (B atau k)
+    pub fn pakai_kedua(&self) -> bool {
+        self.operasi.pakai_b() || self.operasi.pakai_k()
+    }
+
+    /// Isian yang sedang disunting kosong (tombol q berarti keluar)
+    pub fn kosong(&mut self) -> bool {
+        match self.aktif {
+            0 => self.a.sel_aktif().is_empty(),
+            _ if self.operasi.pakai_k() => self.k.is_empty(),
+            _ => self.b.sel_aktif().is_empty(),
+        }
+    }
+
+    /// Menangani tombol navigasi dan isian. ← di kolom pertama B/k kembali
+    /// ke A dan → di kolom terakhir A pindah ke B/k.
+    pub fn on_key(&mut self, key: KeyEvent) -> bool {
+        let ctrl = key.modifiers.contains(KeyModifiers::CONTROL);
+        if self.aktif == 1 && !self.pakai_kedua() {
+            self.aktif = 0;
+        }
+        match (self.aktif, key.code) {
+            (0, KeyCode::Right)
+                if !ctrl && self.pakai_kedua() && self.a.kursor.1 + 1 == self.a.jumlah_kolom() =>
+            {
+                self.aktif = 1;
+                self.b.kursor = (self.a.kursor.0.min(self.b.jumlah_baris() - 1), 0);
+                true
+            }
+            (1, KeyCode::Left) if !ctrl && (self.operasi.pakai_k() || self.b.kursor.1 == 0) => {
+                self.aktif = 0;
+                self.a.kursor.1 = self.a.jumlah_kolom() - 1;
+                true
+            }
+            (0, _) => self.a.on_key(key),
+            (_, KeyCode::Backspace) if self.operasi.pakai_k() => {
+                self.k.pop();
+                true
+            }
+            (_, KeyCode::Char(c)) if self.operasi.pakai_k() && karakter_valid(c) => {
+                self.k.push(c);
+                true
+            }
+            _ if self.operasi.pakai_k() => false,
+            _ => self.b.on_key(key),
+        }
+    }
+}
+
+/// Isian metode iterasi: matriks diperbesar [A | b], parameter iterasi
+/// (tebakan awal, toleransi, batas iterasi, ω), dan metode terpilih
+#[derive(Clone, PartialEq)]
+pub struct IsianIteratif {
+    pub matriks: EditorMatriks,
+    pub parameter: [String; 4],
+    /// `None` saat menyunting matriks, `Some(i)` saat menyunting parameter ke-i
+    pub aktif: Option<usize>,
+    pub metode: MetodeIteratif,
+}
+
+impl Default for IsianIteratif {
+    fn default() -> Self {
+        Self {
+            matriks: EditorMatriks::new(3, 4, true),
+            parameter: parameter_awal(),
+            aktif: None,
+            metode: MetodeIteratif::Jacobi,
+        }
+    }
+}
+
+impl IsianIteratif {
+    /// Isian yang sedang disunting kosong (tombol q berarti keluar)
+    pub fn kosong(&mut self) -> bool {
+        match self.aktif {
+            Some(i) => self.parameter[i].is_empty(),
+            None => self.matriks.sel_aktif().is_empty(),
+        }
+    }
+
+    /// Menangani tombol navigasi dan isian. ↓ di baris terakhir matriks
+    /// pindah ke parameter, ↑ di parameter kembali ke matriks.
+    pub fn on_key(&mut self, key: KeyEvent) -> bool {
+        let ctrl = key.modifiers.contains(KeyModifiers::CONTROL);
+        let terakhir = self.parameter.len() - 1;
+        match (self.aktif, key.code) {
+            (None, KeyCode::Down)
+                if !ctrl && self.matriks.kursor.0 + 1 == self.matriks.jumlah_baris() =>
+            {
+                self.aktif = Some(self.matriks.kursor.1.min(terakhir));
+            }
+            (None, _) => return self.matriks.on_key(key),
+            (Some(_), KeyCode::Up) if !ctrl => self.aktif = None,
+            (Some(i), KeyCode::Left) if !ctrl => self.aktif = Some(i.saturating_sub(1)),
+            (Some(i), KeyCode::Right) if !ctrl => self.aktif = Some((i + 1).min(terakhir)),
+            (Some(i), KeyCode::Backspace) => {
+                self.parameter[i].pop();
+            }
+            // Tebakan awal boleh berisi beberapa nilai yang dipisah titik koma
+            (Some(i), KeyCode::Char(c)) if karakter_valid(c) || c == ';' => {
+                self.parameter[i].push(c)
+            }
+            _ => return false,
+        }
+        true
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn ctrl(code: KeyCode) -> KeyEvent {
+        KeyEvent::new(code, KeyModifiers::CONTROL)
+    }
+
+    fn isi(editor: &mut EditorMatriks) {
+        for (i, b) in editor.sel.iter_mut().enumerate() {
+            for (j, s) in b.iter_mut().enumerate() {
+                *s = format!("{}{}", i, j);
+            }
+        }
+    }
+
+    #[test]
+    fn tambah_dan_hapus_baris() {
+        let mut e = EditorMatriks::new(2, 3, true);
+        assert!(e.on_key(ctrl(KeyCode::Down)));
+        assert_eq!((e.jumlah_baris(), e.jumlah_kolom()), (3, 3));
+        for _ in 0..MAX_BARIS {
+            e.on_key(ctrl(KeyCode::Down));
+        }
+        assert_eq!(e.jumlah_baris(), MAX_BARIS);
+
+        e.kursor = (MAX_BARIS - 1, 0);
+        assert!(e.on_key(ctrl(KeyCode::Up)));
+        assert_eq!(e.jumlah_baris(), MAX_BARIS - 1);
+        assert_eq!(e.kursor, (MAX_BARIS - 2, 0));
+        for _ in 0..MAX_BARIS {
+            e.on_key(ctrl(KeyCode::Up));
+        }
+        assert_eq!(e.jumlah_baris(), 1);
+        assert_eq!(e.kursor, (0, 0));
+    }
+
+    #[test]
+    fn kolom_baru_disisipkan_sebelum_ruas_kanan() {
+        let mut e = EditorMatriks::new(2, 3, true);
+        isi(&mut e);
+        assert!(e.on_key(ctrl(KeyCode::Right)));
+        assert_eq!(e.sel[0], ["00", "01", "", "02"]);
+        assert_eq!(e.sel[1], ["10", "11", "", "12"]);
+        assert_eq!(e.jumlah_variabel(), 3);
+        for _ in 0..MAX_KOLOM {
+            e.on_key(ctrl(KeyCode::Right));
+        }
+        assert_eq!(e.jumlah_kolom(), MAX_KOLOM);
+    }
+
+    #[test]
+    fn hapus_kolom_menyisakan_ruas_kanan() {
+        let mut e = EditorMatriks::new(1, 3, true);
+        isi(&mut e);
+        e.kursor = (0, 2);
+        assert!(e.on_key(ctrl(KeyCode::Left)));
+        assert_eq!(e.sel[0], ["00", "02"]);
+        assert_eq!(e.kursor, (0, 1));
+        // Paling sedikit satu koefisien dan ruas kanan
+        e.on_key(ctrl(KeyCode::Left));
+        assert_eq!(e.sel[0], ["00", "02"]);
+
+        let mut e = EditorMatriks::new(1, 2, false);
+        isi(&mut e);
+        e.on_key(ctrl(KeyCode::Left));
+        e.on_key(ctrl(KeyCode::Left));
+        assert_eq!(e.sel[0], ["00"]);
+    }
+
+    #[test]
+    fn panah_tanpa_ctrl_hanya_memindah_kursor() {
+        let mut e = EditorMatriks::new(2, 2, false);
+        e.on_key(KeyEvent::from(KeyCode::Down));
+        e.on_key(KeyEvent::from(KeyCode::Down));
+        e.on_key(KeyEvent::from(KeyCode::Right));
+        assert_eq!(e.kursor, (1, 1));
+        assert_eq!((e.jumlah_baris(), e.jumlah_kolom()), (2, 2));
+        assert!(!e.on_key(KeyEvent::from(KeyCode::Enter)));
+    }
+}
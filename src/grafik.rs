@@ -0,0 +1,509 @@
+use crate::solver::daerah::{Daerah, JenisDaerah};
+use crate::solver::gauss::JenisSolusi;
+use crate::solver::pecahan::Pecahan;
+use crate::solver::pertidaksamaan::{Himpunan, Relasi};
+use crate::solver::splkdv::{Perpotongan, Titik};
+
+/// Data dan tampilan grafik garis SPLDV, daerah penyelesaian
+/// pertidaksamaan, parabola persamaan kuadrat, atau kurva SPLKDV pada layar
+/// hasil. Pusat dan lebar tampilan bisa digeser dan diperbesar.
+#[derive(Clone, PartialEq)]
+pub struct Grafik {
+    /// Garis ax + by = c yang tidak trivial, beserta nomor persamaannya
+    pub garis: Vec<(usize, [f64; 3])>,
+    /// Titik potong yang ditandai ●
+    pub titik: Vec<(f64, f64)>,
+    pub jenis: JenisSolusi,
+    /// Daerah yang diarsir untuk sistem pertidaksamaan
+    pub daerah: Option<Daerah>,
+    /// Kurva y = ax² + bx + c; satu untuk persamaan kuadrat, dua untuk SPLKDV
+    pub kurva: Vec<Parabola>,
+    pub perpotongan: Option<Perpotongan>,
+    /// Titik data regresi linear yang ditandai •
+    pub data: Vec<(f64, f64)>,
+    pub pusat: (f64, f64),
+    /// Setengah lebar sumbu x yang terlihat
+    pub skala: f64,
+    awal: ((f64, f64), f64),
+}
+
+impl Grafik {
+    pub fn spldv(k: [Pecahan; 6], jenis: JenisSolusi, titik: Option<(Pecahan, Pecahan)>) -> Self {
+        let k = k.map(|v| v.ke_f64());
+        let garis: Vec<(usize, [f64; 3])> = [[k[0], k[1], k[2]], [k[3], k[4], k[5]]]
+            .into_iter()
+            .enumerate()
+            .filter(|(_, [a, b, _])| *a != 0.0 || *b != 0.0)
+            .map(|(i, g)| (i + 1, g))
+            .collect();
+        let titik: Vec<(f64, f64)> = titik
+            .map(|(x, y)| (x.ke_f64(), y.ke_f64()))
+            .into_iter()
+            .collect();
+
+        // Pusatkan pada titik potong, atau pada titik garis terdekat ke titik asal
+        let pusat = titik
+            .first()
+            .copied()
+            .unwrap_or_else(|| match garis.first() {
+                Some((_, [a, b, c])) => {
+                    let n = a * a + b * b;
+                    (a * c / n, b * c / n)
+                }
+                None => (0.0, 0.0),
+            });
+        let jarak = pusat.0.abs().max(pusat.1.abs());
+        let skala = (jarak * 1.5).max(5.0);
+        Self {
+            garis,
+            titik,
+            jenis,
+            daerah: None,
+            kurva: Vec::new(),
+            perpotongan: None,
+            data: Vec::new(),
+            pusat,
+            skala,
+            awal: (pusat, skala),
+        }
+    }
+
+    /// Garis batas dan daerah penyelesaian sistem pertidaksamaan dua variabel
+    pub fn daerah(daerah: Daerah) -> Self {
+        let garis: Vec<(usize, [f64; 3])> = daerah
+            .batasan
+            .iter()
+            .enumerate()
+            .filter(|(_, ([a, b, _], _))| !a.is_nol() || !b.is_nol())
+            .map(|(i, (k, _))| (i + 1, k.map(|v| v.ke_f64())))
+            .collect();
+
+        // Tampilkan semua titik pojok beserta titik asal
+        let titik: Vec<(f64, f64)> = daerah
+            .pojok
+            .iter()
+            .map(|((x, y), _)| (x.ke_f64(), y.ke_f64()))
+            .chain(std::iter::once((0.0, 0.0)))
+            .collect();
+        let (x0, x1) = titik.iter().fold((f64::MAX, f64::MIN), |(lo, hi), p| {
+            (lo.min(p.0), hi.max(p.0))
+        });
+        let (y0, y1) = titik.iter().fold((f64::MAX, f64::MIN), |(lo, hi), p| {
+            (lo.min(p.1), hi.max(p.1))
+        });
+        let pusat = ((x0 + x1) / 2.0, (y0 + y1) / 2.0);
+        let skala = ((x1 - x0).max(y1 - y0) * 0.75 + 1.0).max(5.0);
+        let jenis = match daerah.jenis {
+            JenisDaerah::Kosong => JenisSolusi::TidakAda,
+            _ => JenisSolusi::TakHingga,
+        };
+        Self {
+            garis,
+            titik: Vec::new(),
+            jenis,
+            daerah: Some(daerah),
+            kurva: Vec::new(),
+            perpotongan: None,
+            data: Vec::new(),
+            pusat,
+            skala,
+            awal: (pusat, skala),
+        }
+    }
+
+    /// Parabola y = ax² + bx + c dengan akar-akar realnya (titik potong sumbu x)
+    pub fn parabola(k: [Pecahan; 3], akar: Vec<f64>) -> Self {
+        let parabola = Parabola {
+            k: k.map(|v| v.ke_f64()),
+        };
+        // Puncak di bagian bawah (atau atas) tampilan, sumbu x tetap terlihat
+        let (pusat, skala) = match parabola.puncak() {
+            Some((xp, yp)) => {
+                let a = parabola.k[0];
+                let lebar = akar
+                    .iter()
+                    .map(|x| (x - xp).abs())
+                    .fold((yp / a).abs().sqrt(), f64::max);
+                let skala = (lebar * 1.5).max(yp.abs()).max(5.0);
+                ((xp, yp + a.signum() * skala * 0.5), skala)
+            }
+            None => {
+                let x0 = akar.first().copied().unwrap_or(0.0);
+                ((x0, 0.0), (x0.abs() * 1.5).max(5.0))
+            }
+        };
+        let jenis = if akar.is_empty() {
+            JenisSolusi::TidakAda
+        } else {
+            JenisSolusi::Tunggal
+        };
+        Self {
+            garis: Vec::new(),
+            titik: akar.into_iter().map(|x| (x, 0.0)).collect(),
+            jenis,
+            daerah: None,
+            kurva: vec![parabola],
+            perpotongan: None,
+            data: Vec::new(),
+            pusat,
+            skala,
+            awal: (pusat, skala),
+        }
+    }
+
+    /// Dua kurva SPLKDV beserta titik potongnya
+    pub fn splkdv(k: [[Pecahan; 3]; 2], perpotongan: Perpotongan, titik: &[Titik]) -> Self {
+        let kurva = k.map(|k| Parabola {
+            k: k.map(|v| v.ke_f64()),
+        });
+        let titik: Vec<(f64, f64)> = titik
+            .iter()
+            .map(|(x, y)| (x.ke_f64(), y.ke_f64()))
+            .collect();
+
+        // Tampilkan titik potong dan puncak setiap parabola
+        let penting: Vec<(f64, f64)> = titik
+            .iter()
+            .copied()
+            .chain(kurva.iter().filter_map(Parabola::puncak))
+            .collect();
+        let (pusat, skala) = if penting.is_empty() {
+            ((0.0, kurva[0].nilai(0.0)), 5.0)
+        } else {
+            let (x0, x1) = penting.iter().fold((f64::MAX, f64::MIN), |(lo, hi), p| {
+                (lo.min(p.0), hi.max(p.0))
+            });
+            let (y0, y1) = penting.iter().fold((f64::MAX, f64::MIN), |(lo, hi), p| {
+                (lo.min(p.1), hi.max(p.1))
+            });
+            let skala = ((x1 - x0).max(y1 - y0) * 0.75 + 1.0).max(5.0);
+            (((x0 + x1) / 2.0, (y0 + y1) / 2.0), skala)
+        };
+        let jenis = match perpotongan {
+            Perpotongan::TidakAda => JenisSolusi::TidakAda,
+            Perpotongan::Berimpit => JenisSolusi::TakHingga,
+            _ => JenisSolusi::Tunggal,
+        };
+        Self {
+            garis: Vec::new(),
+            titik,
+            jenis,
+            daerah: None,
+            kurva: kurva.to_vec(),
+            perpotongan: Some(perpotongan),
+            data: Vec::new(),
+            pusat,
+            skala,
+            awal: (pusat, skala),
+        }
+    }
+
+    /// Titik data dengan garis regresi y = mx + c, beserta titik prediksinya
+    pub fn regresi(
+        data: &[(Pecahan, Pecahan)],
+        m: Pecahan,
+        c: Pecahan,
+        prediksi: Option<(Pecahan, Pecahan)>,
+    ) -> Self {
+        let data: Vec<(f64, f64)> = data.iter().map(|(x, y)| (x.ke_f64(), y.ke_f64())).collect();
+        let titik: Vec<(f64, f64)> = prediksi
+            .map(|(x, y)| (x.ke_f64(), y.ke_f64()))
+            .into_iter()
+            .collect();
+
+        // Tampilkan semua titik data dan titik prediksi
+        let semua: Vec<&(f64, f64)> = data.iter().chain(&titik).collect();
+        let (x0, x1) = semua.iter().fold((f64::MAX, f64::MIN), |(lo, hi), p| {
+            (lo.min(p.0), hi.max(p.0))
+        });
+        let (y0, y1) = semua.iter().fold((f64::MAX, f64::MIN), |(lo, hi), p| {
+            (lo.min(p.1), hi.max(p.1))
+        });
+        let pusat = ((x0 + x1) / 2.0, (y0 + y1) / 2.0);
+        let skala = ((x1 - x0).max(y1 - y0) * 0.75 + 1.0).max(5.0);
+        // y = mx + c ditulis sebagai mx − y = −c
+        let garis = vec![(1, [m.ke_f64(), -1.0, -c.ke_f64()])];
+        Self {
+            garis,
+            titik,
+            jenis: JenisSolusi::Tunggal,
+            daerah: None,
+            kurva: Vec::new(),
+            perpotongan: None,
+            data,
+            pusat,
+            skala,
+            awal: (pusat, skala),
+        }
+    }
+
+    /// Menggeser pusat sejauh sepersepuluh lebar tampilan per langkah
+    pub fn geser(&mut self, dx: f64, dy: f64) {
+        self.pusat.0 += dx * self.skala / 5.0;
+        self.pusat.1 += dy * self.skala / 5.0;
+    }
+
+    /// `faktor` < 1 memperbesar, > 1 memperkecil
+    pub fn zoom(&mut self, faktor: f64) {
+        self.skala = (self.skala * faktor).clamp(0.01, 1e6);
+    }
+
+    pub fn reset(&mut self) {
+        (self.pusat, self.skala) = self.awal;
+    }
+
+    /// Dua titik ujung garis ax + by = c yang terpotong oleh batas tampilan,
+    /// atau `None` jika garis tidak melewati tampilan
+    pub fn ujung(g: [f64; 3], bx: [f64; 2], by: [f64; 2]) -> Option<((f64, f64), (f64, f64))> {
+        let [a, b, c] = g;
+        let mut titik = Vec::new();
+        if b != 0.0 {
+            for x in bx {
+                titik.push((x, (c - a * x) / b));
+            }
+        }
+        if a != 0.0 {
+            for y in by {
+                titik.push(((c - b * y) / a, y));
+            }
+        }
+        let eps = 1e-9 * (bx[1] - bx[0]);
+        let titik: Vec<(f64, f64)> = titik
+            .into_iter()
+            .filter(|(x, y)| {
+                (bx[0] - eps..=bx[1] + eps).contains(x) && (by[0] - eps..=by[1] + eps).contains(y)
+            })
+            // Pembulatan tidak boleh membuat ujung keluar dari batas canvas
+            .map(|(x, y)| (x.clamp(bx[0], bx[1]), y.clamp(by[0], by[1])))
+            .collect();
+        // Ujung terjauh satu sama lain di sepanjang garis
+        let mut titik = titik;
+        titik.sort_by(|p, q| (p.0 * b - p.1 * a).total_cmp(&(q.0 * b - q.1 * a)));
+        match (titik.first(), titik.last()) {
+            (Some(&p), Some(&q)) if p != q => Some((p, q)),
+            _ => None,
+        }
+    }
+}
+
+/// Kurva y = ax² + bx + c (garis lurus jika a = 0)
+#[derive(Clone, PartialEq)]
+pub struct Parabola {
+    pub k: [f64; 3],
+}
+
+impl Parabola {
+    pub fn nilai(&self, x: f64) -> f64 {
+        let [a, b, c] = self.k;
+        (a * x + b) * x + c
+    }
+
+    /// Titik puncak, `None` untuk garis lurus (a = 0)
+    pub fn puncak(&self) -> Option<(f64, f64)> {
+        let [a, b, _] = self.k;
+        (a != 0.0).then(|| {
+            let x = -b / (2.0 * a);
+            (x, self.nilai(x))
+        })
+    }
+}
+
+/// Ujung selang pada garis bilangan. Titik tertutup (●) termasuk
+/// dalam himpunan penyelesaian, titik terbuka (○) tidak.
+#[derive(Clone, Copy, PartialEq)]
+pub struct Batas {
+    pub nilai: Pecahan,
+    pub tertutup: bool,
+}
+
+/// Garis bilangan untuk himpunan penyelesaian satu variabel:
+/// titik-titik tunggal dan selang yang diarsir
+#[derive(Clone, PartialEq)]
+pub struct GarisBilangan {
+    pub titik: Vec<Batas>,
+    /// Selang (bawah, atas); `None` berarti tak hingga ke arah tersebut
+    pub selang: Vec<(Option<Batas>, Option<Batas>)>,
+}
+
+impl GarisBilangan {
+    /// Penyelesaian tunggal x = nilai
+    pub fn titik(nilai: Pecahan) -> Self {
+        Self {
+            titik: vec![Batas {
+                nilai,
+                tertutup: true,
+            }],
+            selang: Vec::new(),
+        }
+    }
+
+    /// Setiap bilangan real adalah penyelesaian
+    pub fn semua() -> Self {
+        Self {
+            titik: Vec::new(),
+            selang: vec![(None, None)],
+        }
+    }
+
+    /// Tidak ada penyelesaian
+    pub fn kosong() -> Self {
+        Self {
+            titik: Vec::new(),
+            selang: Vec::new(),
+        }
+    }
+
+    /// Himpunan penyelesaian pertidaksamaan satu variabel
+    pub fn pertidaksamaan(himpunan: Himpunan) -> Self {
+        let (relasi, nilai) = match himpunan {
+            Himpunan::Selang(relasi, nilai) => (relasi, nilai),
+            Himpunan::Semua => return Self::semua(),
+            Himpunan::Kosong => return Self::kosong(),
+        };
+        let batas = Some(Batas {
+            nilai,
+            tertutup: relasi.tertutup(),
+        });
+        let selang = match relasi {
+            Relasi::Kurang | Relasi::KurangSama => (None, batas),
+            Relasi::Lebih | Relasi::LebihSama => (batas, None),
+        };
+        Self {
+            titik: Vec::new(),
+            selang: vec![selang],
+        }
+    }
+
+    fn batas(&self) -> impl Iterator<Item = &Batas> {
+        self.titik.iter().chain(
+            self.selang
+                .iter()
+                .flat_map(|(a, b)| a.iter().chain(b.iter())),
+        )
+    }
+
+    /// Rentang tampilan (min, maks) dan jarak tanda skala untuk sekitar
+    /// `jumlah_tanda` tanda. Rentang selalu memuat 0 dan semua ujung selang.
+    pub fn skala(&self, jumlah_tanda: usize) -> (f64, f64, f64) {
+        let nilai: Vec<f64> = self.batas().map(|b| b.nilai.ke_f64()).collect();
+        let lo = nilai.iter().copied().fold(0.0, f64::min);
+        let hi = nilai.iter().copied().fold(0.0, f64::max);
+        let rentang = if hi - lo > 0.0 { hi - lo } else { 10.0 };
+        let langkah = langkah_rapi(rentang / jumlah_tanda.max(2) as f64);
+        let min = (lo / langkah).floor() * langkah - langkah;
+        let maks = (hi / langkah).ceil() * langkah + langkah;
+        (min, maks, langkah)
+    }
+}
+
+// Jarak tanda skala yang "rapi": 1, 2, atau 5 dikali pangkat sepuluh
+fn langkah_rapi(kasar: f64) -> f64 {
+    let pangkat = 10f64.powf(kasar.log10().floor());
+    let sisa = kasar / pangkat;
+    let kelipatan = if sisa <= 1.0 {
+        1.0
+    } else if sisa <= 2.0 {
+        2.0
+    } else if sisa <= 5.0 {
+        5.0
+    } else {
+        10.0
+    };
+    kelipatan * pangkat
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::solver::pecahan::uji::larik;
+
+    #[test]
+    fn spldv_dipusatkan_pada_titik_potong() {
+        // x + y = 5, x − y = 1
+        let titik = Some((Pecahan::from(3), Pecahan::from(2)));
+        let g = Grafik::spldv(larik([1, 1, 5, 1, -1, 1]), JenisSolusi::Tunggal, titik);
+        assert_eq!(g.garis.len(), 2);
+        assert_eq!(g.titik, [(3.0, 2.0)]);
+        assert_eq!(g.pusat, (3.0, 2.0));
+        assert_eq!(g.skala, 5.0);
+    }
+
+    #[test]
+    fn spldv_tanpa_titik_potong_dipusatkan_pada_garis() {
+        // 0x + 0y = 0 tidak digambar; garis y = 10 dipusatkan di (0, 10)
+        let g = Grafik::spldv(larik([0, 1, 10, 0, 0, 0]), JenisSolusi::TakHingga, None);
+        assert_eq!(g.garis, [(1, [0.0, 1.0, 10.0])]);
+        assert_eq!(g.pusat, (0.0, 10.0));
+        assert_eq!(g.skala, 15.0);
+    }
+
+    #[test]
+    fn geser_zoom_dan_reset() {
+        let mut g = Grafik::spldv(larik([1, 0, 0, 0, 1, 0]), JenisSolusi::Tunggal, None);
+        g.geser(1.0, -1.0);
+        assert_eq!(g.pusat, (1.0, -1.0));
+        g.zoom(0.5);
+        assert_eq!(g.skala, 2.5);
+        g.zoom(1e-9);
+        assert_eq!(g.skala, 0.01);
+        g.reset();
+        assert_eq!((g.pusat, g.skala), ((0.0, 0.0), 5.0));
+    }
+
+    #[test]
+    fn ujung_garis_dipotong_batas_tampilan() {
+        let batas = [-5.0, 5.0];
+        // y = x memotong dua sudut
+        let (p, q) = Grafik::ujung([1.0, -1.0, 0.0], batas, batas).unwrap();
+        assert_eq!([p.0.min(q.0), p.0.max(q.0)], batas);
+        assert!(p.0 == p.1 && q.0 == q.1);
+        // Garis tegak x = 2
+        let (p, q) = Grafik::ujung([1.0, 0.0, 2.0], batas, batas).unwrap();
+        assert_eq!((p.0, q.0), (2.0, 2.0));
+        assert_eq!([p.1.min(q.1), p.1.max(q.1)], batas);
+        // x + y = 20 tidak melewati tampilan
+        assert_eq!(Grafik::ujung([1.0, 1.0, 20.0], batas, batas), None);
+    }
+
+    fn hampir(a: f64, b: f64) -> bool {
+        (a - b).abs() < 1e-9
+    }
+
+    #[test]
+    fn langkah_skala_satu_dua_atau_lima() {
+        for (kasar, rapi) in [
+            (1.0, 1.0),
+            (1.5, 2.0),
+            (0.3, 0.5),
+            (7.0, 10.0),
+            (25.0, 50.0),
+            (0.012, 0.02),
+        ] {
+            assert!(hampir(langkah_rapi(kasar), rapi), "{} → {}", kasar, rapi);
+        }
+    }
+
+    #[test]
+    fn skala_memuat_nol_dan_titik() {
+        // Rentang 0..3 dengan lima tanda: langkah 1, satu langkah di kedua sisi
+        let (min, maks, langkah) = GarisBilangan::titik(Pecahan::from(3)).skala(5);
+        assert!(hampir(min, -1.0) && hampir(maks, 4.0) && hampir(langkah, 1.0));
+
+        let (min, maks, langkah) = GarisBilangan::titik(Pecahan::new(-25, 2)).skala(5);
+        assert!(hampir(langkah, 5.0));
+        assert!(min < -12.5 && maks > 0.0);
+    }
+
+    #[test]
+    fn skala_tanpa_ujung_berpusat_di_nol() {
+        for gb in [GarisBilangan::kosong(), GarisBilangan::semua()] {
+            let (min, maks, langkah) = gb.skala(5);
+            assert!(hampir(min, -2.0) && hampir(maks, 2.0) && hampir(langkah, 2.0));
+        }
+        // Paling sedikit dua tanda
+        assert_eq!(
+            GarisBilangan::kosong().skala(0),
+            GarisBilangan::kosong().skala(2)
+        );
+    }
+}
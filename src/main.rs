@@ -12,6 +12,34 @@ use std::io::{self, Read};
 use std::path::PathBuf;
 
 mod app;
+mod editor;
+mod grafik;
+mod solver {
+    pub mod daerah;
+    pub mod dekomposisi;
+    pub mod eigen;
+    pub mod ekspresi;
+    pub mod format;
+    pub mod gauss;
+    pub mod iteratif;
+    pub mod kalkulator;
+    pub mod kondisi;
+    pub mod kuadrat;
+    pub mod kuadrat_terkecil;
+    pub mod matriks;
+    pub mod metode;
+    pub mod parameter;
+    pub mod pecahan;
+    pub mod pertidaksamaan;
+    pub mod program_linear;
+    pub mod regresi;
+    pub mod ruang;
+    pub mod spldv;
+    pub mod splkdv;
+    pub mod splsv;
+    pub mod spltv;
+    pub mod substitusi;
+}
 mod ui;
 mod themes {
     pub mod gruvbox;
@@ -26,7 +54,6 @@ use app::App;
     long_about = None,
     name = "SPLTUI"
 )]
-
 struct Args {
     #[arg(short, long)]
     verbose: bool,
@@ -39,6 +66,8 @@ struct Args {
     #[arg(long)]
     spldv: bool,
     #[arg(long)]
+    spltv: bool,
+    #[arg(long)]
     hasil: bool,
 }
 
@@ -59,7 +88,7 @@ fn main() -> Result<(), io::Error> {
     let mut terminal = Terminal::new(backend)?;
 
     // Create app and run main loop
-    let mut app = App::new(args.splsv, args.spldv, args.hasil);
+    let mut app = App::new(args.splsv, args.spldv, args.spltv, args.hasil);
     run_app(&mut terminal, &mut app, args.verbose, args.theme.clone())?;
 
     // Restore terminal
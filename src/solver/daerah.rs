@@ -0,0 +1,442 @@
+use crate::solver::format::{Teks, hubungan};
+use crate::solver::pecahan::{Hitung, Pecahan};
+use crate::solver::pertidaksamaan::Relasi;
+
+/// Pertidaksamaan ax + by (relasi) c
+pub type Batasan = ([Pecahan; 3], Relasi);
+
+/// Bentuk daerah penyelesaian sistem pertidaksamaan dua variabel
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum JenisDaerah {
+    Kosong,
+    Terbatas,
+    TakTerbatas,
+}
+
+impl JenisDaerah {
+    pub fn nama(self) -> &'static str {
+        match self {
+            JenisDaerah::Kosong => "Daerah penyelesaian kosong",
+            JenisDaerah::Terbatas => "Daerah penyelesaian terbatas",
+            JenisDaerah::TakTerbatas => "Daerah penyelesaian tidak terbatas",
+        }
+    }
+}
+
+/// Daerah penyelesaian beserta titik-titik pojoknya
+#[derive(Clone, PartialEq)]
+pub struct Daerah {
+    pub batasan: Vec<Batasan>,
+    /// Titik pojok berurutan mengelilingi daerah, beserta keterangan
+    /// apakah titik tersebut termasuk daerah (tidak, jika terletak pada
+    /// garis batas dengan tanda < atau >)
+    pub pojok: Vec<((Pecahan, Pecahan), bool)>,
+    pub jenis: JenisDaerah,
+}
+
+impl Daerah {
+    /// Apakah titik (x, y) berada di daerah penyelesaian (termasuk batasnya),
+    /// dipakai untuk mengarsir daerah pada grafik
+    pub fn memuat(&self, x: f64, y: f64) -> bool {
+        self.batasan.iter().all(|([a, b, c], r)| {
+            let kiri = a.ke_f64() * x + b.ke_f64() * y;
+            let c = c.ke_f64();
+            let eps = 1e-9 * (1.0 + c.abs());
+            match r.tutup() {
+                Relasi::LebihSama => kiri >= c - eps,
+                _ => kiri <= c + eps,
+            }
+        })
+    }
+}
+
+// Nomor pertidaksamaan pertama yang tidak dipenuhi oleh (x, y). Jika
+// `ketat` salah, tanda < dan > dianggap ≤ dan ≥ (daerah beserta batasnya).
+fn dilanggar(batasan: &[Batasan], x: Pecahan, y: Pecahan, ketat: bool) -> Hitung<Option<usize>> {
+    for (i, ([a, b, c], r)) in batasan.iter().enumerate() {
+        let r = if ketat { *r } else { r.tutup() };
+        if !r.dipenuhi(ruas_kiri(a, b, x, y)?, *c) {
+            return Ok(Some(i));
+        }
+    }
+    Ok(None)
+}
+
+// Nilai ax + by
+fn ruas_kiri(a: &Pecahan, b: &Pecahan, x: Pecahan, y: Pecahan) -> Hitung<Pecahan> {
+    a.kali(x)?.tambah(b.kali(y)?)
+}
+
+// Titik di bagian dalam relatif daerah tertutup P (tanda < dan > dianggap
+// ≤ dan ≥), yaitu rata-rata titik-titik P yang diketahui ditambah jumlah
+// semua arah tak terbatasnya. Garis batas yang memuat titik ini memuat
+// seluruh P, sehingga daerah dengan tanda < atau > asli tidak kosong tepat
+// ketika titik ini memenuhi semua pertidaksamaan.
+fn titik_dalam(batasan: &[Batasan], titik: &[(Pecahan, Pecahan)]) -> Hitung<(Pecahan, Pecahan)> {
+    let n = Pecahan::from(titik.len() as i128);
+    let (mut x, mut y) = (
+        Pecahan::jumlah(titik.iter().map(|t| t.0))?.bagi(n)?,
+        Pecahan::jumlah(titik.iter().map(|t| t.1))?.bagi(n)?,
+    );
+    // Arah sejajar garis batas dan arah normal ke dalam sudah mencakup
+    // semua pembangkit kerucut arah tak terbatas di bidang
+    let mut arah = arah_tak_terbatas(batasan)?;
+    for ([a, b, _], r) in batasan {
+        let d = match r.tutup() {
+            Relasi::LebihSama => (*a, *b),
+            _ => (-*a, -*b),
+        };
+        if (!a.is_nol() || !b.is_nol()) && dapat_ditempuh(batasan, d)? && !arah.contains(&d) {
+            arah.push(d);
+        }
+    }
+    for (dx, dy) in arah {
+        x = x.tambah(dx)?;
+        y = y.tambah(dy)?;
+    }
+    Ok((x, y))
+}
+
+// Apakah arah d bisa ditempuh tanpa batas: n·d ≤ 0 untuk setiap normal
+// n yang menghadap ke luar daerah
+fn dapat_ditempuh(batasan: &[Batasan], (dx, dy): (Pecahan, Pecahan)) -> Hitung<bool> {
+    for ([a, b, _], r) in batasan {
+        let (nx, ny) = match r.tutup() {
+            Relasi::LebihSama => (-*a, -*b),
+            _ => (*a, *b),
+        };
+        if ruas_kiri(&nx, &ny, dx, dy)? > Pecahan::NOL {
+            return Ok(false);
+        }
+    }
+    Ok(true)
+}
+
+/// Arah d yang bisa ditempuh tanpa batas dari setiap titik daerah, yaitu
+/// n·d ≤ 0 untuk setiap normal n yang menghadap ke luar daerah. Jika ada,
+/// arah seperti itu selalu bisa dipilih sejajar dengan salah satu garis batas,
+/// sehingga cukup diperiksa arah-arah sejajar garis batas.
+pub fn arah_tak_terbatas(batasan: &[Batasan]) -> Hitung<Vec<(Pecahan, Pecahan)>> {
+    let mut arah: Vec<(Pecahan, Pecahan)> = Vec::new();
+    for d in batasan
+        .iter()
+        .filter(|([a, b, _], _)| !a.is_nol() || !b.is_nol())
+        .flat_map(|([a, b, _], _)| [(-*b, *a), (*b, -*a)])
+    {
+        if dapat_ditempuh(batasan, d)? && !arah.contains(&d) {
+            arah.push(d);
+        }
+    }
+    Ok(arah)
+}
+
+// Huruf untuk menamai titik pojok: A, B, C, ...
+pub fn huruf(i: usize) -> char {
+    (b'A' + (i % 26) as u8) as char
+}
+
+//
+// Sistem Pertidaksamaan Linear Dua Variabel
+// Setiap pertidaksamaan ax + by (relasi) c dibatasi oleh garis ax + by = c.
+// Daerah yang memenuhi ditentukan dengan uji titik, lalu titik pojok dicari
+// dari titik potong setiap pasang garis batas yang memenuhi semua pertidaksamaan.
+//
+pub fn daerah_proses(batasan: &[Batasan]) -> Hitung<(Daerah, Teks)> {
+    let tulis = |([a, b, c], r): &Batasan, t| hubungan(&[(*a, "x"), (*b, "y")], r.simbol(), *c, t);
+    let mut steps = Teks::from("Sistem pertidaksamaan:\n");
+    for (i, p) in batasan.iter().enumerate() {
+        steps.tulis(|t| format!("  P{}: {}\n", i + 1, tulis(p, t)));
+    }
+
+    steps.push_str("\nLangkah 1: Gambar garis batas dan uji titik\n");
+    let mut mustahil = false;
+    for (i, ([a, b, c], r)) in batasan.iter().enumerate() {
+        if a.is_nol() && b.is_nol() {
+            let benar = r.dipenuhi(Pecahan::NOL, *c);
+            mustahil |= !benar;
+            steps.tulis(|t| {
+                format!(
+                    "  P{}: 0 {} {} {}\n",
+                    i + 1,
+                    r.simbol(),
+                    c.tampil(t),
+                    if benar {
+                        "selalu benar → tidak membatasi daerah"
+                    } else {
+                        "tidak pernah benar → daerah kosong"
+                    }
+                )
+            });
+            continue;
+        }
+        // Titik uji (0, 0), kecuali jika garis melalui titik asal
+        let (px, py) = if !c.is_nol() {
+            (Pecahan::NOL, Pecahan::NOL)
+        } else if !a.is_nol() {
+            (Pecahan::SATU, Pecahan::NOL)
+        } else {
+            (Pecahan::NOL, Pecahan::SATU)
+        };
+        let kiri = ruas_kiri(a, b, px, py)?;
+        let benar = r.dipenuhi(kiri, *c);
+        steps.tulis(|t| {
+            format!(
+                "  P{}: garis {}{}\n      uji ({}, {}): {} {} {} {} → daerah {} ({}, {})\n",
+                i + 1,
+                hubungan(&[(*a, "x"), (*b, "y")], "=", *c, t),
+                if r.tutup() == *r {
+                    ""
+                } else {
+                    " (garis putus-putus, tidak termasuk)"
+                },
+                px.tampil(t),
+                py.tampil(t),
+                kiri.tampil(t),
+                r.simbol(),
+                c.tampil(t),
+                if benar { "benar" } else { "salah" },
+                if benar { "memuat" } else { "tidak memuat" },
+                px.tampil(t),
+                py.tampil(t)
+            )
+        });
+    }
+
+    let garis: Vec<usize> = (0..batasan.len())
+        .filter(|&i| {
+            let [a, b, _] = batasan[i].0;
+            !a.is_nol() || !b.is_nol()
+        })
+        .collect();
+
+    steps.push_str("\nLangkah 2: Titik potong setiap pasang garis batas\n");
+    let mut pojok: Vec<(Pecahan, Pecahan)> = Vec::new();
+    if garis.len() < 2 {
+        steps.push_str("  Kurang dari dua garis batas, tidak ada titik potong.\n");
+    }
+    for (n, &i) in garis.iter().enumerate() {
+        for &j in &garis[n + 1..] {
+            let ([a1, b1, c1], _) = batasan[i];
+            let ([a2, b2, c2], _) = batasan[j];
+            let d = a1.kali(b2)?.kurang(a2.kali(b1)?)?;
+            if d.is_nol() {
+                steps.tulis(|_| {
+                    format!("  P{} dan P{}: sejajar, tidak berpotongan\n", i + 1, j + 1)
+                });
+                continue;
+            }
+            let x = c1.kali(b2)?.kurang(c2.kali(b1)?)?.bagi(d)?;
+            let y = a1.kali(c2)?.kurang(a2.kali(c1)?)?.bagi(d)?;
+            let keterangan = match dilanggar(batasan, x, y, false)? {
+                Some(k) => format!("✗ tidak memenuhi P{}", k + 1),
+                None if pojok.contains(&(x, y)) => "✓ (titik yang sama)".to_string(),
+                None => {
+                    pojok.push((x, y));
+                    "✓ titik pojok".to_string()
+                }
+            };
+            steps.tulis(|t| {
+                format!(
+                    "  P{} ∩ P{} = ({}, {}) {}\n",
+                    i + 1,
+                    j + 1,
+                    x.tampil(t),
+                    y.tampil(t),
+                    keterangan
+                )
+            });
+        }
+    }
+
+    // Titik-titik daerah tertutup yang diketahui. Tanpa titik pojok, semua
+    // garis batas sejajar, sehingga cukup diperiksa titik asal dan titik
+    // terdekat ke titik asal pada setiap garis.
+    let titik: Vec<(Pecahan, Pecahan)> = if mustahil {
+        Vec::new()
+    } else if !pojok.is_empty() {
+        pojok.clone()
+    } else {
+        let mut titik = Vec::new();
+        for &i in garis.iter() {
+            let [a, b, c] = batasan[i].0;
+            let n = ruas_kiri(&a, &b, a, b)?;
+            titik.push((a.kali(c)?.bagi(n)?, b.kali(c)?.bagi(n)?));
+        }
+        let mut memenuhi = Vec::new();
+        for (x, y) in std::iter::once((Pecahan::NOL, Pecahan::NOL)).chain(titik) {
+            if dilanggar(batasan, x, y, false)?.is_none() {
+                memenuhi.push((x, y));
+            }
+        }
+        memenuhi
+    };
+    // Garis batas bertanda < atau > yang memuat seluruh daerah tertutup
+    // membuat daerah sebenarnya kosong
+    let garis_ketat = if titik.is_empty() {
+        None
+    } else {
+        let (x, y) = titik_dalam(batasan, &titik)?;
+        dilanggar(batasan, x, y, true)?
+    };
+    let ada_titik = !titik.is_empty() && garis_ketat.is_none();
+
+    let arah = arah_tak_terbatas(batasan)?.first().copied();
+
+    let jenis = if !ada_titik {
+        JenisDaerah::Kosong
+    } else if garis.is_empty() || arah.is_some() {
+        JenisDaerah::TakTerbatas
+    } else {
+        JenisDaerah::Terbatas
+    };
+
+    // Urutkan titik pojok mengelilingi titik tengahnya
+    if !pojok.is_empty() {
+        let n = pojok.len() as f64;
+        let cx = pojok.iter().map(|p| p.0.ke_f64()).sum::<f64>() / n;
+        let cy = pojok.iter().map(|p| p.1.ke_f64()).sum::<f64>() / n;
+        pojok.sort_by(|p, q| {
+            let sudut = |t: &(Pecahan, Pecahan)| (t.1.ke_f64() - cy).atan2(t.0.ke_f64() - cx);
+            sudut(p).total_cmp(&sudut(q))
+        });
+    }
+    let pojok: Vec<((Pecahan, Pecahan), bool)> = pojok
+        .into_iter()
+        .map(|(x, y)| Ok(((x, y), dilanggar(batasan, x, y, true)?.is_none())))
+        .collect::<Hitung<_>>()?;
+
+    steps.push_str("\nLangkah 3: Titik pojok daerah penyelesaian\n");
+    if pojok.is_empty() {
+        steps.push_str("  Tidak ada titik pojok.\n");
+    }
+    for (i, ((x, y), termasuk)) in pojok.iter().enumerate() {
+        steps.tulis(|t| {
+            format!(
+                "  {}({}, {}){}\n",
+                huruf(i),
+                x.tampil(t),
+                y.tampil(t),
+                if *termasuk {
+                    ""
+                } else {
+                    " — tidak termasuk daerah (terletak pada garis batas < atau >)"
+                }
+            )
+        });
+    }
+
+    steps.tulis(|_| format!("\n{}.\n", jenis.nama()));
+    match (jenis, arah) {
+        (JenisDaerah::Kosong, _) => {
+            if let Some(k) = garis_ketat {
+                steps.tulis(|_| {
+                    format!(
+                        "Jika batasnya ikut, semua titik daerah terletak pada garis P{0},\n\
+                     padahal tanda {1} pada P{0} tidak memuat garis tersebut.\n",
+                        k + 1,
+                        batasan[k].1.simbol()
+                    )
+                });
+            }
+            steps.push_str("Tidak ada titik yang memenuhi semua pertidaksamaan sekaligus.\n")
+        }
+        (JenisDaerah::TakTerbatas, Some((dx, dy))) => steps.tulis(|t| {
+            format!(
+                "Daerah terus memanjang ke arah ({}, {}) tanpa batas.\n",
+                dx.tampil(t),
+                dy.tampil(t)
+            )
+        }),
+        (JenisDaerah::TakTerbatas, None) => {
+            steps.push_str("Tidak ada garis batas: seluruh bidang xy memenuhi.\n")
+        }
+        (JenisDaerah::Terbatas, _) => match pojok.len() {
+            1 => steps.push_str("Daerah hanya berupa satu titik.\n"),
+            2 => steps.push_str("Daerah hanya berupa ruas garis di antara kedua titik pojok.\n"),
+            n => steps.tulis(|_| {
+                format!(
+                    "Daerah berbentuk segi-{} yang dibatasi oleh titik-titik pojoknya.\n",
+                    n
+                )
+            }),
+        },
+    }
+
+    let daerah = Daerah {
+        batasan: batasan.to_vec(),
+        pojok,
+        jenis,
+    };
+    Ok((daerah, steps))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn batasan(a: i128, b: i128, r: Relasi, c: i128) -> Batasan {
+        ([a.into(), b.into(), c.into()], r)
+    }
+
+    fn jenis(sistem: &[Batasan]) -> JenisDaerah {
+        daerah_proses(sistem).unwrap().0.jenis
+    }
+
+    #[test]
+    fn dua_sisi_ketat_garis_yang_sama_kosong() {
+        let sistem = [
+            batasan(1, 1, Relasi::Kurang, 2),
+            batasan(1, 1, Relasi::Lebih, 2),
+        ];
+        assert_eq!(jenis(&sistem), JenisDaerah::Kosong);
+        // Dengan batas ikut, daerahnya adalah garis x + y = 2
+        let sistem = [
+            batasan(1, 1, Relasi::KurangSama, 2),
+            batasan(1, 1, Relasi::LebihSama, 2),
+        ];
+        assert_eq!(jenis(&sistem), JenisDaerah::TakTerbatas);
+    }
+
+    #[test]
+    fn titik_tunggal_pada_garis_ketat_kosong() {
+        let mut sistem = vec![
+            batasan(1, 0, Relasi::LebihSama, 0),
+            batasan(0, 1, Relasi::LebihSama, 0),
+            batasan(1, 1, Relasi::KurangSama, 0),
+        ];
+        assert_eq!(jenis(&sistem), JenisDaerah::Terbatas);
+        sistem[2].1 = Relasi::Kurang;
+        assert_eq!(jenis(&sistem), JenisDaerah::Kosong);
+    }
+
+    #[test]
+    fn segitiga_terbuka_tetap_terbatas() {
+        let (daerah, _) = daerah_proses(&[
+            batasan(1, 0, Relasi::Lebih, 0),
+            batasan(0, 1, Relasi::Lebih, 0),
+            batasan(1, 1, Relasi::Kurang, 1),
+        ])
+        .unwrap();
+        assert_eq!(daerah.jenis, JenisDaerah::Terbatas);
+        assert_eq!(daerah.pojok.len(), 3);
+        assert!(daerah.pojok.iter().all(|(_, termasuk)| !termasuk));
+    }
+
+    #[test]
+    fn jalur_sejajar() {
+        let jalur = |r1, r2| jenis(&[batasan(0, 1, r1, 0), batasan(0, 1, r2, 1)]);
+        assert_eq!(
+            jalur(Relasi::Lebih, Relasi::Kurang),
+            JenisDaerah::TakTerbatas
+        );
+        assert_eq!(jalur(Relasi::Kurang, Relasi::Lebih), JenisDaerah::Kosong);
+    }
+
+    #[test]
+    fn setengah_bidang_tak_terbatas() {
+        assert_eq!(
+            jenis(&[batasan(1, 1, Relasi::Lebih, 2)]),
+            JenisDaerah::TakTerbatas
+        );
+    }
+}
@@ -0,0 +1,869 @@
+use crate::solver::format::{Teks, kurung};
+use crate::solver::kuadrat::{bentuk_akar, sederhana_akar};
+use crate::solver::matriks::{Kurung, terpisah, tulis, tulis_sel};
+use crate::solver::pecahan::{Hitung, Pecahan, Tampilan};
+
+/// Jenis faktorisasi matriks persegi
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum JenisDekomposisi {
+    Lu,
+    Qr,
+    Cholesky,
+}
+
+impl JenisDekomposisi {
+    pub const SEMUA: [JenisDekomposisi; 3] = [
+        JenisDekomposisi::Lu,
+        JenisDekomposisi::Qr,
+        JenisDekomposisi::Cholesky,
+    ];
+
+    pub fn nama(self) -> &'static str {
+        match self {
+            JenisDekomposisi::Lu => "LU (PA = LU)",
+            JenisDekomposisi::Qr => "QR (Gram–Schmidt)",
+            JenisDekomposisi::Cholesky => "Cholesky (A = LLᵀ)",
+        }
+    }
+
+    /// Jenis berikutnya, atau sebelumnya jika `mundur`
+    pub fn geser(self, mundur: bool) -> JenisDekomposisi {
+        let n = Self::SEMUA.len();
+        let i = Self::SEMUA.iter().position(|&j| j == self).unwrap_or(0);
+        Self::SEMUA[if mundur { (i + n - 1) % n } else { (i + 1) % n }]
+    }
+}
+
+/// Akhir proses dekomposisi
+pub enum HasilDekomposisi {
+    /// Faktorisasi terbentuk; berisi penyelesaian Ax = b jika b diisi
+    Berhasil(Option<Vec<Pecahan>>),
+    /// Faktor LU terbentuk, tetapi U memiliki unsur diagonal nol
+    Singular,
+    /// Faktorisasi tidak dapat dibentuk, beserta alasannya
+    Gagal(String),
+}
+
+type Matriks = Vec<Vec<Pecahan>>;
+
+// Bilangan k·√r dengan r > 0 untuk unsur faktor QR dan Cholesky. Hasil kali
+// dua bilangan dengan r yang sama selalu rasional: (k₁√r)(k₂√r) = k₁k₂r.
+#[derive(Clone, Copy)]
+struct Akar {
+    koef: Pecahan,
+    radikan: Pecahan,
+}
+
+impl Akar {
+    fn nol() -> Self {
+        Akar {
+            koef: Pecahan::NOL,
+            radikan: Pecahan::SATU,
+        }
+    }
+
+    fn tulis(self, t: Tampilan) -> Hitung<String> {
+        if self.koef.is_nol() {
+            return Ok(Pecahan::NOL.tampil(t).to_string());
+        }
+        let (q, r) = sederhana_akar(self.radikan)?;
+        let k = self.koef.kali(q)?;
+        let teks = bentuk_akar(Pecahan::NOL, k.abs(), r, false, "+", t)?;
+        Ok(if k.is_negatif() {
+            format!("-{}", teks)
+        } else {
+            teks
+        })
+    }
+
+    // Ditulis dalam kurung jika negatif atau berbentuk pecahan
+    fn kurung(self, t: Tampilan) -> Hitung<String> {
+        let teks = self.tulis(t)?;
+        Ok(if teks.starts_with('-') || teks.contains('/') {
+            format!("({})", teks)
+        } else {
+            teks
+        })
+    }
+}
+
+fn nol(n: usize) -> Matriks {
+    vec![vec![Pecahan::NOL; n]; n]
+}
+
+// Vektor kolom sebagai matriks n × 1
+fn vektor(v: &[Pecahan]) -> Matriks {
+    v.iter().map(|x| vec![*x]).collect()
+}
+
+fn sel_akar(m: &[Vec<Akar>], t: Tampilan) -> Hitung<Vec<Vec<String>>> {
+    m.iter()
+        .map(|b| b.iter().map(|v| v.tulis(t)).collect())
+        .collect()
+}
+
+fn kali_titik(p: &[Pecahan], q: &[Pecahan]) -> Hitung<Pecahan> {
+    Pecahan::jumlah_perkalian(p.iter().copied().zip(q.iter().copied()))
+}
+
+// Pengurangan berantai, mis. "7 − 3 − (-1)"
+fn kurangi(awal: String, suku: &[String]) -> String {
+    suku.iter()
+        .fold(awal, |teks, s| format!("{} − {}", teks, s))
+}
+
+// Tanda hasil pemeriksaan perkalian kembali
+fn cocok(hasil: &[Vec<Pecahan>], a: &[Vec<Pecahan>], rumus: &str, asal: &str) -> String {
+    if hasil == a {
+        format!("  {} = {} ✓\n", rumus, asal)
+    } else {
+        format!("  {} ≠ {}\n", rumus, asal)
+    }
+}
+
+// Substitusi maju (segitiga bawah) atau balik (segitiga atas) untuk M·v = c,
+// mis. "  y2 = 3 − (1/2)·5 = 1/2"
+fn substitusi(
+    m: &[Vec<Pecahan>],
+    c: &[Pecahan],
+    nama: &[String],
+    balik: bool,
+) -> Hitung<(Vec<Pecahan>, Teks)> {
+    let n = c.len();
+    let mut v = vec![Pecahan::NOL; n];
+    let mut steps = Teks::default();
+    let urutan: Vec<usize> = if balik {
+        (0..n).rev().collect()
+    } else {
+        (0..n).collect()
+    };
+    for i in urutan {
+        let lain: Vec<usize> = if balik { i + 1..n } else { 0..i }
+            .filter(|&j| !m[i][j].is_nol())
+            .collect();
+        let sisa = c[i].kurang(Pecahan::jumlah_perkalian(
+            lain.iter().map(|&j| (m[i][j], v[j])),
+        )?)?;
+        v[i] = sisa.bagi(m[i][i])?;
+        let baris = |t| {
+            let suku: Vec<String> = lain
+                .iter()
+                .map(|&j| format!("{}·{}", kurung(m[i][j], t), kurung(v[j], t)))
+                .collect();
+            let rumus = kurangi(c[i].tampil(t).to_string(), &suku);
+            match (lain.is_empty(), m[i][i] == Pecahan::SATU) {
+                (true, true) => rumus,
+                (true, false) => format!("{} / {} = {}", rumus, kurung(m[i][i], t), v[i].tampil(t)),
+                (false, true) => format!("{} = {}", rumus, v[i].tampil(t)),
+                (false, false) => {
+                    format!("({}) / {} = {}", rumus, kurung(m[i][i], t), v[i].tampil(t))
+                }
+            }
+        };
+        steps.tulis(|t| format!("  {} = {}\n", nama[i], baris(t)));
+    }
+    Ok((v, steps))
+}
+
+fn nama_y(n: usize) -> Vec<String> {
+    (1..=n).map(|i| format!("y{}", i)).collect()
+}
+
+// PA = LU dengan eliminasi Gauss berpivot parsial. Pengali setiap langkah
+// eliminasi disimpan di L, dan pertukaran baris dicatat di P.
+fn lu(
+    a: &[Vec<Pecahan>],
+    b: Option<&[Pecahan]>,
+    nama: &[String],
+) -> Hitung<(HasilDekomposisi, Teks)> {
+    let n = a.len();
+    let mut u = a.to_vec();
+    let mut l = nol(n);
+    let mut urutan: Vec<usize> = (0..n).collect();
+    let mut steps = Teks::from(
+        "\nEliminasi Gauss dengan pivot parsial; setiap pengali lᵢⱼ disimpan di L\n\
+         dan setiap pertukaran baris dicatat di P:\n",
+    );
+    for c in 0..n {
+        let p = (c..n).max_by_key(|&i| u[i][c].abs()).unwrap_or(c);
+        if u[p][c].is_nol() {
+            steps.tulis(|_| {
+                format!(
+                    "\nKolom {}: semua calon pivot nol, sehingga u{}{} = 0.\n",
+                    c + 1,
+                    c + 1,
+                    c + 1
+                )
+            });
+            continue;
+        }
+        // Kolom terakhir tanpa pertukaran tidak perlu dieliminasi
+        if p == c && c + 1 == n {
+            continue;
+        }
+        steps.tulis(|_| format!("\nKolom {}:\n", c + 1));
+        if p != c {
+            u.swap(p, c);
+            l.swap(p, c);
+            urutan.swap(p, c);
+            steps.tulis(|t| {
+                format!(
+                    "  B{} ↔ B{} (pivot terbesar {}){}\n",
+                    c + 1,
+                    p + 1,
+                    u[c][c].tampil(t),
+                    if c > 0 { ", baris L ikut ditukar" } else { "" }
+                )
+            });
+        }
+        for i in c + 1..n {
+            let atas = u[i][c];
+            let faktor = atas.bagi(u[c][c])?;
+            l[i][c] = faktor;
+            if faktor.is_nol() {
+                continue;
+            }
+            let acuan = u[c].clone();
+            for (v, a) in u[i].iter_mut().zip(&acuan) {
+                *v = v.kurang(faktor.kali(*a)?)?;
+            }
+            steps.tulis(|t| {
+                format!(
+                    "  l{}{} = {} / {} = {},  B{} ← B{} − {}·B{}\n",
+                    i + 1,
+                    c + 1,
+                    atas.tampil(t),
+                    kurung(u[c][c], t),
+                    faktor.tampil(t),
+                    i + 1,
+                    i + 1,
+                    kurung(faktor, t),
+                    c + 1
+                )
+            });
+        }
+        steps.gambar(|t| tulis("", &u, Kurung::Siku, "", t));
+    }
+    for (i, baris) in l.iter_mut().enumerate() {
+        baris[i] = Pecahan::SATU;
+    }
+    let p: Matriks = urutan
+        .iter()
+        .map(|&k| {
+            (0..n)
+                .map(|j| if j == k { Pecahan::SATU } else { Pecahan::NOL })
+                .collect()
+        })
+        .collect();
+
+    steps.push_str("\nHasil faktorisasi:\n");
+    steps.gambar(|t| tulis("P = ", &p, Kurung::Siku, "", t));
+    steps.gambar(|t| tulis("L = ", &l, Kurung::Siku, "", t));
+    steps.gambar(|t| tulis("U = ", &u, Kurung::Siku, "", t));
+
+    let hasil_kali: Matriks = (0..n)
+        .map(|i| {
+            (0..n)
+                .map(|j| Pecahan::jumlah_perkalian((0..n).map(|k| (l[i][k], u[k][j]))))
+                .collect()
+        })
+        .collect::<Hitung<_>>()?;
+    let pa: Matriks = urutan.iter().map(|&k| a[k].clone()).collect();
+    steps.push_str("\nPemeriksaan dengan mengalikan kembali:\n");
+    steps.gambar(|t| tulis("LU = ", &hasil_kali, Kurung::Siku, "", t));
+    steps.gambar(|t| tulis("PA = ", &pa, Kurung::Siku, "", t));
+    steps.tulis(|_| cocok(&hasil_kali, &pa, "LU", "PA"));
+
+    let singular = (0..n).any(|i| u[i][i].is_nol());
+    if singular {
+        steps.push_str("\nU memiliki unsur diagonal nol → det(A) = 0, A singular.\n");
+    }
+    let Some(b) = b else {
+        return Ok((HasilDekomposisi::Berhasil(None), steps));
+    };
+    if singular {
+        steps.push_str("Ax = b tidak dapat diselesaikan dengan substitusi balik pada U.\n");
+        return Ok((HasilDekomposisi::Singular, steps));
+    }
+
+    let pb: Vec<Pecahan> = urutan.iter().map(|&k| b[k]).collect();
+    steps.push_str("\nMenyelesaikan Ax = b: PAx = Pb → LUx = Pb. Misalkan y = Ux.\n");
+    steps.gambar(|t| tulis("Pb = ", &vektor(&pb), Kurung::Siku, "", t));
+    steps.push_str("\nSubstitusi maju Ly = Pb:\n");
+    let (y, langkah) = substitusi(&l, &pb, &nama_y(n), false)?;
+    steps.sambung(&langkah);
+    steps.push_str("\nSubstitusi balik Ux = y:\n");
+    let (x, langkah) = substitusi(&u, &y, nama, true)?;
+    steps.sambung(&langkah);
+    Ok((HasilDekomposisi::Berhasil(Some(x)), steps))
+}
+
+// A = QR dengan proses Gram–Schmidt. uⱼ dihitung secara rasional, lalu
+// qⱼ = uⱼ/‖uⱼ‖ dan rᵢⱼ = qᵢ·aⱼ ditulis dalam bentuk k·√r.
+fn qr(
+    a: &[Vec<Pecahan>],
+    b: Option<&[Pecahan]>,
+    nama: &[String],
+) -> Hitung<(HasilDekomposisi, Teks)> {
+    let n = a.len();
+    let kolom: Matriks = (0..n).map(|j| a.iter().map(|r| r[j]).collect()).collect();
+    let mut u: Matriks = Vec::new();
+    // sⱼ = uⱼ·uⱼ = ‖uⱼ‖²
+    let mut s: Vec<Pecahan> = Vec::new();
+    let mut steps = Teks::from(
+        "\nProses Gram–Schmidt pada kolom-kolom A (a1, a2, …):\n  \
+         uⱼ = aⱼ − Σᵢ₍ᵢ<ⱼ₎ ((uᵢ·aⱼ)/(uᵢ·uᵢ))·uᵢ,  qⱼ = uⱼ / ‖uⱼ‖\n",
+    );
+    for (j, aj) in kolom.iter().enumerate() {
+        let mut uj = aj.clone();
+        let mut proyeksi = Vec::new();
+        for i in 0..j {
+            let koef = kali_titik(&u[i], aj)?.bagi(s[i])?;
+            if koef.is_nol() {
+                continue;
+            }
+            for (v, w) in uj.iter_mut().zip(&u[i]) {
+                *v = v.kurang(koef.kali(*w)?)?;
+            }
+            proyeksi.push((koef, i));
+        }
+        let rumus = |t| {
+            proyeksi
+                .iter()
+                .fold(format!("a{}", j + 1), |teks, (koef, i)| {
+                    format!("{} − {}·u{}", teks, kurung(*koef, t), i + 1)
+                })
+        };
+        steps.push('\n');
+        steps.gambar(|t| {
+            tulis(
+                &format!("u{} = {} = ", j + 1, rumus(t)),
+                &vektor(&uj),
+                Kurung::Siku,
+                "",
+                t,
+            )
+        });
+        let sj = kali_titik(&uj, &uj)?;
+        if sj.is_nol() {
+            steps.tulis(|_| {
+                format!(
+                    "\nu{} = 0 → kolom a{} merupakan kombinasi linear kolom sebelumnya,\n\
+                 sehingga Q tidak dapat dibentuk (A singular).\n",
+                    j + 1,
+                    j + 1
+                )
+            });
+            return Ok((
+                HasilDekomposisi::Gagal(
+                    "Kolom-kolom A bergantung linear, faktorisasi QR tidak dapat dibentuk"
+                        .to_string(),
+                ),
+                steps,
+            ));
+        }
+        let norma = Akar {
+            koef: Pecahan::SATU,
+            radikan: sj,
+        };
+        steps.coba_tulis(|t| {
+            Ok(format!(
+                "  u{}·u{} = {},  ‖u{}‖ = {}\n",
+                j + 1,
+                j + 1,
+                sj.tampil(t),
+                j + 1,
+                norma.tulis(t)?
+            ))
+        })?;
+        u.push(uj);
+        s.push(sj);
+    }
+
+    // qᵢⱼ = uⱼ[i]/√sⱼ = (uⱼ[i]/sⱼ)·√sⱼ dan rᵢⱼ = (uᵢ·aⱼ)/√sᵢ = ((uᵢ·aⱼ)/sᵢ)·√sᵢ
+    let q: Vec<Vec<Akar>> = (0..n)
+        .map(|i| {
+            (0..n)
+                .map(|j| {
+                    Ok(Akar {
+                        koef: u[j][i].bagi(s[j])?,
+                        radikan: s[j],
+                    })
+                })
+                .collect()
+        })
+        .collect::<Hitung<_>>()?;
+    let r: Vec<Vec<Akar>> = (0..n)
+        .map(|i| {
+            (0..n)
+                .map(|j| {
+                    Ok(if i > j {
+                        Akar::nol()
+                    } else {
+                        Akar {
+                            koef: kali_titik(&u[i], &kolom[j])?.bagi(s[i])?,
+                            radikan: s[i],
+                        }
+                    })
+                })
+                .collect()
+        })
+        .collect::<Hitung<_>>()?;
+    steps.push_str("\nHasil faktorisasi (kolom Q adalah qⱼ, dan rᵢⱼ = qᵢ·aⱼ untuk i ≤ j):\n");
+    steps.coba_gambar(|t| Ok(tulis_sel("Q = ", &sel_akar(&q, t)?, Kurung::Siku, "")))?;
+    steps.coba_gambar(|t| Ok(tulis_sel("R = ", &sel_akar(&r, t)?, Kurung::Siku, "")))?;
+
+    // qᵢₖ·rₖⱼ memuat √sₖ dua kali sehingga rasional
+    let hasil_kali: Matriks = (0..n)
+        .map(|i| {
+            (0..n)
+                .map(|j| {
+                    Pecahan::jumlah(
+                        (0..=j)
+                            .map(|k| Pecahan::hasil_kali([q[i][k].koef, r[k][j].koef, s[k]]))
+                            .collect::<Hitung<Vec<_>>>()?,
+                    )
+                })
+                .collect()
+        })
+        .collect::<Hitung<_>>()?;
+    steps.push_str("\nPemeriksaan dengan mengalikan kembali:\n");
+    steps.gambar(|t| tulis("QR = ", &hasil_kali, Kurung::Siku, "", t));
+    steps.tulis(|_| cocok(&hasil_kali, a, "QR", "A"));
+
+    let Some(b) = b else {
+        return Ok((HasilDekomposisi::Berhasil(None), steps));
+    };
+    let qtb: Vec<Akar> = (0..n)
+        .map(|i| {
+            Ok(Akar {
+                koef: kali_titik(&u[i], b)?.bagi(s[i])?,
+                radikan: s[i],
+            })
+        })
+        .collect::<Hitung<_>>()?;
+    steps.push_str("\nMenyelesaikan Ax = b: QRx = b → Rx = Qᵀb (karena QᵀQ = I).\n");
+    steps.coba_gambar(|t| {
+        let kolom_qtb: Vec<Vec<String>> = qtb
+            .iter()
+            .map(|v| Ok(vec![v.tulis(t)?]))
+            .collect::<Hitung<_>>()?;
+        Ok(tulis_sel("Qᵀb = ", &kolom_qtb, Kurung::Siku, ""))
+    })?;
+    steps.push_str(
+        "\nSetiap baris ke-i dari [R | Qᵀb] memuat faktor ‖uᵢ‖, sehingga setelah\n\
+         dibagi ‖uᵢ‖ sistemnya menjadi rasional:\n",
+    );
+    let rasional: Matriks = (0..n)
+        .map(|i| {
+            r[i].iter()
+                .map(|v| v.koef)
+                .chain(std::iter::once(qtb[i].koef))
+                .collect()
+        })
+        .collect();
+    steps.gambar(|t| terpisah(&rasional, n, t));
+    let segitiga: Matriks = rasional.iter().map(|b| b[..n].to_vec()).collect();
+    let ruas_kanan: Vec<Pecahan> = rasional.iter().map(|b| b[n]).collect();
+    steps.push_str("\nSubstitusi balik:\n");
+    let (x, langkah) = substitusi(&segitiga, &ruas_kanan, nama, true)?;
+    steps.sambung(&langkah);
+    Ok((HasilDekomposisi::Berhasil(Some(x)), steps))
+}
+
+// A = LLᵀ untuk A simetris definit positif. Unsur L disimpan sebagai
+// lᵢⱼ = l̃ᵢⱼ·√dⱼ (faktorisasi LDLᵀ), sehingga setiap hasil kali lᵢₖ·lⱼₖ rasional.
+fn cholesky(
+    a: &[Vec<Pecahan>],
+    b: Option<&[Pecahan]>,
+    nama: &[String],
+) -> Hitung<(HasilDekomposisi, Teks)> {
+    let n = a.len();
+    let mut steps = Teks::default();
+    let tidak_simetris = (0..n)
+        .flat_map(|i| (i + 1..n).map(move |j| (i, j)))
+        .find(|&(i, j)| a[i][j] != a[j][i]);
+    if let Some((i, j)) = tidak_simetris {
+        steps.tulis(|t| {
+            format!(
+                "\na{}{} = {} ≠ a{}{} = {} → A tidak simetris.\n",
+                i + 1,
+                j + 1,
+                a[i][j].tampil(t),
+                j + 1,
+                i + 1,
+                a[j][i].tampil(t)
+            )
+        });
+        return Ok((
+            HasilDekomposisi::Gagal("A tidak simetris, faktorisasi Cholesky tidak ada".to_string()),
+            steps,
+        ));
+    }
+    steps.push_str(
+        "\nA simetris. Unsur L dihitung kolom demi kolom:\n  \
+         lⱼⱼ = √(aⱼⱼ − Σₖ₍ₖ<ⱼ₎ lⱼₖ²),  lᵢⱼ = (aᵢⱼ − Σₖ₍ₖ<ⱼ₎ lᵢₖ·lⱼₖ) / lⱼⱼ  (i > j)\n",
+    );
+
+    let mut lt = nol(n);
+    let mut d = vec![Pecahan::NOL; n];
+    for j in 0..n {
+        steps.tulis(|_| format!("\nKolom {}:\n", j + 1));
+        // lⱼₖ² = l̃ⱼₖ²·dₖ
+        let kuadrat: Vec<Pecahan> = (0..j)
+            .map(|k| Pecahan::hasil_kali([lt[j][k], lt[j][k], d[k]]))
+            .collect::<Hitung<_>>()?;
+        let isi = a[j][j].kurang(Pecahan::jumlah(kuadrat.iter().copied())?)?;
+        let simbol: Vec<String> = (0..j).map(|k| format!("l{}{}²", j + 1, k + 1)).collect();
+        let rumus = if j == 0 {
+            format!("√a{}{}", j + 1, j + 1)
+        } else {
+            format!("√({})", kurangi(format!("a{}{}", j + 1, j + 1), &simbol))
+        };
+        let angka = |t| {
+            if j == 0 {
+                format!("√{}", kurung(a[j][j], t))
+            } else {
+                let nilai: Vec<String> = kuadrat.iter().map(|v| kurung(*v, t)).collect();
+                format!("√({})", kurangi(a[j][j].tampil(t).to_string(), &nilai))
+            }
+        };
+        if isi <= Pecahan::NOL {
+            steps.tulis(|t| {
+                format!(
+                    "  l{}{} = {} = {}, tetapi {} ≤ 0 → A tidak definit positif.\n",
+                    j + 1,
+                    j + 1,
+                    rumus,
+                    angka(t),
+                    isi.tampil(t)
+                )
+            });
+            return Ok((
+                HasilDekomposisi::Gagal(
+                    "A tidak definit positif, faktorisasi Cholesky tidak ada".to_string(),
+                ),
+                steps,
+            ));
+        }
+        d[j] = isi;
+        lt[j][j] = Pecahan::SATU;
+        let ljj = Akar {
+            koef: Pecahan::SATU,
+            radikan: isi,
+        };
+        steps.coba_tulis(|t| {
+            // √4 = 2, tetapi √3 tidak perlu ditulis ulang
+            let (angka, nilai) = (angka(t), ljj.tulis(t)?);
+            let akhir = if nilai == angka {
+                String::new()
+            } else {
+                format!(" = {}", nilai)
+            };
+            Ok(format!(
+                "  l{}{} = {} = {}{}\n",
+                j + 1,
+                j + 1,
+                rumus,
+                angka,
+                akhir
+            ))
+        })?;
+
+        for i in j + 1..n {
+            // lᵢₖ·lⱼₖ = l̃ᵢₖ·l̃ⱼₖ·dₖ
+            let hasil_kali: Vec<Pecahan> = (0..j)
+                .map(|k| Pecahan::hasil_kali([lt[i][k], lt[j][k], d[k]]))
+                .collect::<Hitung<_>>()?;
+            let pembilang = a[i][j].kurang(Pecahan::jumlah(hasil_kali.iter().copied())?)?;
+            lt[i][j] = pembilang.bagi(d[j])?;
+            let simbol: Vec<String> = (0..j)
+                .map(|k| format!("l{}{}·l{}{}", i + 1, k + 1, j + 1, k + 1))
+                .collect();
+            let rumus = if j == 0 {
+                format!("a{}{} / l{}{}", i + 1, j + 1, j + 1, j + 1)
+            } else {
+                format!(
+                    "({}) / l{}{}",
+                    kurangi(format!("a{}{}", i + 1, j + 1), &simbol),
+                    j + 1,
+                    j + 1
+                )
+            };
+            let angka = |t| -> Hitung<String> {
+                Ok(if j == 0 {
+                    format!("{} / {}", a[i][j].tampil(t), ljj.kurung(t)?)
+                } else {
+                    let nilai: Vec<String> = hasil_kali.iter().map(|v| kurung(*v, t)).collect();
+                    format!(
+                        "({}) / {}",
+                        kurangi(a[i][j].tampil(t).to_string(), &nilai),
+                        ljj.kurung(t)?
+                    )
+                })
+            };
+            let lij = Akar {
+                koef: lt[i][j],
+                radikan: d[j],
+            };
+            steps.coba_tulis(|t| {
+                Ok(format!(
+                    "  l{}{} = {} = {} = {}\n",
+                    i + 1,
+                    j + 1,
+                    rumus,
+                    angka(t)?,
+                    lij.tulis(t)?
+                ))
+            })?;
+        }
+    }
+
+    let l: Vec<Vec<Akar>> = (0..n)
+        .map(|i| {
+            (0..n)
+                .map(|j| {
+                    if i < j {
+                        Akar::nol()
+                    } else {
+                        Akar {
+                            koef: lt[i][j],
+                            radikan: d[j],
+                        }
+                    }
+                })
+                .collect()
+        })
+        .collect();
+    let lt_transpos: Vec<Vec<Akar>> = (0..n).map(|i| (0..n).map(|j| l[j][i]).collect()).collect();
+    steps.push_str("\nHasil faktorisasi:\n");
+    steps.coba_gambar(|t| Ok(tulis_sel("L = ", &sel_akar(&l, t)?, Kurung::Siku, "")))?;
+    steps.coba_gambar(|t| {
+        Ok(tulis_sel(
+            "Lᵀ = ",
+            &sel_akar(&lt_transpos, t)?,
+            Kurung::Siku,
+            "",
+        ))
+    })?;
+
+    let hasil_kali: Matriks = (0..n)
+        .map(|i| {
+            (0..n)
+                .map(|j| {
+                    Pecahan::jumlah(
+                        (0..n)
+                            .map(|k| Pecahan::hasil_kali([lt[i][k], lt[j][k], d[k]]))
+                            .collect::<Hitung<Vec<_>>>()?,
+                    )
+                })
+                .collect()
+        })
+        .collect::<Hitung<_>>()?;
+    steps.push_str("\nPemeriksaan dengan mengalikan kembali:\n");
+    steps.gambar(|t| tulis("LLᵀ = ", &hasil_kali, Kurung::Siku, "", t));
+    steps.tulis(|_| cocok(&hasil_kali, a, "LLᵀ", "A"));
+
+    let Some(b) = b else {
+        return Ok((HasilDekomposisi::Berhasil(None), steps));
+    };
+
+    // yᵢ = wᵢ·√dᵢ, sehingga lᵢₖ·yₖ = l̃ᵢₖ·wₖ·dₖ rasional
+    steps.push_str(
+        "\nMenyelesaikan Ax = b: LLᵀx = b. Misalkan y = Lᵀx.\n\nSubstitusi maju Ly = b:\n",
+    );
+    let mut w = vec![Pecahan::NOL; n];
+    for i in 0..n {
+        let hasil_kali: Vec<Pecahan> = (0..i)
+            .map(|k| Pecahan::hasil_kali([lt[i][k], w[k], d[k]]))
+            .collect::<Hitung<_>>()?;
+        let sisa = b[i].kurang(Pecahan::jumlah(hasil_kali.iter().copied())?)?;
+        w[i] = sisa.bagi(d[i])?;
+        let lii = Akar {
+            koef: Pecahan::SATU,
+            radikan: d[i],
+        };
+        let yi = Akar {
+            koef: w[i],
+            radikan: d[i],
+        };
+        let simbol: Vec<String> = (0..i)
+            .map(|k| format!("l{}{}·y{}", i + 1, k + 1, k + 1))
+            .collect();
+        let rumus = if i == 0 {
+            "b1 / l11".to_string()
+        } else {
+            format!(
+                "({}) / l{}{}",
+                kurangi(format!("b{}", i + 1), &simbol),
+                i + 1,
+                i + 1
+            )
+        };
+        let angka = |t| -> Hitung<String> {
+            Ok(if i == 0 {
+                format!("{} / {}", b[i].tampil(t), lii.kurung(t)?)
+            } else {
+                let nilai: Vec<String> = hasil_kali.iter().map(|v| kurung(*v, t)).collect();
+                format!(
+                    "({}) / {}",
+                    kurangi(b[i].tampil(t).to_string(), &nilai),
+                    lii.kurung(t)?
+                )
+            })
+        };
+        steps.coba_tulis(|t| {
+            Ok(format!(
+                "  y{} = {} = {} = {}\n",
+                i + 1,
+                rumus,
+                angka(t)?,
+                yi.tulis(t)?
+            ))
+        })?;
+    }
+
+    // Baris ke-i dari Lᵀx = y memuat √dᵢ di kedua ruas
+    steps.push_str("\nSubstitusi balik Lᵀx = y:\n");
+    let mut x = vec![Pecahan::NOL; n];
+    for i in (0..n).rev() {
+        let lain: Vec<usize> = (i + 1..n).collect();
+        // Unsur lⱼᵢ·xⱼ setelah baris dibagi √dᵢ
+        let suku: Vec<Pecahan> = lain
+            .iter()
+            .map(|&j| lt[j][i].kali(x[j]))
+            .collect::<Hitung<_>>()?;
+        x[i] = w[i].kurang(Pecahan::jumlah(suku.iter().copied())?)?;
+        let lii = Akar {
+            koef: Pecahan::SATU,
+            radikan: d[i],
+        };
+        let yi = Akar {
+            koef: w[i],
+            radikan: d[i],
+        };
+        let simbol: Vec<String> = lain
+            .iter()
+            .map(|&j| format!("l{}{}·{}", j + 1, i + 1, nama[j]))
+            .collect();
+        let rumus = if lain.is_empty() {
+            format!("y{} / l{}{}", i + 1, i + 1, i + 1)
+        } else {
+            format!(
+                "({}) / l{}{}",
+                kurangi(format!("y{}", i + 1), &simbol),
+                i + 1,
+                i + 1
+            )
+        };
+        let angka = |t| -> Hitung<String> {
+            Ok(if lain.is_empty() {
+                format!("{} / {}", yi.kurung(t)?, lii.kurung(t)?)
+            } else {
+                let nilai: Vec<String> = suku
+                    .iter()
+                    .map(|&koef| {
+                        Akar {
+                            koef,
+                            radikan: d[i],
+                        }
+                        .kurung(t)
+                    })
+                    .collect::<Hitung<_>>()?;
+                format!("({}) / {}", kurangi(yi.tulis(t)?, &nilai), lii.kurung(t)?)
+            })
+        };
+        steps.coba_tulis(|t| {
+            Ok(format!(
+                "  {} = {} = {} = {}\n",
+                nama[i],
+                rumus,
+                angka(t)?,
+                x[i].tampil(t)
+            ))
+        })?;
+    }
+    Ok((HasilDekomposisi::Berhasil(Some(x)), steps))
+}
+
+//
+// Dekomposisi Matriks Persegi
+// LU: PA = LU dengan eliminasi Gauss berpivot parsial (P matriks permutasi).
+// QR: A = QR dengan proses Gram–Schmidt; Q ortonormal, R segitiga atas.
+// Cholesky: A = LLᵀ untuk A simetris definit positif.
+// Unsur Q, R, dan L Cholesky berbentuk k·√r sehingga tetap ditulis eksak.
+// Setiap faktorisasi diperiksa dengan mengalikan kembali faktornya, dan jika
+// b diisi, Ax = b diselesaikan dengan substitusi maju/balik pada faktornya.
+//
+pub fn dekomposisi_proses(
+    jenis: JenisDekomposisi,
+    a: &[Vec<Pecahan>],
+    b: Option<&[Pecahan]>,
+    nama: &[String],
+) -> Hitung<(HasilDekomposisi, Teks)> {
+    let mut steps = Teks::dari(|_| format!("Dekomposisi: {}\n\n", jenis.nama()));
+    steps.gambar(|t| tulis("A = ", a, Kurung::Siku, "", t));
+    if let Some(b) = b {
+        steps.gambar(|t| tulis("b = ", &vektor(b), Kurung::Siku, "", t));
+    }
+    let (hasil, langkah) = match jenis {
+        JenisDekomposisi::Lu => lu(a, b, nama),
+        JenisDekomposisi::Qr => qr(a, b, nama),
+        JenisDekomposisi::Cholesky => cholesky(a, b, nama),
+    }?;
+    steps.sambung(&langkah);
+    Ok((hasil, steps))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::solver::pecahan::uji::{matriks, nama};
+
+    #[test]
+    fn semua_faktorisasi_menyelesaikan_sistem() {
+        // 4x₁ + 2x₂ = 6, 2x₁ + 3x₂ = 5 → x = (1, 1); A simetris definit positif
+        let a = matriks(&[&[4, 2], &[2, 3]]);
+        let b = [Pecahan::from(6), Pecahan::from(5)];
+        for jenis in JenisDekomposisi::SEMUA {
+            let (hasil, langkah) = dekomposisi_proses(jenis, &a, Some(&b), &nama(2)).unwrap();
+            match hasil {
+                HasilDekomposisi::Berhasil(Some(x)) => {
+                    assert_eq!(x, [Pecahan::SATU, Pecahan::SATU], "{:?}", jenis)
+                }
+                _ => panic!("{:?} gagal", jenis),
+            }
+            assert!(!langkah.pecahan.teks().contains('✗'), "{:?}", jenis);
+        }
+    }
+
+    #[test]
+    fn faktorisasi_tanpa_ruas_kanan() {
+        let a = matriks(&[&[0, 1], &[1, 0]]);
+        let (hasil, langkah) =
+            dekomposisi_proses(JenisDekomposisi::Lu, &a, None, &nama(2)).unwrap();
+        assert!(matches!(hasil, HasilDekomposisi::Berhasil(None)));
+        assert!(langkah.pecahan.teks().contains("B1 ↔ B2"));
+    }
+
+    #[test]
+    fn matriks_singular_atau_tidak_memenuhi_syarat() {
+        let singular = matriks(&[&[1, 2], &[2, 4]]);
+        let b = [Pecahan::from(3), Pecahan::from(6)];
+        let (hasil, langkah) =
+            dekomposisi_proses(JenisDekomposisi::Lu, &singular, Some(&b), &nama(2)).unwrap();
+        assert!(matches!(hasil, HasilDekomposisi::Singular));
+        assert!(langkah.pecahan.teks().contains("A singular"));
+
+        let tidak_simetris = matriks(&[&[1, 2], &[3, 4]]);
+        let (hasil, _) =
+            dekomposisi_proses(JenisDekomposisi::Cholesky, &tidak_simetris, None, &nama(2))
+                .unwrap();
+        assert!(matches!(hasil, HasilDekomposisi::Gagal(_)));
+
+        let tidak_definit = matriks(&[&[1, 2], &[2, 1]]);
+        let (hasil, _) =
+            dekomposisi_proses(JenisDekomposisi::Cholesky, &tidak_definit, None, &nama(2)).unwrap();
+        assert!(matches!(hasil, HasilDekomposisi::Gagal(_)));
+    }
+}
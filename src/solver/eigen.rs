@@ -0,0 +1,785 @@
+use crate::solver::format::{Teks, kombinasi_linear, kurung};
+use crate::solver::kalkulator::gauss_jordan;
+use crate::solver::kuadrat::{bentuk_akar, sederhana_akar};
+use crate::solver::matriks::{Kurung, determinan, langkah_determinan, tulis, tulis_sel};
+use crate::solver::pecahan::{Hitung, Pecahan, Tampilan, desimal, kpk};
+use crate::solver::ruang::basis_nol;
+
+/// Nilai eigen real
+#[derive(Clone, Copy)]
+pub enum NilaiEigen {
+    Rasional(Pecahan),
+    /// p + q√r dengan r bebas kuadrat; q negatif untuk akar sekawan p − |q|√r
+    Akar {
+        p: Pecahan,
+        q: Pecahan,
+        r: i128,
+    },
+    /// Hampiran numerik jika akar polinom karakteristik tidak rasional
+    Hampiran(f64),
+}
+
+impl NilaiEigen {
+    pub fn ke_f64(self) -> f64 {
+        match self {
+            NilaiEigen::Rasional(x) => x.ke_f64(),
+            NilaiEigen::Akar { p, q, r } => p.ke_f64() + q.ke_f64() * (r as f64).sqrt(),
+            NilaiEigen::Hampiran(x) => x,
+        }
+    }
+
+    /// Tanda di antara nama dan nilainya: "=" untuk nilai eksak, "≈" untuk hampiran
+    pub fn relasi(self) -> &'static str {
+        match self {
+            NilaiEigen::Hampiran(_) => "≈",
+            _ => "=",
+        }
+    }
+
+    pub fn tulis(self, t: Tampilan) -> Hitung<String> {
+        match self {
+            NilaiEigen::Rasional(x) => Ok(x.tampil(t).to_string()),
+            NilaiEigen::Akar { p, q, r } => bentuk_akar(
+                p,
+                q.abs(),
+                r,
+                false,
+                if q.is_negatif() { "-" } else { "+" },
+                t,
+            ),
+            NilaiEigen::Hampiran(x) => Ok(desimal(x)),
+        }
+    }
+}
+
+/// Satu nilai eigen beserta basis ruang eigennya
+pub struct Eigen {
+    pub nilai: NilaiEigen,
+    /// Kelipatan aljabar (banyaknya sebagai akar polinom karakteristik)
+    pub aljabar: usize,
+    /// Basis ruang eigen (unsur eksak atau hampiran); banyaknya adalah
+    /// kelipatan geometri
+    pub vektor: Vec<Vec<NilaiEigen>>,
+}
+
+/// Hasil analisis nilai eigen
+pub struct HasilEigen {
+    /// Nilai eigen real dari kecil ke besar
+    pub eigen: Vec<Eigen>,
+    /// Pasangan nilai eigen kompleks sekawan, mis. "1 ± 2i"
+    pub kompleks: Option<Teks>,
+    /// A = PDP⁻¹ dengan P dan D real
+    pub diagonal: bool,
+}
+
+// Polinom monik dalam λ dari pangkat tertinggi, mis. "λ³ - 6λ² + 11λ - 6".
+// `koef` adalah koefisien setelah suku pangkat tertinggi.
+fn polinom(koef: &[Pecahan], t: Tampilan) -> String {
+    const PANGKAT: [&str; 3] = ["λ", "λ²", "λ³"];
+    let derajat = koef.len();
+    let mut suku = vec![(Pecahan::SATU, PANGKAT[derajat - 1])];
+    for (i, k) in koef[..derajat - 1].iter().enumerate() {
+        suku.push((*k, PANGKAT[derajat - 2 - i]));
+    }
+    let kiri = kombinasi_linear(Pecahan::NOL, &suku, t);
+    let c = koef[derajat - 1];
+    if c.is_nol() {
+        kiri
+    } else if c.is_negatif() {
+        format!("{} - {}", kiri, (-c).tampil(t))
+    } else {
+        format!("{} + {}", kiri, c.tampil(t))
+    }
+}
+
+// Faktor linear λ − s dari teks s, mis. "λ − 2" atau "λ + 0.9057"
+fn faktor(s: String) -> String {
+    match s.strip_prefix('-') {
+        _ if s == "0" => "λ".to_string(),
+        Some(s) => format!("λ + {}", s),
+        None => format!("λ − {}", s),
+    }
+}
+
+// Nilai polinom monik di λ = x
+fn nilai_polinom(koef: &[Pecahan], x: Pecahan) -> Hitung<Pecahan> {
+    koef.iter()
+        .try_fold(Pecahan::SATU, |hasil, k| hasil.kali(x)?.tambah(*k))
+}
+
+// Pembagi positif n (n > 0), atau `None` jika n terlalu besar untuk diuji
+fn pembagi(n: i128) -> Option<Vec<i128>> {
+    if n > 1_000_000_000_000 {
+        return None;
+    }
+    let mut kecil = Vec::new();
+    let mut besar = Vec::new();
+    let mut i = 1;
+    while i * i <= n {
+        if n % i == 0 {
+            kecil.push(i);
+            if i * i != n {
+                besar.push(n / i);
+            }
+        }
+        i += 1;
+    }
+    kecil.extend(besar.into_iter().rev());
+    Some(kecil)
+}
+
+// Teorema Akar Rasional untuk λ³ + bλ² + cλ + e: setelah dikalikan KPK
+// penyebutnya, setiap akar rasional berbentuk ±p/q dengan p | suku tetap
+// dan q | koefisien λ³. Mengembalikan akar terkecil yang ditemukan.
+fn akar_rasional(koef: &[Pecahan]) -> Hitung<Option<Pecahan>> {
+    let Some(kpk) = koef.iter().try_fold(1, |l, k| kpk(l, k.penyebut())) else {
+        return Ok(None);
+    };
+    let tetap = koef[2].kali(Pecahan::from(kpk))?.pembilang().abs();
+    if tetap == 0 {
+        return Ok(Some(Pecahan::NOL));
+    }
+    let (Some(pembagi_tetap), Some(pembagi_kpk)) = (pembagi(tetap), pembagi(kpk)) else {
+        return Ok(None);
+    };
+    let mut calon: Vec<Pecahan> = Vec::new();
+    for &p in &pembagi_tetap {
+        for &q in &pembagi_kpk {
+            calon.push(Pecahan::coba(p, q)?);
+            calon.push(Pecahan::coba(-p, q)?);
+        }
+    }
+    calon.sort();
+    for x in calon {
+        if nilai_polinom(koef, x)?.is_nol() {
+            return Ok(Some(x));
+        }
+    }
+    Ok(None)
+}
+
+// Nilai eigen real beserta kelipatan aljabarnya, dan akar kompleks jika ada
+type DaftarAkar = (Vec<(NilaiEigen, usize)>, Option<Teks>);
+
+// Akar λ² + bλ + c = 0 dengan diskriminan D = b² − 4c
+fn akar_kuadrat(b: Pecahan, c: Pecahan, steps: &mut Teks) -> Hitung<DaftarAkar> {
+    let d = b.kali(b)?.kurang(Pecahan::from(4).kali(c)?)?;
+    steps.tulis(|t| {
+        format!(
+            "  D = b² − 4c = {}² − 4·{} = {}\n",
+            kurung(b, t),
+            kurung(c, t),
+            d.tampil(t)
+        )
+    });
+    // λ = p ± q√r, dengan p = -b/2 dan q√r = √|D| / 2
+    let p = (-b).bagi(Pecahan::from(2))?;
+    let (q, r) = sederhana_akar(d.abs().bagi(Pecahan::from(4))?)?;
+    Ok(if d.is_nol() {
+        steps.tulis(|t| format!("  D = 0 → λ = -b/2 = {} (kembar)\n", p.tampil(t)));
+        (vec![(NilaiEigen::Rasional(p), 2)], None)
+    } else if d.is_negatif() {
+        let mut kompleks = Teks::default();
+        kompleks.coba_tulis(|t| bentuk_akar(p, q, r, true, "±", t))?;
+        steps.tulis(|t| {
+            format!(
+                "  D < 0 → λ = {} (kompleks sekawan, bukan bilangan real)\n",
+                kompleks.tampil(t)
+            )
+        });
+        (Vec::new(), Some(kompleks))
+    } else if r == 1 {
+        let (x1, x2) = (p.kurang(q)?, p.tambah(q)?);
+        steps.tulis(|t| {
+            format!(
+                "  D > 0 → λ = (-b ± √D)/2 = {} atau {}\n",
+                x1.tampil(t),
+                x2.tampil(t)
+            )
+        });
+        (
+            vec![(NilaiEigen::Rasional(x1), 1), (NilaiEigen::Rasional(x2), 1)],
+            None,
+        )
+    } else {
+        steps.coba_tulis(|t| {
+            Ok(format!(
+                "  D > 0 → λ = (-b ± √D)/2 = {}\n",
+                bentuk_akar(p, q, r, false, "±", t)?
+            ))
+        })?;
+        let akar = vec![
+            (NilaiEigen::Akar { p, q: -q, r }, 1),
+            (NilaiEigen::Akar { p, q, r }, 1),
+        ];
+        (akar, None)
+    })
+}
+
+// Akar real λ³ + bλ² + cλ + e dengan metode bagi dua pada [-R, R],
+// R = 1 + maks |koefisien| (batas Cauchy)
+fn bagi_dua(koef: &[f64]) -> f64 {
+    let f = |x: f64| koef.iter().fold(1.0, |h, k| h * x + k);
+    let batas = 1.0 + koef.iter().fold(0.0_f64, |m, k| m.max(k.abs()));
+    let (mut kiri, mut kanan) = (-batas, batas);
+    for _ in 0..200 {
+        let tengah = (kiri + kanan) / 2.0;
+        if f(kiri) * f(tengah) <= 0.0 {
+            kanan = tengah;
+        } else {
+            kiri = tengah;
+        }
+    }
+    (kiri + kanan) / 2.0
+}
+
+// Akar polinom karakteristik 3 × 3 yang tidak memiliki akar rasional
+fn akar_numerik(koef: &[Pecahan], steps: &mut Teks) -> DaftarAkar {
+    let k: Vec<f64> = koef.iter().map(|x| x.ke_f64()).collect();
+    let s = bagi_dua(&k);
+    steps.tulis(|_| {
+        format!(
+            "  Tidak ada akar rasional → akar dicari secara numerik.\n  \
+         Metode bagi dua pada [-R, R] dengan R = 1 + maks |koefisien|:\n  \
+         λ ≈ {}\n",
+            desimal(s)
+        )
+    });
+    // Bagi sintetis dengan (λ − s)
+    let (b, c) = (k[0] + s, k[1] + s * (k[0] + s));
+    steps.tulis(|_| {
+        format!(
+            "  Bagi sintetis dengan ({}): λ² + ({})λ + ({}) ≈ 0\n",
+            faktor(desimal(s)),
+            desimal(b),
+            desimal(c)
+        )
+    });
+    let d = b * b - 4.0 * c;
+    steps.tulis(|_| format!("  D ≈ {}\n", desimal(d)));
+    if d < 0.0 {
+        let kompleks =
+            Teks::dari(|_| format!("{} ± {}i", desimal(-b / 2.0), desimal((-d).sqrt() / 2.0)));
+        steps.tulis(|t| {
+            format!(
+                "  D < 0 → λ ≈ {} (kompleks sekawan, bukan bilangan real)\n",
+                kompleks.tampil(t)
+            )
+        });
+        (vec![(NilaiEigen::Hampiran(s), 1)], Some(kompleks))
+    } else {
+        let (l1, l2) = ((-b - d.sqrt()) / 2.0, (-b + d.sqrt()) / 2.0);
+        steps.tulis(|_| format!("  D > 0 → λ ≈ {} atau λ ≈ {}\n", desimal(l1), desimal(l2)));
+        let akar = [s, l1, l2]
+            .into_iter()
+            .map(|x| (NilaiEigen::Hampiran(x), 1))
+            .collect();
+        (akar, None)
+    }
+}
+
+// Polinom karakteristik beserta langkah determinannya. Mengembalikan
+// koefisien polinom monik det(λI − A) setelah suku pangkat tertinggi.
+fn polinom_karakteristik(a: &[Vec<Pecahan>], steps: &mut Teks) -> Hitung<Vec<Pecahan>> {
+    let n = a.len();
+    steps.push_str("\nLangkah 1: Polinom karakteristik det(A − λI) = 0\n");
+    steps.gambar(|t| {
+        let sel: Vec<Vec<String>> = (0..n)
+            .map(|i| {
+                (0..n)
+                    .map(|j| match (i == j, a[i][j].is_nol()) {
+                        (true, true) => "-λ".to_string(),
+                        (true, false) => format!("{} − λ", a[i][j].tampil(t)),
+                        (false, _) => a[i][j].tampil(t).to_string(),
+                    })
+                    .collect()
+            })
+            .collect();
+        tulis_sel("det(A − λI) = ", &sel, Kurung::Tegak, "")
+    });
+    let jejak = Pecahan::jumlah((0..n).map(|i| a[i][i]))?;
+    let det = determinan(a)?;
+    if n == 2 {
+        steps.tulis(|t| {
+            format!(
+                "  det(A − λI) = ({} − λ)({} − λ) − {}·{}\n              \
+             = λ² − ({} + {})λ + ({}·{} − {}·{})\n",
+                a[0][0].tampil(t),
+                a[1][1].tampil(t),
+                kurung(a[0][1], t),
+                kurung(a[1][0], t),
+                a[0][0].tampil(t),
+                kurung(a[1][1], t),
+                kurung(a[0][0], t),
+                kurung(a[1][1], t),
+                kurung(a[0][1], t),
+                kurung(a[1][0], t)
+            )
+        });
+        let koef = vec![-jejak, det];
+        steps.tulis(|t| format!("              = {}\n", polinom(&koef, t)));
+        return Ok(koef);
+    }
+
+    // 3 × 3: det(A − λI) = −λ³ + tr(A)λ² − Mλ + det(A)
+    steps.push_str(
+        "  Untuk matriks 3×3: det(A − λI) = −λ³ + tr(A)λ² − Mλ + det(A),\n  \
+         dengan M jumlah minor utama 2×2.\n",
+    );
+    steps.tulis(|t| {
+        format!(
+            "  tr(A) = {} + {} + {} = {}\n",
+            a[0][0].tampil(t),
+            kurung(a[1][1], t),
+            kurung(a[2][2], t),
+            jejak.tampil(t)
+        )
+    });
+    let pasangan = [(0, 1), (0, 2), (1, 2)];
+    let minor: Vec<Pecahan> = pasangan
+        .iter()
+        .map(|&(i, j)| a[i][i].kali(a[j][j])?.kurang(a[i][j].kali(a[j][i])?))
+        .collect::<Hitung<_>>()?;
+    let jumlah = Pecahan::jumlah(minor.iter().copied())?;
+    steps.tulis(|t| {
+        let rumus: Vec<String> = pasangan
+            .iter()
+            .map(|&(i, j)| {
+                format!(
+                    "({}·{} − {}·{})",
+                    kurung(a[i][i], t),
+                    kurung(a[j][j], t),
+                    kurung(a[i][j], t),
+                    kurung(a[j][i], t)
+                )
+            })
+            .collect();
+        format!(
+            "  M = {}\n    = {} = {}\n",
+            rumus.join(" + "),
+            minor
+                .iter()
+                .map(|m| kurung(*m, t))
+                .collect::<Vec<_>>()
+                .join(" + "),
+            jumlah.tampil(t)
+        )
+    });
+    steps.sambung(&langkah_determinan("det(A)", a)?);
+    let koef = vec![-jejak, jumlah, -det];
+    steps.tulis(|t| format!("  det(A − λI) = −({})\n", polinom(&koef, t)));
+    Ok(koef)
+}
+
+// Vektor dikalikan KPK penyebutnya agar unsurnya bulat, mis. (1/2, 1) → (1, 2)
+fn bulatkan(v: Vec<Pecahan>) -> Hitung<Vec<Pecahan>> {
+    match v.iter().try_fold(1, |l, x| kpk(l, x.penyebut())) {
+        Some(kpk) => v.into_iter().map(|x| x.kali(Pecahan::from(kpk))).collect(),
+        None => Ok(v),
+    }
+}
+
+/// Periksa ukuran A sebelum dihitung: hanya matriks persegi 2 × 2 atau 3 × 3
+pub fn periksa_ukuran(baris: usize, kolom: usize) -> Result<(), String> {
+    if baris != kolom {
+        Err(format!(
+            "A berukuran {}×{} — matriks harus persegi (n×n)",
+            baris, kolom
+        ))
+    } else if !(2..=3).contains(&kolom) {
+        Err(format!(
+            "A berukuran {}×{} — hanya 2×2 atau 3×3",
+            baris, kolom
+        ))
+    } else {
+        Ok(())
+    }
+}
+
+/// Vektor kolom, mis. "(1, (1 + √5)/2)ᵀ"
+pub fn vektor_teks(v: &[NilaiEigen], t: Tampilan) -> Hitung<String> {
+    let isi: Vec<String> = v.iter().map(|x| x.tulis(t)).collect::<Hitung<_>>()?;
+    Ok(format!("({})ᵀ", isi.join(", ")))
+}
+
+// Vektor eigen hampiran untuk 3 × 3: hasil kali silang dua baris A − λI
+// (tegak lurus kedua baris tersebut) yang terpanjang, diskalakan agar
+// unsur terbesarnya 1
+fn vektor_numerik(a: &[Vec<Pecahan>], lambda: f64) -> Vec<f64> {
+    let m: Vec<Vec<f64>> = (0..3)
+        .map(|i| {
+            (0..3)
+                .map(|j| a[i][j].ke_f64() - if i == j { lambda } else { 0.0 })
+                .collect()
+        })
+        .collect();
+    let silang = |p: &[f64], q: &[f64]| {
+        vec![
+            p[1] * q[2] - p[2] * q[1],
+            p[2] * q[0] - p[0] * q[2],
+            p[0] * q[1] - p[1] * q[0],
+        ]
+    };
+    let panjang = |v: &[f64]| v.iter().map(|x| x * x).sum::<f64>();
+    let v = [(0, 1), (0, 2), (1, 2)]
+        .iter()
+        .map(|&(i, j)| silang(&m[i], &m[j]))
+        .fold(vec![0.0; 3], |terbaik, v| {
+            if panjang(&v) > panjang(&terbaik) {
+                v
+            } else {
+                terbaik
+            }
+        });
+    let skala = v
+        .iter()
+        .copied()
+        .fold(0.0, |m: f64, x| if x.abs() > m.abs() { x } else { m });
+    v.iter().map(|x| x / skala).collect()
+}
+
+//
+// Nilai dan Vektor Eigen (2 × 2 dan 3 × 3)
+// Nilai eigen adalah akar persamaan karakteristik det(A − λI) = 0. Untuk 2 × 2
+// akarnya dicari dengan rumus abc (eksak, bentuk akar jika irasional); untuk
+// 3 × 3 dengan Teorema Akar Rasional lalu bagi sintetis, atau metode bagi dua
+// jika tidak ada akar rasional. Vektor eigen adalah basis ruang nol A − λI.
+// A dapat didiagonalkan (A = PDP⁻¹) jika semua nilai eigen real dan jumlah
+// kelipatan geometrinya sama dengan n.
+//
+pub fn eigen_proses(a: &[Vec<Pecahan>]) -> Hitung<(HasilEigen, Teks)> {
+    let n = a.len();
+    let mut steps = Teks::default();
+    steps.gambar(|t| tulis("A = ", a, Kurung::Siku, "", t));
+    let koef = polinom_karakteristik(a, &mut steps)?;
+
+    steps.tulis(|t| format!("\nLangkah 2: Nilai eigen, akar {} = 0\n", polinom(&koef, t)));
+    let (mut akar, kompleks) = if n == 2 {
+        akar_kuadrat(koef[0], koef[1], &mut steps)?
+    } else if let Some(s) = akar_rasional(&koef)? {
+        steps.tulis(|t| {
+            format!(
+                "  Uji akar rasional ±p/q (p pembagi suku tetap, q pembagi koefisien λ³):\n  \
+             p({}) = 0 → λ = {} adalah akar\n",
+                kurung(s, t),
+                s.tampil(t)
+            )
+        });
+        // Bagi sintetis dengan (λ − s)
+        let b = koef[0].tambah(s)?;
+        let c = koef[1].tambah(s.kali(b)?)?;
+        steps.tulis(|t| {
+            format!(
+                "  Bagi sintetis dengan ({}): {}\n",
+                faktor(s.tampil(t).to_string()),
+                polinom(&[b, c], t)
+            )
+        });
+        let (mut sisa, kompleks) = akar_kuadrat(b, c, &mut steps)?;
+        match sisa
+            .iter_mut()
+            .find(|(x, _)| matches!(x, NilaiEigen::Rasional(x) if *x == s))
+        {
+            Some((_, k)) => *k += 1,
+            None => sisa.push((NilaiEigen::Rasional(s), 1)),
+        }
+        (sisa, kompleks)
+    } else {
+        akar_numerik(&koef, &mut steps)
+    };
+    akar.sort_by(|p, q| p.0.ke_f64().total_cmp(&q.0.ke_f64()));
+
+    steps.push_str("\nLangkah 3: Vektor eigen, basis ruang nol A − λI\n");
+    if akar.is_empty() {
+        steps.push_str("  Tidak ada nilai eigen real, sehingga tidak ada vektor eigen real.\n");
+    }
+    let mut eigen: Vec<Eigen> = Vec::new();
+    // Vektor eksak untuk pemeriksaan AP = PD jika semua nilai eigen rasional
+    let mut eksak: Option<Vec<Vec<Pecahan>>> = Some(Vec::new());
+    for (i, &(nilai, aljabar)) in akar.iter().enumerate() {
+        steps.coba_tulis(|t| {
+            Ok(format!(
+                "\nλ{} {} {}:\n",
+                i + 1,
+                nilai.relasi(),
+                nilai.tulis(t)?
+            ))
+        })?;
+        let vektor: Vec<Vec<NilaiEigen>> = match nilai {
+            NilaiEigen::Rasional(s) => {
+                let mut m: Vec<Vec<Pecahan>> = a.to_vec();
+                for (r, b) in m.iter_mut().enumerate() {
+                    b[r] = b[r].kurang(s)?;
+                }
+                steps.gambar(|t| {
+                    tulis(
+                        &format!("A − {}I = ", kurung(s, t)),
+                        &m,
+                        Kurung::Siku,
+                        "",
+                        t,
+                    )
+                });
+                let (pivot, _) = gauss_jordan(&mut m, n, None, false)?;
+                steps.gambar(|t| tulis("RREF = ", &m, Kurung::Siku, "", t));
+                let basis: Vec<Vec<Pecahan>> = basis_nol(&m, &pivot)
+                    .into_iter()
+                    .map(bulatkan)
+                    .collect::<Hitung<_>>()?;
+                if let Some(e) = eksak.as_mut() {
+                    e.extend(basis.iter().cloned());
+                }
+                basis
+                    .iter()
+                    .map(|v| v.iter().map(|x| NilaiEigen::Rasional(*x)).collect())
+                    .collect()
+            }
+            NilaiEigen::Akar { p, q, r } if n == 2 => {
+                // Baris pertama (a11 − λ)x1 + a12·x2 = 0 dipenuhi oleh (a12, λ − a11);
+                // a12 ≠ 0 karena nilai eigen irasional
+                eksak = None;
+                steps.tulis(|t| {
+                    format!(
+                        "  Baris pertama: ({} − λ)x1 + {}·x2 = 0\n  \
+                     dipenuhi oleh x1 = {}, x2 = λ − {}\n",
+                        a[0][0].tampil(t),
+                        kurung(a[0][1], t),
+                        a[0][1].tampil(t),
+                        kurung(a[0][0], t)
+                    )
+                });
+                vec![vec![
+                    NilaiEigen::Rasional(a[0][1]),
+                    NilaiEigen::Akar {
+                        p: p.kurang(a[0][0])?,
+                        q,
+                        r,
+                    },
+                ]]
+            }
+            _ => {
+                eksak = None;
+                let v = vektor_numerik(a, nilai.ke_f64());
+                steps.push_str(
+                    "  Hasil kali silang dua baris A − λI tegak lurus kedua baris tersebut,\n  \
+                     lalu diskalakan agar unsur terbesarnya 1 (hampiran):\n",
+                );
+                vec![v.iter().map(|x| NilaiEigen::Hampiran(*x)).collect()]
+            }
+        };
+        for (k, v) in vektor.iter().enumerate() {
+            steps.coba_tulis(|t| {
+                Ok(format!(
+                    "  v{}{} = {}\n",
+                    i + 1,
+                    huruf(k, vektor.len()),
+                    vektor_teks(v, t)?
+                ))
+            })?;
+        }
+        steps.tulis(|_| {
+            format!(
+                "  Kelipatan aljabar {}, kelipatan geometri {}\n",
+                aljabar,
+                vektor.len()
+            )
+        });
+        eigen.push(Eigen {
+            nilai,
+            aljabar,
+            vektor,
+        });
+    }
+
+    steps.push_str("\nLangkah 4: Keterdiagonalan\n");
+    let geometri: usize = eigen.iter().map(|e| e.vektor.len()).sum();
+    let diagonal = kompleks.is_none() && geometri == n;
+    if let Some(kompleks) = &kompleks {
+        steps.tulis(|t| {
+            format!(
+                "  A memiliki nilai eigen kompleks λ = {}, sehingga A tidak dapat\n  \
+             didiagonalkan atas ℝ (atas ℂ dapat, karena semua nilai eigennya berbeda).\n",
+                kompleks.tampil(t)
+            )
+        });
+    } else if let Some(e) = eigen.iter().find(|e| e.vektor.len() < e.aljabar) {
+        steps.coba_tulis(|t| {
+            Ok(format!(
+                "  λ = {} memiliki kelipatan aljabar {} tetapi kelipatan geometri {},\n  \
+             sehingga hanya ada {} vektor eigen bebas linear dari {} yang diperlukan.\n  \
+             A tidak dapat didiagonalkan.\n",
+                e.nilai.tulis(t)?,
+                e.aljabar,
+                e.vektor.len(),
+                geometri,
+                n
+            ))
+        })?;
+    } else {
+        steps.tulis(|_| {
+            format!(
+                "  Jumlah kelipatan geometri = {} = n → A dapat didiagonalkan, A = PDP⁻¹\n  \
+             dengan kolom P vektor eigen dan D diagonal berisi nilai eigennya.\n",
+                geometri
+            )
+        });
+        let kolom: Vec<(NilaiEigen, &Vec<NilaiEigen>)> = eigen
+            .iter()
+            .flat_map(|e| e.vektor.iter().map(move |v| (e.nilai, v)))
+            .collect();
+        steps.coba_gambar(|t| {
+            let p: Vec<Vec<String>> = (0..n)
+                .map(|i| kolom.iter().map(|(_, v)| v[i].tulis(t)).collect())
+                .collect::<Hitung<_>>()?;
+            Ok(tulis_sel("P = ", &p, Kurung::Siku, ""))
+        })?;
+        steps.coba_gambar(|t| {
+            let d: Vec<Vec<String>> = (0..n)
+                .map(|i| {
+                    (0..n)
+                        .map(|j| {
+                            if i == j {
+                                kolom[i].0.tulis(t)
+                            } else {
+                                Ok("0".to_string())
+                            }
+                        })
+                        .collect()
+                })
+                .collect::<Hitung<_>>()?;
+            Ok(tulis_sel("D = ", &d, Kurung::Siku, ""))
+        })?;
+        if let Some(v) = eksak {
+            // AP = PD: kolom ke-j AP adalah A·vⱼ = λⱼvⱼ
+            let lambda: Vec<Pecahan> = eigen
+                .iter()
+                .flat_map(|e| match e.nilai {
+                    NilaiEigen::Rasional(x) => vec![x; e.vektor.len()],
+                    _ => Vec::new(),
+                })
+                .collect();
+            let ap: Vec<Vec<Pecahan>> = (0..n)
+                .map(|i| {
+                    v.iter()
+                        .map(|vj| Pecahan::jumlah_perkalian((0..n).map(|t| (a[i][t], vj[t]))))
+                        .collect()
+                })
+                .collect::<Hitung<_>>()?;
+            let pd: Vec<Vec<Pecahan>> = (0..n)
+                .map(|i| {
+                    v.iter()
+                        .zip(&lambda)
+                        .map(|(vj, l)| vj[i].kali(*l))
+                        .collect()
+                })
+                .collect::<Hitung<_>>()?;
+            steps.gambar(|t| {
+                tulis(
+                    "Periksa: AP = PD = ",
+                    &ap,
+                    Kurung::Siku,
+                    if ap == pd { " ✓" } else { " ✗" },
+                    t,
+                )
+            });
+        }
+    }
+
+    Ok((
+        HasilEigen {
+            eigen,
+            kompleks,
+            diagonal,
+        },
+        steps,
+    ))
+}
+
+// Akhiran nama vektor jika satu nilai eigen memiliki lebih dari satu
+// vektor basis, mis. v1a, v1b
+fn huruf(k: usize, banyak: usize) -> String {
+    if banyak == 1 {
+        String::new()
+    } else {
+        ((b'a' + k as u8) as char).to_string()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::solver::pecahan::uji::matriks;
+
+    const P: Tampilan = Tampilan::Pecahan;
+
+    #[test]
+    fn ukuran_diperiksa() {
+        assert!(periksa_ukuran(2, 2).is_ok());
+        assert!(periksa_ukuran(3, 3).is_ok());
+        assert_eq!(
+            periksa_ukuran(2, 3),
+            Err("A berukuran 2×3 — matriks harus persegi (n×n)".to_string())
+        );
+        assert_eq!(
+            periksa_ukuran(4, 4),
+            Err("A berukuran 4×4 — hanya 2×2 atau 3×3".to_string())
+        );
+    }
+
+    #[test]
+    fn nilai_eigen_rasional() {
+        let (hasil, _) = eigen_proses(&matriks(&[&[2, 1], &[1, 2]])).unwrap();
+        let nilai: Vec<String> = hasil
+            .eigen
+            .iter()
+            .map(|e| e.nilai.tulis(P).unwrap())
+            .collect();
+        assert_eq!(nilai, ["1", "3"]);
+        assert!(
+            hasil
+                .eigen
+                .iter()
+                .all(|e| e.aljabar == 1 && e.vektor.len() == 1)
+        );
+        assert!(hasil.kompleks.is_none());
+        assert!(hasil.diagonal);
+    }
+
+    #[test]
+    fn nilai_eigen_dengan_faktor_prima_besar() {
+        // λ² = 10007², dengan 10007 di atas batas pembagian percobaan
+        let (hasil, _) = eigen_proses(&matriks(&[&[0, 100_140_049], &[1, 0]])).unwrap();
+        let nilai: Vec<String> = hasil
+            .eigen
+            .iter()
+            .map(|e| e.nilai.tulis(P).unwrap())
+            .collect();
+        assert_eq!(nilai, ["-10007", "10007"]);
+    }
+
+    #[test]
+    fn nilai_eigen_irasional_dan_kompleks() {
+        let (hasil, _) = eigen_proses(&matriks(&[&[1, 1], &[1, 0]])).unwrap();
+        let nilai: Vec<String> = hasil
+            .eigen
+            .iter()
+            .map(|e| e.nilai.tulis(P).unwrap())
+            .collect();
+        assert_eq!(nilai, ["(1 - √5)/2", "(1 + √5)/2"]);
+        assert!(hasil.eigen.iter().all(|e| e.nilai.relasi() == "="));
+
+        let (hasil, _) = eigen_proses(&matriks(&[&[0, -1], &[1, 0]])).unwrap();
+        assert!(hasil.eigen.is_empty());
+        assert!(hasil.kompleks.is_some());
+        assert!(!hasil.diagonal);
+    }
+
+    #[test]
+    fn matriks_tidak_terdiagonalkan() {
+        let (hasil, _) = eigen_proses(&matriks(&[&[1, 1], &[0, 1]])).unwrap();
+        assert_eq!(hasil.eigen.len(), 1);
+        assert_eq!(hasil.eigen[0].aljabar, 2);
+        assert_eq!(hasil.eigen[0].vektor.len(), 1);
+        assert!(!hasil.diagonal);
+    }
+}
@@ -0,0 +1,724 @@
+use crate::solver::format::persamaan;
+use crate::solver::pecahan::{Hitung, MAKS_DIGIT, Pecahan, Tampilan};
+use crate::solver::substitusi::Bentuk;
+use std::cell::Cell;
+
+/// Pohon ekspresi hasil penguraian isian, mis. `-(4+1)/2` atau `2sqrt(3)`
+#[derive(Clone, Debug, PartialEq)]
+pub enum Ekspresi {
+    Angka(Pecahan),
+    Nama(String),
+    Negatif(Box<Ekspresi>),
+    Operasi(char, Box<Ekspresi>, Box<Ekspresi>),
+    Fungsi(String, Box<Ekspresi>),
+}
+
+/// Nilai ekspresi: pecahan eksak, atau pendekatan rasional jika ekspresi
+/// mengandung bilangan irasional (mis. `sqrt(2)` atau `pi`)
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct Nilai {
+    pub nilai: Pecahan,
+    pub eksak: bool,
+}
+
+const FUNGSI: [&str; 3] = ["sqrt", "akar", "abs"];
+
+thread_local! {
+    static HAMPIRAN: Cell<bool> = const { Cell::new(false) };
+}
+
+/// Menjalankan `f` dan melaporkan apakah ada isian yang nilainya hanya
+/// didekati (mis. `sqrt(2)` atau `pi`) selama `f` berjalan
+pub fn catat_hampiran<T>(f: impl FnOnce() -> T) -> (T, bool) {
+    let lama = HAMPIRAN.with(|h| h.replace(false));
+    let hasil = f();
+    let hampiran = HAMPIRAN.with(|h| h.replace(lama));
+    (hasil, hampiran)
+}
+
+/// Karakter yang boleh diketik di kotak isian koefisien
+pub fn karakter_valid(c: char) -> bool {
+    c.is_ascii_alphanumeric() || "+-*/^()., ".contains(c)
+}
+
+/// Mengurai lalu menghitung isian koefisien
+pub fn hitung(teks: &str) -> Result<Nilai, String> {
+    nilai(&parse(teks)?)
+}
+
+#[derive(Clone, Debug, PartialEq)]
+enum Token {
+    Angka(Pecahan),
+    Nama(String),
+    Simbol(char),
+}
+
+fn token(teks: &str) -> Result<Vec<Token>, String> {
+    let mut hasil = Vec::new();
+    let mut chars = teks.chars().peekable();
+    while let Some(&c) = chars.peek() {
+        if c.is_whitespace() {
+            chars.next();
+        } else if c.is_ascii_digit() || c == '.' || c == ',' {
+            let mut angka = String::new();
+            while let Some(&d) = chars.peek() {
+                if d.is_ascii_digit() || d == '.' || d == ',' {
+                    // Koma juga diterima sebagai pemisah desimal
+                    angka.push(if d == ',' { '.' } else { d });
+                    chars.next();
+                } else {
+                    break;
+                }
+            }
+            let p = angka.parse().map_err(|_| {
+                // Angka dengan satu titik desimal hanya gagal karena terlalu panjang
+                if angka != "." && angka.matches('.').count() <= 1 {
+                    format!("angka terlalu panjang (maks. {} digit)", MAKS_DIGIT)
+                } else {
+                    format!("angka '{}' tidak valid", angka)
+                }
+            })?;
+            hasil.push(Token::Angka(p));
+        } else if c.is_ascii_alphabetic() || c == '_' {
+            let mut nama = String::new();
+            while let Some(&d) = chars.peek() {
+                if d.is_ascii_alphanumeric() || d == '_' {
+                    nama.push(d);
+                    chars.next();
+                } else {
+                    break;
+                }
+            }
+            hasil.push(Token::Nama(nama));
+        } else if "+-*/^()".contains(c) {
+            hasil.push(Token::Simbol(c));
+            chars.next();
+        } else {
+            return Err(format!("karakter '{}' tidak dikenal", c));
+        }
+    }
+    Ok(hasil)
+}
+
+/// Mengurai teks menjadi pohon ekspresi.
+/// Perkalian boleh ditulis tanpa tanda: `2x`, `3(1+2)`, `2sqrt(5)`, dengan
+/// aturan berikut agar tidak rancu:
+/// - nama ditulis bersambung adalah satu nama, dan boleh diakhiri angka:
+///   `xy` adalah variabel `xy` dan `3x2` berarti 3·x2;
+/// - angka harus didahului operator, sehingga `2 3` dan `x 2` ditolak;
+/// - pembagi tidak boleh langsung diikuti perkalian tanpa tanda, sehingga
+///   `1/2x` ditolak dan harus ditulis `(1/2)x` atau `1/(2x)`.
+pub fn parse(teks: &str) -> Result<Ekspresi, String> {
+    let token = token(teks)?;
+    if token.is_empty() {
+        return Err("isian kosong".to_string());
+    }
+    let mut p = Pengurai { token, posisi: 0 };
+    let e = p.jumlah()?;
+    match p.lihat() {
+        None => Ok(e),
+        Some(Token::Simbol(')')) => Err("kurung tutup berlebih".to_string()),
+        Some(_) => Err("ekspresi tidak lengkap".to_string()),
+    }
+}
+
+struct Pengurai {
+    token: Vec<Token>,
+    posisi: usize,
+}
+
+impl Pengurai {
+    fn lihat(&self) -> Option<&Token> {
+        self.token.get(self.posisi)
+    }
+
+    fn simbol(&mut self, s: char) -> bool {
+        if self.lihat() == Some(&Token::Simbol(s)) {
+            self.posisi += 1;
+            true
+        } else {
+            false
+        }
+    }
+
+    // jumlah := kali (('+' | '-') kali)*
+    fn jumlah(&mut self) -> Result<Ekspresi, String> {
+        let mut kiri = self.kali()?;
+        loop {
+            let op = if self.simbol('+') {
+                '+'
+            } else if self.simbol('-') {
+                '-'
+            } else {
+                return Ok(kiri);
+            };
+            kiri = Ekspresi::Operasi(op, Box::new(kiri), Box::new(self.kali()?));
+        }
+    }
+
+    // kali := unari (('*' | '/' | tanpa tanda) unari)*
+    // Perkalian tanpa tanda tidak boleh diikuti angka atau langsung
+    // mengikuti pembagi (lihat `parse`)
+    fn kali(&mut self) -> Result<Ekspresi, String> {
+        let mut kiri = self.unari()?;
+        let mut pembagi = false;
+        loop {
+            let op = if self.simbol('*') {
+                '*'
+            } else if self.simbol('/') {
+                '/'
+            } else if matches!(self.lihat(), Some(Token::Angka(_))) {
+                return Err("angka harus didahului operator, mis. 2*3 bukan 2 3".to_string());
+            } else if matches!(self.lihat(), Some(Token::Nama(_) | Token::Simbol('('))) {
+                if pembagi {
+                    return Err("pembagian rancu, tulis (1/2)x atau 1/(2x)".to_string());
+                }
+                '*'
+            } else {
+                return Ok(kiri);
+            };
+            pembagi = op == '/';
+            kiri = Ekspresi::Operasi(op, Box::new(kiri), Box::new(self.unari()?));
+        }
+    }
+
+    // unari := ('-' | '+') unari | pangkat
+    fn unari(&mut self) -> Result<Ekspresi, String> {
+        if self.simbol('-') {
+            Ok(Ekspresi::Negatif(Box::new(self.unari()?)))
+        } else if self.simbol('+') {
+            self.unari()
+        } else {
+            self.pangkat()
+        }
+    }
+
+    // pangkat := atom ('^' unari)?
+    fn pangkat(&mut self) -> Result<Ekspresi, String> {
+        let dasar = self.atom()?;
+        if self.simbol('^') {
+            Ok(Ekspresi::Operasi(
+                '^',
+                Box::new(dasar),
+                Box::new(self.unari()?),
+            ))
+        } else {
+            Ok(dasar)
+        }
+    }
+
+    // atom := angka | fungsi '(' jumlah ')' | nama | '(' jumlah ')'
+    fn atom(&mut self) -> Result<Ekspresi, String> {
+        match self.lihat().cloned() {
+            Some(Token::Angka(p)) => {
+                self.posisi += 1;
+                Ok(Ekspresi::Angka(p))
+            }
+            Some(Token::Nama(nama)) => {
+                self.posisi += 1;
+                if FUNGSI.contains(&nama.as_str()) {
+                    if !self.simbol('(') {
+                        return Err(format!("{} harus diikuti kurung, mis. {}(2)", nama, nama));
+                    }
+                    let isi = self.jumlah()?;
+                    if !self.simbol(')') {
+                        return Err("kurung tidak ditutup".to_string());
+                    }
+                    Ok(Ekspresi::Fungsi(nama, Box::new(isi)))
+                } else {
+                    Ok(Ekspresi::Nama(nama))
+                }
+            }
+            Some(Token::Simbol('(')) => {
+                self.posisi += 1;
+                let isi = self.jumlah()?;
+                if !self.simbol(')') {
+                    return Err("kurung tidak ditutup".to_string());
+                }
+                Ok(isi)
+            }
+            Some(Token::Simbol(c)) => Err(format!("'{}' tidak pada tempatnya", c)),
+            None => Err("ekspresi tidak lengkap".to_string()),
+        }
+    }
+}
+
+fn perkiraan(v: f64) -> Result<Nilai, String> {
+    HAMPIRAN.with(|h| h.set(true));
+    Pecahan::dari_f64(v)
+        .map(|nilai| Nilai {
+            nilai,
+            eksak: false,
+        })
+        .ok_or_else(|| "hasil bukan bilangan real".to_string())
+}
+
+/// Menghitung nilai ekspresi tanpa variabel
+pub fn nilai(e: &Ekspresi) -> Result<Nilai, String> {
+    match e {
+        Ekspresi::Angka(p) => Ok(Nilai {
+            nilai: *p,
+            eksak: true,
+        }),
+        Ekspresi::Nama(nama) if nama == "pi" => perkiraan(std::f64::consts::PI),
+        Ekspresi::Nama(nama) => Err(format!("'{}' tidak dikenal", nama)),
+        Ekspresi::Negatif(a) => {
+            let a = nilai(a)?;
+            Ok(Nilai {
+                nilai: -a.nilai,
+                eksak: a.eksak,
+            })
+        }
+        Ekspresi::Fungsi(f, a) => {
+            let a = nilai(a)?;
+            match f.as_str() {
+                "abs" => Ok(Nilai {
+                    nilai: a.nilai.abs(),
+                    eksak: a.eksak,
+                }),
+                _ if a.nilai.is_negatif() => Err("akar dari bilangan negatif".to_string()),
+                _ => match a.nilai.akar_eksak() {
+                    Some(akar) => Ok(Nilai {
+                        nilai: akar,
+                        eksak: a.eksak,
+                    }),
+                    None => perkiraan(a.nilai.ke_f64().sqrt()),
+                },
+            }
+        }
+        Ekspresi::Operasi(op, a, b) => {
+            let (a, b) = (nilai(a)?, nilai(b)?);
+            let eksak = a.eksak && b.eksak;
+            let hasil = match op {
+                '+' => a.nilai.tambah(b.nilai)?,
+                '-' => a.nilai.kurang(b.nilai)?,
+                '*' => a.nilai.kali(b.nilai)?,
+                '/' => a.nilai.bagi(b.nilai)?,
+                _ => return pangkat(a, b),
+            };
+            Ok(Nilai {
+                nilai: hasil,
+                eksak,
+            })
+        }
+    }
+}
+
+// Pangkat bulat dihitung eksak, pangkat pecahan didekati
+fn pangkat(a: Nilai, b: Nilai) -> Result<Nilai, String> {
+    if b.eksak && b.nilai.is_bulat() && b.nilai.abs() <= Pecahan::from(64) {
+        let n = b.nilai.ke_f64() as i32;
+        return a
+            .nilai
+            .pangkat(n)
+            .map(|nilai| Nilai {
+                nilai,
+                eksak: a.eksak,
+            })
+            .map_err(|_| "hasil pangkat terlalu besar atau tidak terdefinisi".to_string());
+    }
+    perkiraan(a.nilai.ke_f64().powf(b.nilai.ke_f64()))
+}
+
+/// Bentuk linear Σ koefisien·variabel + konstanta.
+/// Urutan variabel mengikuti kemunculan pertamanya.
+#[derive(Clone, Debug, PartialEq)]
+pub struct BentukLinear {
+    pub suku: Vec<(String, Pecahan)>,
+    pub konstanta: Pecahan,
+}
+
+impl BentukLinear {
+    fn konstan(k: Pecahan) -> Self {
+        Self {
+            suku: Vec::new(),
+            konstanta: k,
+        }
+    }
+
+    fn is_konstan(&self) -> bool {
+        self.suku.iter().all(|(_, k)| k.is_nol())
+    }
+
+    fn kali(mut self, f: Pecahan) -> Hitung<Self> {
+        for (_, k) in &mut self.suku {
+            *k = k.kali(f)?;
+        }
+        self.konstanta = self.konstanta.kali(f)?;
+        Ok(self)
+    }
+
+    fn tambah(mut self, lain: Self) -> Hitung<Self> {
+        for (nama, k) in lain.suku {
+            match self.suku.iter_mut().find(|(n, _)| *n == nama) {
+                Some((_, lama)) => *lama = lama.tambah(k)?,
+                None => self.suku.push((nama, k)),
+            }
+        }
+        self.konstanta = self.konstanta.tambah(lain.konstanta)?;
+        Ok(self)
+    }
+
+    /// Koefisien variabel `nama` (nol jika tidak muncul)
+    pub fn koefisien(&self, nama: &str) -> Pecahan {
+        self.suku
+            .iter()
+            .find(|(n, _)| n == nama)
+            .map_or(Pecahan::NOL, |(_, k)| *k)
+    }
+}
+
+// Menyederhanakan ekspresi yang memuat variabel menjadi bentuk linear.
+// Jika `substitusi`, suku 1/x (atau x^-1), x², dan √x juga diterima sebagai
+// "variabel" tersendiri yang nantinya dimisalkan.
+fn uraikan(e: &Ekspresi, substitusi: bool) -> Result<BentukLinear, String> {
+    const TIDAK_LINEAR: &str = "persamaan tidak linear";
+    let atom = |bentuk: Bentuk, nama: &str, k: Pecahan| {
+        Ok(BentukLinear {
+            suku: vec![(bentuk.tulis(nama), k)],
+            konstanta: Pecahan::NOL,
+        })
+    };
+    match e {
+        Ekspresi::Nama(nama) if nama != "pi" => atom(Bentuk::Biasa, nama, Pecahan::SATU),
+        Ekspresi::Negatif(a) => Ok(uraikan(a, substitusi)?.kali(-Pecahan::SATU)?),
+        // Pangkat satu tidak mengubah apa pun, mis. 2x^1
+        Ekspresi::Operasi('^', a, b)
+            if variabel(b).is_empty() && nilai(b).is_ok_and(|n| n.nilai == Pecahan::SATU) =>
+        {
+            uraikan(a, substitusi)
+        }
+        Ekspresi::Operasi('^', a, b) if substitusi && variabel(b).is_empty() => {
+            match (a.as_ref(), nilai(b)?.nilai) {
+                (Ekspresi::Nama(nama), n) if nama != "pi" && n == Pecahan::from(2) => {
+                    atom(Bentuk::Kuadrat, nama, Pecahan::SATU)
+                }
+                (Ekspresi::Nama(nama), n) if nama != "pi" && n == -Pecahan::SATU => {
+                    atom(Bentuk::Kebalikan, nama, Pecahan::SATU)
+                }
+                _ => uraikan(e, false),
+            }
+        }
+        Ekspresi::Fungsi(f, a) if substitusi && f != "abs" && !variabel(a).is_empty() => {
+            match a.as_ref() {
+                Ekspresi::Nama(nama) => atom(Bentuk::Akar, nama, Pecahan::SATU),
+                _ => Err("hanya akar dari satu variabel yang bisa dimisalkan".to_string()),
+            }
+        }
+        Ekspresi::Operasi(op @ ('+' | '-' | '*' | '/'), a, b) => {
+            let (a, b) = (uraikan(a, substitusi)?, uraikan(b, substitusi)?);
+            match op {
+                '+' => Ok(a.tambah(b)?),
+                '-' => Ok(a.tambah(b.kali(-Pecahan::SATU)?)?),
+                '*' if a.is_konstan() => Ok(b.kali(a.konstanta)?),
+                '*' if b.is_konstan() => Ok(a.kali(b.konstanta)?),
+                // k / (cx) = (k/c) · 1/x
+                '/' if substitusi && a.is_konstan() && b.konstanta.is_nol() => {
+                    let suku: Vec<&(String, Pecahan)> =
+                        b.suku.iter().filter(|(_, k)| !k.is_nol()).collect();
+                    match suku[..] {
+                        [(nama, c)] if Bentuk::urai(nama).0 == Bentuk::Biasa => {
+                            atom(Bentuk::Kebalikan, nama, a.konstanta.bagi(*c)?)
+                        }
+                        _ => Err(TIDAK_LINEAR.to_string()),
+                    }
+                }
+                '/' if !b.is_konstan() => Err(TIDAK_LINEAR.to_string()),
+                '/' => Ok(a.kali(Pecahan::SATU.bagi(b.konstanta)?)?),
+                _ => Err(TIDAK_LINEAR.to_string()),
+            }
+        }
+        // Pangkat dan fungsi hanya boleh berisi konstanta
+        _ => match nilai(e) {
+            Ok(n) => Ok(BentukLinear::konstan(n.nilai)),
+            Err(_) if !variabel(e).is_empty() => Err(TIDAK_LINEAR.to_string()),
+            Err(err) => Err(err),
+        },
+    }
+}
+
+// Nama variabel yang muncul di dalam ekspresi
+fn variabel(e: &Ekspresi) -> Vec<&str> {
+    match e {
+        Ekspresi::Angka(_) => Vec::new(),
+        Ekspresi::Nama(nama) if nama == "pi" => Vec::new(),
+        Ekspresi::Nama(nama) => vec![nama],
+        Ekspresi::Negatif(a) | Ekspresi::Fungsi(_, a) => variabel(a),
+        Ekspresi::Operasi(_, a, b) => {
+            let mut v = variabel(a);
+            v.extend(variabel(b));
+            v
+        }
+    }
+}
+
+/// Mengurai persamaan bebas seperti `2x + 3y = 7 - y` menjadi
+/// bentuk linear `ruas kiri − ruas kanan = 0`
+pub fn persamaan_linear(teks: &str) -> Result<BentukLinear, String> {
+    uraikan_persamaan(teks, false)
+}
+
+/// Seperti [`persamaan_linear`], tetapi suku 1/x, x², dan √x diterima,
+/// mis. `2/x + 3/y = 1`
+pub fn persamaan_substitusi(teks: &str) -> Result<BentukLinear, String> {
+    uraikan_persamaan(teks, true)
+}
+
+/// Nama yang boleh dipakai sebagai parameter di isian koefisien
+pub const PARAMETER: [&str; 2] = ["k", "m"];
+
+/// Isian koefisien yang boleh memuat satu parameter, mis. `2k - 1`.
+/// Menghasilkan nama parameter (jika ada) dan koefisien [konstanta, koefisien parameter].
+pub fn hitung_parameter(teks: &str) -> Result<(Option<String>, [Pecahan; 2]), String> {
+    let bentuk = uraikan(&parse(teks)?, false)?;
+    let suku: Vec<&(String, Pecahan)> = bentuk.suku.iter().filter(|(_, k)| !k.is_nol()).collect();
+    match suku[..] {
+        [] => Ok((None, [bentuk.konstanta, Pecahan::NOL])),
+        [(nama, k)] if PARAMETER.contains(&nama.as_str()) => {
+            Ok((Some(nama.clone()), [bentuk.konstanta, *k]))
+        }
+        _ => Err("hanya parameter k atau m yang diperbolehkan".to_string()),
+    }
+}
+
+fn uraikan_persamaan(teks: &str, substitusi: bool) -> Result<BentukLinear, String> {
+    let (kiri, kanan) = match teks.split('=').collect::<Vec<_>>()[..] {
+        [kiri, kanan] => (kiri, kanan),
+        [_] => return Err("tanda '=' tidak ada".to_string()),
+        _ => return Err("tanda '=' lebih dari satu".to_string()),
+    };
+    let kiri = uraikan(
+        &parse(kiri).map_err(|e| format!("ruas kiri: {}", e))?,
+        substitusi,
+    )?;
+    let kanan = uraikan(
+        &parse(kanan).map_err(|e| format!("ruas kanan: {}", e))?,
+        substitusi,
+    )?;
+    Ok(kiri.tambah(kanan.kali(-Pecahan::SATU)?)?)
+}
+
+/// Sistem persamaan bebas dalam bentuk matriks diperbesar
+pub struct SistemLinear {
+    pub nama: Vec<String>,
+    pub matriks: Vec<Vec<Pecahan>>,
+}
+
+impl SistemLinear {
+    /// Persamaan ke-`i` dalam bentuk baku, mis. "2x + 3y = 7"
+    pub fn persamaan(&self, i: usize, t: Tampilan) -> String {
+        let n = self.nama.len();
+        let suku: Vec<(Pecahan, &str)> = self.matriks[i][..n]
+            .iter()
+            .zip(&self.nama)
+            .map(|(k, v)| (*k, v.as_str()))
+            .collect();
+        persamaan(&suku, self.matriks[i][n], t)
+    }
+}
+
+/// Mengurai setiap baris persamaan, atau (indeks baris, pesan) untuk
+/// baris pertama yang tidak valid
+pub fn sistem_linear(baris: &[String]) -> Result<SistemLinear, (usize, String)> {
+    uraikan_sistem(baris, false)
+}
+
+/// Seperti [`sistem_linear`] untuk persamaan dengan suku 1/x, x², dan √x.
+/// Setiap suku khusus menjadi satu kolom matriks.
+pub fn sistem_substitusi(baris: &[String]) -> Result<SistemLinear, (usize, String)> {
+    uraikan_sistem(baris, true)
+}
+
+fn uraikan_sistem(baris: &[String], substitusi: bool) -> Result<SistemLinear, (usize, String)> {
+    let bentuk = baris
+        .iter()
+        .enumerate()
+        .map(|(i, s)| uraikan_persamaan(s, substitusi).map_err(|e| (i, e)))
+        .collect::<Result<Vec<_>, _>>()?;
+    let mut nama: Vec<String> = Vec::new();
+    for (v, _) in bentuk.iter().flat_map(|b| &b.suku) {
+        if !nama.contains(v) {
+            nama.push(v.clone());
+        }
+    }
+    if nama.is_empty() {
+        return Err((0, "tidak ada variabel".to_string()));
+    }
+    // Nama beberapa huruf yang setiap hurufnya juga variabel, mis. xy
+    // bersama x dan y, rancu dengan perkalian
+    let dasar: Vec<&str> = nama.iter().map(|v| Bentuk::urai(v).1).collect();
+    for (v, d) in nama.iter().zip(&dasar) {
+        let huruf: Vec<String> = d.chars().map(String::from).collect();
+        if huruf.len() > 1
+            && d.chars().all(|c| c.is_ascii_alphabetic())
+            && huruf.iter().all(|h| dasar.contains(&h.as_str()))
+        {
+            let i = bentuk
+                .iter()
+                .position(|b| b.suku.iter().any(|(s, _)| s == v))
+                .unwrap_or(0);
+            return Err((
+                i,
+                format!("'{}' rancu dengan {}, beri nama lain", d, huruf.join("·")),
+            ));
+        }
+    }
+    let matriks = bentuk
+        .iter()
+        .map(|b| {
+            nama.iter()
+                .map(|v| b.koefisien(v))
+                .chain(std::iter::once(-b.konstanta))
+                .collect()
+        })
+        .collect();
+    Ok(SistemLinear { nama, matriks })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::solver::pecahan::uji::p;
+
+    const P: Tampilan = Tampilan::Pecahan;
+
+    fn baris(teks: &[&str]) -> Vec<String> {
+        teks.iter().map(|s| s.to_string()).collect()
+    }
+
+    #[test]
+    fn menghitung_isian() {
+        assert_eq!(hitung("1/2 + 0.25").map(|n| n.nilai), Ok(p(3, 4)));
+        assert_eq!(hitung("-(4+1)/2").map(|n| n.nilai), Ok(p(-5, 2)));
+        assert_eq!(hitung("2^3 - 3*2").map(|n| n.nilai), Ok(p(2, 1)));
+        assert_eq!(hitung("sqrt(9/4)").map(|n| n.eksak), Ok(true));
+        assert_eq!(hitung("sqrt(2)").map(|n| n.eksak), Ok(false));
+        assert!(hitung("1/0").is_err());
+        assert!(hitung("2 +").is_err());
+    }
+
+    #[test]
+    fn hampiran_dicatat() {
+        let (_, hampiran) = catat_hampiran(|| hitung("sqrt(4)"));
+        assert!(!hampiran);
+        let (_, hampiran) = catat_hampiran(|| hitung("pi"));
+        assert!(hampiran);
+    }
+
+    #[test]
+    fn persamaan_dinormalkan_ke_ruas_kiri() {
+        let bentuk = persamaan_linear("x - y = 1").unwrap();
+        assert_eq!(bentuk.koefisien("x"), p(1, 1));
+        assert_eq!(bentuk.koefisien("y"), p(-1, 1));
+        assert_eq!(bentuk.konstanta, p(-1, 1));
+
+        // Variabel di kedua ruas digabung: 2x + 3y − (7 − y) = 0
+        let bentuk = persamaan_linear("2x + 3y = 7 - y").unwrap();
+        assert_eq!(bentuk.koefisien("x"), p(2, 1));
+        assert_eq!(bentuk.koefisien("y"), p(4, 1));
+        assert_eq!(bentuk.konstanta, p(-7, 1));
+
+        // Variabel yang saling menghapus tetap bernilai nol
+        let bentuk = persamaan_linear("x + y = y + 2").unwrap();
+        assert_eq!(bentuk.koefisien("y"), Pecahan::NOL);
+
+        // Pangkat satu tetap linear
+        let bentuk = persamaan_linear("2x^1 + y = 3").unwrap();
+        assert_eq!(bentuk.koefisien("x"), p(2, 1));
+        assert_eq!(bentuk.koefisien("y"), p(1, 1));
+        let bentuk = persamaan_linear("(x + y)^(3 - 2) = 1").unwrap();
+        assert_eq!(bentuk.koefisien("y"), p(1, 1));
+    }
+
+    #[test]
+    fn persamaan_tidak_valid() {
+        assert!(persamaan_linear("x + 1").is_err());
+        assert!(persamaan_linear("x = 1 = 2").is_err());
+        assert!(persamaan_linear("x*y = 1").is_err());
+        assert_eq!(
+            persamaan_linear("x^2 + y = 3"),
+            Err("persamaan tidak linear".to_string())
+        );
+        assert_eq!(
+            persamaan_linear("x^0 = 1"),
+            Err("persamaan tidak linear".to_string())
+        );
+        assert!(persamaan_linear("1/x = 2").is_err());
+        assert!(persamaan_substitusi("1/x = 2").is_ok());
+    }
+
+    #[test]
+    fn sistem_menjadi_matriks_diperbesar() {
+        let sistem = sistem_linear(&baris(&["x - y = 1", "2y = 6 - x"])).unwrap();
+        assert_eq!(sistem.nama, ["x", "y"]);
+        assert_eq!(
+            sistem.matriks,
+            [[p(1, 1), p(-1, 1), p(1, 1)], [p(1, 1), p(2, 1), p(6, 1)]]
+        );
+        assert_eq!(sistem.persamaan(1, P), "x + 2y = 6");
+
+        assert_eq!(
+            sistem_linear(&baris(&["x = 1", "y = "]))
+                .err()
+                .map(|(i, _)| i),
+            Some(1)
+        );
+    }
+
+    #[test]
+    fn bentuk_baku_untuk_konfirmasi() {
+        let sistem = sistem_linear(&baris(&["2x + 3y = 7", "3y + 2 = x/2 + 4"])).unwrap();
+        assert_eq!(sistem.persamaan(0, P), "2x + 3y = 7");
+        assert_eq!(sistem.persamaan(1, P), "-(1/2)x + 3y = 2");
+    }
+
+    #[test]
+    fn aturan_perkalian_tanpa_tanda() {
+        // Nama bersambung adalah satu nama dan boleh diakhiri angka
+        let bentuk = persamaan_linear("3x2 + xy = 1").unwrap();
+        assert_eq!(bentuk.koefisien("x2"), p(3, 1));
+        assert_eq!(bentuk.koefisien("xy"), p(1, 1));
+
+        // Angka yang tidak didahului operator
+        assert_eq!(
+            hitung("2 3").map(|n| n.nilai),
+            Err("angka harus didahului operator, mis. 2*3 bukan 2 3".to_string())
+        );
+        assert!(persamaan_linear("x 2 = 1").is_err());
+        assert!(persamaan_linear("(x + 1)2 = 1").is_err());
+
+        // Pembagi yang langsung diikuti perkalian tanpa tanda
+        assert_eq!(
+            persamaan_linear("1/2x = 1"),
+            Err("ruas kiri: pembagian rancu, tulis (1/2)x atau 1/(2x)".to_string())
+        );
+        assert_eq!(
+            persamaan_linear("(1/2)x = 1").unwrap().koefisien("x"),
+            p(1, 2)
+        );
+        assert_eq!(
+            persamaan_linear("1/2*x = 1").unwrap().koefisien("x"),
+            p(1, 2)
+        );
+        assert!(persamaan_substitusi("1/(2x) = 1").is_ok());
+    }
+
+    #[test]
+    fn nama_rancu_dengan_perkalian_ditolak() {
+        // xy bersama x dan y bisa berarti x·y
+        assert_eq!(
+            sistem_linear(&baris(&["2x + 3y = 7", "xy = 1"])).err(),
+            Some((1, "'xy' rancu dengan x·y, beri nama lain".to_string()))
+        );
+        // Tanpa variabel x dan y, xy hanyalah nama
+        assert!(sistem_linear(&baris(&["xy + z = 7", "xy - z = 1"])).is_ok());
+        assert!(sistem_linear(&baris(&["harga + a = 7", "harga - a = 1"])).is_ok());
+    }
+
+    #[test]
+    fn sistem_substitusi_memisalkan_suku() {
+        let sistem = sistem_substitusi(&baris(&["1/x + 1/y = 5", "2/x - 1/y = 1"])).unwrap();
+        assert_eq!(sistem.nama, ["1/x", "1/y"]);
+        assert_eq!(sistem.matriks[1], [p(2, 1), p(-1, 1), p(1, 1)]);
+    }
+}
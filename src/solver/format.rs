@@ -0,0 +1,286 @@
+use crate::solver::matriks::GambarMatriks;
+use crate::solver::pecahan::{Hitung, Pecahan, Tampilan};
+
+/// Potongan isi langkah penyelesaian
+#[derive(Clone, Debug, PartialEq)]
+pub enum Blok {
+    Teks(String),
+    /// Digambar dengan widget matriks di layar hasil
+    Matriks(GambarMatriks),
+}
+
+/// Isi langkah untuk satu tampilan: potongan teks dan gambar matriks
+/// sesuai urutan penulisannya
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct Isi(Vec<Blok>);
+
+impl Isi {
+    pub fn blok(&self) -> &[Blok] {
+        &self.0
+    }
+
+    /// Potongan teksnya saja, tanpa gambar matriks
+    pub fn teks(&self) -> String {
+        self.0
+            .iter()
+            .filter_map(|b| match b {
+                Blok::Teks(t) => Some(t.as_str()),
+                Blok::Matriks(_) => None,
+            })
+            .collect()
+    }
+
+    /// Banyak baris layar (sebelum dilipat): baris teks ditambah tinggi
+    /// setiap gambar matriks
+    pub fn tinggi(&self) -> usize {
+        self.0
+            .iter()
+            .map(|b| match b {
+                Blok::Teks(t) => t.lines().count(),
+                Blok::Matriks(g) => g.sel.len(),
+            })
+            .sum()
+    }
+
+    pub fn push_str(&mut self, teks: &str) {
+        match self.0.last_mut() {
+            Some(Blok::Teks(t)) => t.push_str(teks),
+            _ if teks.is_empty() => {}
+            _ => self.0.push(Blok::Teks(teks.to_string())),
+        }
+    }
+
+    pub fn sambung(&mut self, lain: &Isi) {
+        for b in &lain.0 {
+            match b {
+                Blok::Teks(t) => self.push_str(t),
+                Blok::Matriks(_) => self.0.push(b.clone()),
+            }
+        }
+    }
+}
+
+/// Teks yang memuat angka (langkah penyelesaian atau bagian hasil) dalam
+/// tampilan pecahan dan desimal sekaligus, sehingga penyelesai cukup
+/// dijalankan sekali untuk kedua tampilan
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct Teks {
+    pub pecahan: Isi,
+    pub desimal: Isi,
+}
+
+impl Teks {
+    /// Teks yang potongan pertamanya dibuat oleh `f`
+    pub fn dari(f: impl Fn(Tampilan) -> String) -> Self {
+        let mut teks = Self::default();
+        teks.tulis(f);
+        teks
+    }
+
+    /// Menambahkan potongan yang memuat angka. `f` dijalankan sekali untuk
+    /// setiap tampilan dan menulis angkanya dengan tampilan tersebut.
+    pub fn tulis(&mut self, f: impl Fn(Tampilan) -> String) {
+        self.pecahan.push_str(&f(Tampilan::Pecahan));
+        self.desimal.push_str(&f(Tampilan::Desimal));
+    }
+
+    /// Seperti [`Teks::tulis`] untuk potongan yang memuat perhitungan
+    pub fn coba_tulis(&mut self, f: impl Fn(Tampilan) -> Hitung<String>) -> Hitung<()> {
+        let pecahan = f(Tampilan::Pecahan)?;
+        let desimal = f(Tampilan::Desimal)?;
+        self.pecahan.push_str(&pecahan);
+        self.desimal.push_str(&desimal);
+        Ok(())
+    }
+
+    /// Menambahkan gambar matriks; seperti [`Teks::tulis`], `f` dijalankan
+    /// sekali untuk setiap tampilan
+    pub fn gambar(&mut self, f: impl Fn(Tampilan) -> GambarMatriks) {
+        self.pecahan.0.push(Blok::Matriks(f(Tampilan::Pecahan)));
+        self.desimal.0.push(Blok::Matriks(f(Tampilan::Desimal)));
+    }
+
+    /// Seperti [`Teks::gambar`] untuk matriks yang isinya memuat perhitungan
+    pub fn coba_gambar(&mut self, f: impl Fn(Tampilan) -> Hitung<GambarMatriks>) -> Hitung<()> {
+        let pecahan = f(Tampilan::Pecahan)?;
+        let desimal = f(Tampilan::Desimal)?;
+        self.pecahan.0.push(Blok::Matriks(pecahan));
+        self.desimal.0.push(Blok::Matriks(desimal));
+        Ok(())
+    }
+
+    /// Menambahkan teks yang sama untuk kedua tampilan
+    pub fn push_str(&mut self, teks: &str) {
+        self.pecahan.push_str(teks);
+        self.desimal.push_str(teks);
+    }
+
+    pub fn push(&mut self, c: char) {
+        self.push_str(c.encode_utf8(&mut [0; 4]));
+    }
+
+    pub fn sambung(&mut self, lain: &Teks) {
+        self.pecahan.sambung(&lain.pecahan);
+        self.desimal.sambung(&lain.desimal);
+    }
+
+    pub fn isi(&self, tampilan: Tampilan) -> &Isi {
+        match tampilan {
+            Tampilan::Pecahan => &self.pecahan,
+            Tampilan::Desimal => &self.desimal,
+        }
+    }
+
+    /// Potongan teks versi tampilan `t`
+    pub fn tampil(&self, t: Tampilan) -> String {
+        self.isi(t).teks()
+    }
+}
+
+impl From<&str> for Teks {
+    fn from(teks: &str) -> Self {
+        let mut hasil = Self::default();
+        hasil.push_str(teks);
+        hasil
+    }
+}
+
+// Koefisien di depan variabel: 1 dan -1 tidak ditulis, pecahan diberi kurung
+pub fn koefisien(k: Pecahan, t: Tampilan) -> String {
+    if k.abs() == Pecahan::SATU {
+        String::new()
+    } else if !k.is_bulat() && t == Tampilan::Pecahan {
+        format!("({})", k.abs().tampil(t))
+    } else {
+        k.abs().tampil(t).to_string()
+    }
+}
+
+// Menulis kombinasi linear: konstanta + Σ koef·variabel (mis. "7 - 2t")
+pub fn kombinasi_linear(konstanta: Pecahan, suku: &[(Pecahan, &str)], t: Tampilan) -> String {
+    let mut hasil = String::new();
+    if !konstanta.is_nol() || suku.iter().all(|(k, _)| k.is_nol()) {
+        hasil.push_str(&konstanta.tampil(t).to_string());
+    }
+    for (k, var) in suku.iter().filter(|(k, _)| !k.is_nol()) {
+        if hasil.is_empty() {
+            hasil.push_str(if k.is_negatif() { "-" } else { "" });
+        } else {
+            hasil.push_str(if k.is_negatif() { " - " } else { " + " });
+        }
+        hasil.push_str(&format!("{}{}", koefisien(*k, t), var));
+    }
+    hasil
+}
+
+// Menulis (pembilang)/penyebut, tanpa kurung jika penyebutnya 1 atau -1
+pub fn bagi(
+    konstanta: Pecahan,
+    suku: &[(Pecahan, &str)],
+    penyebut: Pecahan,
+    t: Tampilan,
+) -> Hitung<String> {
+    Ok(if suku.iter().all(|(k, _)| k.is_nol()) {
+        konstanta.bagi(penyebut)?.tampil(t).to_string()
+    } else if penyebut == Pecahan::SATU {
+        kombinasi_linear(konstanta, suku, t)
+    } else if penyebut == -Pecahan::SATU {
+        let suku: Vec<(Pecahan, &str)> = suku.iter().map(|(k, v)| (-*k, *v)).collect();
+        kombinasi_linear(-konstanta, &suku, t)
+    } else {
+        format!(
+            "({})/{}",
+            kombinasi_linear(konstanta, suku, t),
+            penyebut.tampil(t)
+        )
+    })
+}
+
+// Menulis persamaan linear, mis. "2x - 3y = 7"
+pub fn persamaan(suku: &[(Pecahan, &str)], ruas_kanan: Pecahan, t: Tampilan) -> String {
+    hubungan(suku, "=", ruas_kanan, t)
+}
+
+// Menulis persamaan atau pertidaksamaan linear dengan tanda `tanda`, mis. "x + y ≤ 4"
+pub fn hubungan(suku: &[(Pecahan, &str)], tanda: &str, ruas_kanan: Pecahan, t: Tampilan) -> String {
+    let kiri = if suku.iter().all(|(k, _)| k.is_nol()) {
+        // Tetap tulis variabelnya agar bentuk persamaan terlihat
+        suku.iter()
+            .map(|(_, v)| format!("0{}", v))
+            .collect::<Vec<_>>()
+            .join(" + ")
+    } else {
+        kombinasi_linear(Pecahan::NOL, suku, t)
+    };
+    format!("{} {} {}", kiri, tanda, ruas_kanan.tampil(t))
+}
+
+// Angka di dalam operasi pada langkah, diberi kurung jika negatif atau pecahan
+pub fn kurung(k: Pecahan, t: Tampilan) -> String {
+    if k.is_negatif() || (!k.is_bulat() && t == Tampilan::Pecahan) {
+        format!("({})", k.tampil(t))
+    } else {
+        k.tampil(t).to_string()
+    }
+}
+
+// Nilai akhir: pada tampilan pecahan, nilai desimalnya ditulis di samping
+pub fn nilai_akhir(k: Pecahan, t: Tampilan) -> String {
+    if t == Tampilan::Pecahan && !k.is_bulat() {
+        format!("{} ≈ {}", k.pecahan(), k.desimal())
+    } else {
+        k.tampil(t).to_string()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::solver::pecahan::uji::p;
+
+    const P: Tampilan = Tampilan::Pecahan;
+
+    #[test]
+    fn kombinasi_linear_tanpa_koefisien_satu() {
+        assert_eq!(kombinasi_linear(p(7, 1), &[(p(-2, 1), "t")], P), "7 - 2t");
+        assert_eq!(
+            kombinasi_linear(Pecahan::NOL, &[(p(-1, 1), "x"), (p(1, 2), "y")], P),
+            "-x + (1/2)y"
+        );
+        assert_eq!(
+            kombinasi_linear(Pecahan::NOL, &[(Pecahan::NOL, "x")], P),
+            "0"
+        );
+    }
+
+    #[test]
+    fn persamaan_dan_pembagian() {
+        assert_eq!(
+            persamaan(&[(p(2, 1), "x"), (p(-3, 1), "y")], p(7, 1), P),
+            "2x - 3y = 7"
+        );
+        assert_eq!(
+            persamaan(&[(Pecahan::NOL, "x"), (Pecahan::NOL, "y")], p(1, 1), P),
+            "0x + 0y = 1"
+        );
+        assert_eq!(
+            bagi(p(3, 1), &[(p(1, 1), "t")], p(2, 1), P),
+            Ok("(3 + t)/2".into())
+        );
+        assert_eq!(
+            bagi(p(3, 1), &[(p(1, 1), "t")], -Pecahan::SATU, P),
+            Ok("-3 - t".into())
+        );
+        assert_eq!(bagi(p(3, 1), &[], p(2, 1), P), Ok("3/2".into()));
+    }
+
+    #[test]
+    fn kurung_dan_nilai_akhir_mengikuti_tampilan() {
+        assert_eq!(kurung(p(-2, 1), P), "(-2)");
+        assert_eq!(kurung(p(1, 3), P), "(1/3)");
+        assert_eq!(nilai_akhir(p(1, 3), P), "1/3 ≈ 0.3333");
+        assert_eq!(nilai_akhir(p(4, 1), P), "4");
+        assert_eq!(kurung(p(1, 4), Tampilan::Desimal), "0.25");
+        assert_eq!(nilai_akhir(p(1, 3), Tampilan::Desimal), "0.3333");
+    }
+}
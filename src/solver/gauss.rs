@@ -0,0 +1,292 @@
+use crate::solver::format::{Teks, kombinasi_linear, kurung};
+use crate::solver::matriks::{GambarMatriks, diperbesar};
+use crate::solver::pecahan::{Hitung, Pecahan, Tampilan};
+
+#[derive(Clone, Copy, PartialEq)]
+pub enum JenisSolusi {
+    Tunggal,
+    TidakAda,
+    TakHingga,
+}
+
+/// Solusi umum sistem bergantung dalam satu baris, mis.
+/// `x1 = 1 + t, x2 = t  (t bilangan real)`; `None` untuk sistem lainnya
+pub type SolusiUmum = Option<Teks>;
+
+/// Jenis sistem, penyelesaian tunggal `T` jika ada, solusi umum, dan langkah
+pub type Penyelesaian<T> = (JenisSolusi, Option<T>, SolusiUmum, Teks);
+
+//
+// Sistem Persamaan Linear m persamaan, n variabel
+// Matriks diperbesar [A | b] dengan ukuran m × (n + 1)
+// Eliminasi Gauss dengan pivot parsial, lalu substitusi balik. Untuk sistem
+// bergantung, dikembalikan juga solusi umumnya dalam satu baris.
+//
+pub fn gauss_proses(m: Vec<Vec<Pecahan>>, nama: &[String]) -> Hitung<Penyelesaian<Vec<Pecahan>>> {
+    gauss_proses_berjudul(m, nama, "Matriks diperbesar [A | b]")
+}
+
+/// Seperti [`gauss_proses`], dengan judul matriks awal sendiri, mis. untuk
+/// persamaan normal [AᵀA | Aᵀb]
+pub fn gauss_proses_berjudul(
+    mut m: Vec<Vec<Pecahan>>,
+    nama: &[String],
+    judul: &str,
+) -> Hitung<Penyelesaian<Vec<Pecahan>>> {
+    let baris = m.len();
+    let n = nama.len();
+    let mut steps = Teks::dari(|_| format!("{}:\n", judul));
+    steps.gambar(|t| tulis_matriks(&m, t));
+
+    // Eliminasi maju: setiap kolom mencari pivot dengan nilai mutlak terbesar
+    let mut pivot_kolom = Vec::new();
+    let mut r = 0;
+    for c in 0..n {
+        if r >= baris {
+            break;
+        }
+        let p = (r..baris).max_by_key(|&i| m[i][c].abs()).unwrap_or(r);
+        if m[p][c].is_nol() {
+            steps.tulis(|_| {
+                format!(
+                    "\nKolom {} tidak memiliki pivot (semua nol), lanjut ke kolom berikutnya.\n",
+                    nama[c]
+                )
+            });
+            continue;
+        }
+
+        steps.tulis(|_| format!("\nKolom {}:\n", nama[c]));
+        let mut berubah = p != r;
+        if p != r {
+            m.swap(p, r);
+            steps.tulis(|t| {
+                format!(
+                    "  B{} ↔ B{} (pivot terbesar {})\n",
+                    r + 1,
+                    p + 1,
+                    m[r][c].tampil(t)
+                )
+            });
+        }
+        for i in r + 1..baris {
+            let faktor = m[i][c].bagi(m[r][c])?;
+            if faktor.is_nol() {
+                continue;
+            }
+            let (atas, bawah) = m.split_at_mut(i);
+            for (v, p) in bawah[0][c..].iter_mut().zip(&atas[r][c..]) {
+                *v = v.kurang(faktor.kali(*p)?)?;
+            }
+            steps.tulis(|t| {
+                format!(
+                    "  B{} ← B{} − {}·B{}\n",
+                    i + 1,
+                    i + 1,
+                    kurung(faktor, t),
+                    r + 1
+                )
+            });
+            berubah = true;
+        }
+        if berubah {
+            steps.gambar(|t| tulis_matriks(&m, t));
+        } else {
+            steps.push_str("  Tidak perlu operasi baris.\n");
+        }
+        pivot_kolom.push(c);
+        r += 1;
+    }
+
+    let rank = pivot_kolom.len();
+    steps.tulis(|_| format!("\nRank matriks koefisien = {}\n", rank));
+
+    // Baris nol di kiri tetapi tidak nol di kanan → sistem tidak konsisten
+    if let Some(i) = (rank..baris).find(|&i| !m[i][n].is_nol()) {
+        steps.tulis(|t| {
+            format!(
+                "B{} menjadi 0 = {} → sistem tidak konsisten.\n",
+                i + 1,
+                m[i][n].tampil(t)
+            )
+        });
+        return Ok((JenisSolusi::TidakAda, None, None, steps));
+    }
+
+    if rank < n {
+        let bebas: Vec<usize> = (0..n).filter(|c| !pivot_kolom.contains(c)).collect();
+        let nama_bebas: Vec<&str> = bebas.iter().map(|&c| nama[c].as_str()).collect();
+        steps.tulis(|_| {
+            format!(
+                "Rank < jumlah variabel → tak hingga banyak solusi (variabel bebas: {}).\n",
+                nama_bebas.join(", ")
+            )
+        });
+        let (langkah, umum) = solusi_umum(&mut m, &pivot_kolom, &bebas, nama)?;
+        steps.sambung(&langkah);
+        return Ok((JenisSolusi::TakHingga, None, Some(umum), steps));
+    }
+
+    steps.push_str("\nSubstitusi balik:\n");
+    let mut x = vec![Pecahan::NOL; n];
+    for i in (0..n).rev() {
+        let jumlah = (i + 1..n)
+            .map(|j| m[i][j].kali(x[j]))
+            .try_fold(Pecahan::NOL, |s, v| s.tambah(v?))?;
+        x[i] = m[i][n].kurang(jumlah)?.bagi(m[i][i])?;
+        if i + 1 < n {
+            steps.tulis(|t| {
+                format!(
+                    "  {} = ({} − {}) / {} = {}\n",
+                    nama[i],
+                    m[i][n].tampil(t),
+                    kurung(jumlah, t),
+                    kurung(m[i][i], t),
+                    x[i].tampil(t)
+                )
+            });
+        } else {
+            steps.tulis(|t| {
+                format!(
+                    "  {} = {} / {} = {}\n",
+                    nama[i],
+                    m[i][n].tampil(t),
+                    kurung(m[i][i], t),
+                    x[i].tampil(t)
+                )
+            });
+        }
+    }
+
+    Ok((JenisSolusi::Tunggal, Some(x), None, steps))
+}
+
+// Mereduksi ke bentuk eselon baris tereduksi lalu menulis solusi umum
+// dengan parameter t1, t2, ... untuk setiap variabel bebas. Selain langkahnya,
+// dikembalikan solusi umum dalam satu baris untuk hasil akhir.
+fn solusi_umum(
+    m: &mut [Vec<Pecahan>],
+    pivot_kolom: &[usize],
+    bebas: &[usize],
+    nama: &[String],
+) -> Hitung<(Teks, Teks)> {
+    let n = nama.len();
+    for (r, &c) in pivot_kolom.iter().enumerate().rev() {
+        let pivot = m[r][c];
+        for v in m[r][c..].iter_mut() {
+            *v = v.bagi(pivot)?;
+        }
+        for i in 0..r {
+            let faktor = m[i][c];
+            let (atas, bawah) = m.split_at_mut(r);
+            for (v, p) in atas[i][c..].iter_mut().zip(&bawah[0][c..]) {
+                *v = v.kurang(faktor.kali(*p)?)?;
+            }
+        }
+    }
+
+    let mut steps = Teks::from("\nBentuk eselon baris tereduksi:\n");
+    steps.gambar(|t| tulis_matriks(m, t));
+
+    let parameter: Vec<String> = if bebas.len() == 1 {
+        vec!["t".to_string()]
+    } else {
+        (1..=bebas.len()).map(|i| format!("t{}", i)).collect()
+    };
+    let nilai = |t| -> Vec<String> {
+        nama.iter()
+            .enumerate()
+            .filter_map(|(c, nama_c)| {
+                if let Some(j) = bebas.iter().position(|&b| b == c) {
+                    return Some(format!("{} = {}", nama_c, parameter[j]));
+                }
+                let r = pivot_kolom.iter().position(|&p| p == c)?;
+                let suku: Vec<(Pecahan, &str)> = bebas
+                    .iter()
+                    .zip(&parameter)
+                    .map(|(&b, p)| (-m[r][b], p.as_str()))
+                    .collect();
+                Some(format!(
+                    "{} = {}",
+                    nama_c,
+                    kombinasi_linear(m[r][n], &suku, t)
+                ))
+            })
+            .collect()
+    };
+    steps.tulis(|_| format!("\nSolusi umum ({} bilangan real):\n", parameter.join(", ")));
+    steps.tulis(|t| nilai(t).iter().map(|v| format!("  {}\n", v)).collect());
+    let umum = Teks::dari(|t| {
+        format!(
+            "{}  ({} bilangan real)",
+            nilai(t).join(", "),
+            parameter.join(", ")
+        )
+    });
+    Ok((steps, umum))
+}
+
+// Gambar matriks diperbesar dengan pemisah ruas kanan
+pub fn tulis_matriks(m: &[Vec<Pecahan>], t: Tampilan) -> GambarMatriks {
+    diperbesar(m, t)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::solver::pecahan::uji::{matriks, nama};
+
+    #[test]
+    fn penyelesaian_tunggal() {
+        // x1 + x2 + x3 = 6, 2x1 − x2 + x3 = 3, x1 + 2x2 − x3 = 2
+        let m = matriks(&[&[1, 1, 1, 6], &[2, -1, 1, 3], &[1, 2, -1, 2]]);
+        let (jenis, x, _, _) = gauss_proses(m, &nama(3)).unwrap();
+        assert!(jenis == JenisSolusi::Tunggal);
+        assert_eq!(
+            x,
+            Some(vec![Pecahan::from(1), Pecahan::from(2), Pecahan::from(3)])
+        );
+    }
+
+    #[test]
+    fn penyelesaian_pecahan_dengan_pertukaran_baris() {
+        // Pivot pertama nol sehingga baris harus ditukar
+        let m = matriks(&[&[0, 2, 1], &[3, 1, 2]]);
+        let (jenis, x, _, langkah) = gauss_proses(m, &nama(2)).unwrap();
+        assert!(jenis == JenisSolusi::Tunggal);
+        assert_eq!(x, Some(vec![Pecahan::new(1, 2), Pecahan::new(1, 2)]));
+        assert!(langkah.pecahan.teks().contains("B1 ↔ B2"));
+    }
+
+    #[test]
+    fn tidak_ada_penyelesaian() {
+        let m = matriks(&[&[1, 1, 2], &[2, 2, 5]]);
+        let (jenis, x, umum, _) = gauss_proses(m, &nama(2)).unwrap();
+        assert!(jenis == JenisSolusi::TidakAda);
+        assert_eq!(x, None);
+        assert_eq!(umum, None);
+    }
+
+    #[test]
+    fn tak_hingga_penyelesaian() {
+        let m = matriks(&[&[1, -1, 1], &[-2, 2, -2]]);
+        let (jenis, x, umum, _) = gauss_proses(m, &nama(2)).unwrap();
+        assert!(jenis == JenisSolusi::TakHingga);
+        assert_eq!(x, None);
+        assert_eq!(
+            umum.map(|u| u.pecahan.teks()),
+            Some("x1 = 1 + t, x2 = t  (t bilangan real)".to_string())
+        );
+
+        // Lebih sedikit persamaan daripada variabel
+        let m = matriks(&[&[1, 1, 1, 3], &[0, 1, 1, 2]]);
+        assert!(gauss_proses(m, &nama(3)).unwrap().0 == JenisSolusi::TakHingga);
+    }
+
+    #[test]
+    fn judul_matriks_awal() {
+        let m = matriks(&[&[1, 2]]);
+        let (_, _, _, langkah) = gauss_proses_berjudul(m, &nama(1), "Persamaan normal").unwrap();
+        assert!(langkah.pecahan.teks().starts_with("Persamaan normal:\n"));
+    }
+}
@@ -0,0 +1,452 @@
+use crate::solver::ekspresi::hitung;
+use crate::solver::format::{Teks, kurung};
+use crate::solver::pecahan::{Hitung, Pecahan, Tampilan, desimal};
+
+/// Metode iterasi untuk sistem persamaan linear n × n
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum MetodeIteratif {
+    Jacobi,
+    GaussSeidel,
+    Sor,
+}
+
+impl MetodeIteratif {
+    pub const SEMUA: [MetodeIteratif; 3] = [
+        MetodeIteratif::Jacobi,
+        MetodeIteratif::GaussSeidel,
+        MetodeIteratif::Sor,
+    ];
+
+    pub fn nama(self) -> &'static str {
+        match self {
+            MetodeIteratif::Jacobi => "Jacobi",
+            MetodeIteratif::GaussSeidel => "Gauss-Seidel",
+            MetodeIteratif::Sor => "SOR",
+        }
+    }
+
+    /// Metode berikutnya, atau sebelumnya jika `mundur`
+    pub fn geser(self, mundur: bool) -> MetodeIteratif {
+        let n = Self::SEMUA.len();
+        let i = Self::SEMUA.iter().position(|&m| m == self).unwrap_or(0);
+        Self::SEMUA[if mundur { (i + n - 1) % n } else { (i + 1) % n }]
+    }
+}
+
+/// Label isian parameter iterasi, sesuai urutan `ParameterIteratif::baca`
+pub const LABEL_PARAMETER: [&str; 4] = ["x⁽⁰⁾", "Toleransi", "Maks iterasi", "ω (SOR)"];
+
+/// Isian awal parameter iterasi
+pub fn parameter_awal() -> [String; 4] {
+    ["0", "0.0001", "25", "1.25"].map(String::from)
+}
+
+/// Banyak iterasi maksimum yang boleh diminta
+pub const MAKS_ITERASI: usize = 500;
+
+/// Tebakan awal, toleransi galat, batas iterasi, dan faktor relaksasi ω
+pub struct ParameterIteratif {
+    pub awal: Vec<f64>,
+    pub toleransi: f64,
+    pub maks: usize,
+    pub omega: f64,
+}
+
+impl ParameterIteratif {
+    /// Membaca isian parameter untuk sistem `n` variabel. Tebakan awal
+    /// berupa satu nilai untuk semua variabel atau n nilai dipisah `;`.
+    /// Mengembalikan indeks isian pertama yang tidak valid beserta pesannya.
+    pub fn baca(
+        isian: &[String; 4],
+        n: usize,
+        metode: MetodeIteratif,
+    ) -> Result<Self, (usize, String)> {
+        let angka =
+            |i: usize, teks: &str| hitung(teks).map(|v| v.nilai.ke_f64()).map_err(|e| (i, e));
+        let awal = isian[0]
+            .split(';')
+            .map(|s| angka(0, s))
+            .collect::<Result<Vec<f64>, _>>()?;
+        let awal = match awal.len() {
+            1 => vec![awal[0]; n],
+            k if k == n => awal,
+            k => {
+                return Err((0, format!("Perlu 1 atau {} nilai, bukan {}", n, k)));
+            }
+        };
+        let toleransi = angka(1, &isian[1])?;
+        if toleransi <= 0.0 {
+            return Err((1, "Toleransi harus positif".to_string()));
+        }
+        let maks = angka(2, &isian[2])?;
+        if maks.fract() != 0.0 || !(1.0..=MAKS_ITERASI as f64).contains(&maks) {
+            return Err((2, format!("Bilangan bulat 1 sampai {}", MAKS_ITERASI)));
+        }
+        // ω hanya dibaca untuk SOR; Jacobi dan Gauss-Seidel setara dengan ω = 1
+        let omega = if metode == MetodeIteratif::Sor {
+            let omega = angka(3, &isian[3])?;
+            if !(omega > 0.0 && omega < 2.0) {
+                return Err((3, "SOR memerlukan 0 < ω < 2".to_string()));
+            }
+            omega
+        } else {
+            1.0
+        };
+        Ok(Self {
+            awal,
+            toleransi,
+            maks: maks as usize,
+            omega,
+        })
+    }
+}
+
+/// Akhir proses iterasi
+#[derive(Clone, Copy, PartialEq)]
+pub enum Konvergensi {
+    /// Galat lebih kecil dari toleransi pada iterasi ke-k
+    Konvergen(usize),
+    /// Batas iterasi tercapai sebelum galat cukup kecil
+    BelumKonvergen,
+    /// Nilai membesar tanpa batas pada iterasi ke-k
+    Divergen(usize),
+    /// Ada a_ii = 0 sehingga rumus iterasi tidak dapat dibentuk
+    DiagonalNol(usize),
+}
+
+// Batas nilai mutlak sebelum iterasi dianggap divergen
+const BATAS_DIVERGEN: f64 = 1e12;
+
+/// Menulis hampiran dengan enam angka di belakang koma
+pub fn hampiran(v: f64) -> String {
+    let s = format!("{:.6}", v);
+    if s.trim_start_matches('-')
+        .chars()
+        .all(|c| c == '0' || c == '.')
+    {
+        "0.000000".to_string()
+    } else {
+        s
+    }
+}
+
+fn galat(v: f64) -> String {
+    format!("{:.2e}", v)
+}
+
+// Tabel iterasi dengan kolom rata kanan
+fn tabel(judul: &[String], isi: &[Vec<String>]) -> String {
+    let lebar: Vec<usize> = (0..judul.len())
+        .map(|j| {
+            isi.iter()
+                .map(|b| b[j].chars().count())
+                .chain(std::iter::once(judul[j].chars().count()))
+                .max()
+                .unwrap_or(1)
+        })
+        .collect();
+    let baris = |sel: &[String]| {
+        let kolom: Vec<String> = sel
+            .iter()
+            .zip(&lebar)
+            .map(|(s, &w)| format!(" {:>w$} ", s))
+            .collect();
+        format!("  {}\n", kolom.join("│"))
+    };
+    let mut teks = baris(judul);
+    teks.push_str(&format!(
+        "  {}\n",
+        lebar
+            .iter()
+            .map(|w| "─".repeat(w + 2))
+            .collect::<Vec<_>>()
+            .join("┼")
+    ));
+    for b in isi {
+        teks.push_str(&baris(b));
+    }
+    teks
+}
+
+// Rumus iterasi baris ke-i, mis. "x1 = (7 − (-1)·x2 − 2·x3) / 4"
+fn rumus(a: &[Vec<Pecahan>], i: usize, nama: &[String], pangkat: &[&str], t: Tampilan) -> String {
+    let n = nama.len();
+    let mut teks = format!("{}", a[i][n].tampil(t));
+    for j in (0..n).filter(|&j| j != i) {
+        teks.push_str(&format!(
+            " − {}·{}{}",
+            kurung(a[i][j], t),
+            nama[j],
+            pangkat[j]
+        ));
+    }
+    format!("{}⁽ᵏ⁺¹⁾ = ({}) / {}", nama[i], teks, kurung(a[i][i], t))
+}
+
+//
+// Metode Iterasi Jacobi, Gauss-Seidel, dan SOR untuk Ax = b
+// Setiap persamaan ke-i diselesaikan untuk x_i:
+//   x_i⁽ᵏ⁺¹⁾ = (b_i − Σ_{j≠i} a_ij·x_j) / a_ii
+// Jacobi memakai nilai x_j dari iterasi sebelumnya, Gauss-Seidel langsung
+// memakai nilai x_j yang baru, dan SOR mencampur hasil Gauss-Seidel dengan
+// nilai lama: x_i ← (1 − ω)·x_i + ω·x_i(GS). Iterasi berhenti ketika
+// galat ‖x⁽ᵏ⁺¹⁾ − x⁽ᵏ⁾‖∞ < toleransi, batas iterasi tercapai, atau nilainya
+// membesar tanpa batas (divergen).
+//
+pub fn iteratif_proses(
+    m: &[Vec<Pecahan>],
+    nama: &[String],
+    metode: MetodeIteratif,
+    parameter: &ParameterIteratif,
+) -> Hitung<(Konvergensi, Vec<f64>, Teks)> {
+    let n = nama.len();
+    let mut steps = Teks::dari(|_| format!("Metode: {}\n", metode.nama()));
+
+    // Dominan diagonal: |a_ii| > Σ_{j≠i} |a_ij| untuk setiap baris
+    steps.push_str("\nPemeriksaan dominan diagonal |aᵢᵢ| > Σⱼ≠ᵢ |aᵢⱼ|:\n");
+    let mut tegas = true;
+    for (i, baris) in m.iter().enumerate() {
+        let diagonal = baris[i].abs();
+        let lain = Pecahan::jumlah((0..n).filter(|&j| j != i).map(|j| baris[j].abs()))?;
+        let tanda = if diagonal > lain {
+            ">"
+        } else {
+            tegas = false;
+            if diagonal == lain { "=" } else { "<" }
+        };
+        steps.tulis(|t| {
+            format!(
+                "  Baris {}: |{}| = {} {} {}\n",
+                i + 1,
+                baris[i].tampil(t),
+                diagonal.tampil(t),
+                tanda,
+                lain.tampil(t)
+            )
+        });
+    }
+    steps.push_str(if tegas {
+        "  Matriks dominan diagonal tegas → Jacobi dan Gauss-Seidel pasti konvergen.\n"
+    } else {
+        "  Matriks tidak dominan diagonal tegas → konvergensi tidak dijamin.\n"
+    });
+
+    if let Some(i) = (0..n).find(|&i| m[i][i].is_nol()) {
+        steps.tulis(|_| {
+            format!(
+                "\na{}{} = 0, sehingga persamaan ke-{} tidak dapat diselesaikan untuk {}.\n\
+             Tukar urutan persamaan agar semua unsur diagonal tidak nol.\n",
+                i + 1,
+                i + 1,
+                i + 1,
+                nama[i]
+            )
+        });
+        return Ok((Konvergensi::DiagonalNol(i), parameter.awal.clone(), steps));
+    }
+
+    // Rumus iterasi: Jacobi selalu memakai x⁽ᵏ⁾, Gauss-Seidel dan SOR
+    // memakai x⁽ᵏ⁺¹⁾ untuk variabel yang sudah dihitung
+    steps.push_str("\nRumus iterasi:\n");
+    for i in 0..n {
+        let pangkat: Vec<&str> = (0..n)
+            .map(|j| {
+                if metode != MetodeIteratif::Jacobi && j < i {
+                    "⁽ᵏ⁺¹⁾"
+                } else {
+                    "⁽ᵏ⁾"
+                }
+            })
+            .collect();
+        steps.tulis(|t| format!("  {}\n", rumus(m, i, nama, &pangkat, t)));
+    }
+    if metode == MetodeIteratif::Sor {
+        steps.tulis(|_| {
+            format!(
+                "  Lalu dengan ω = {}: xᵢ⁽ᵏ⁺¹⁾ ← (1 − ω)·xᵢ⁽ᵏ⁾ + ω·xᵢ⁽ᵏ⁺¹⁾\n",
+                desimal(parameter.omega)
+            )
+        });
+    }
+
+    let a: Vec<Vec<f64>> = m
+        .iter()
+        .map(|b| b.iter().map(|v| v.ke_f64()).collect())
+        .collect();
+    let mut x = parameter.awal.clone();
+    let mut judul = vec!["k".to_string()];
+    judul.extend(nama.iter().map(|v| format!("{}⁽ᵏ⁾", v)));
+    judul.push("Galat".to_string());
+    let mut isi = vec![
+        std::iter::once("0".to_string())
+            .chain(x.iter().map(|v| hampiran(*v)))
+            .chain(std::iter::once("-".to_string()))
+            .collect::<Vec<_>>(),
+    ];
+
+    let mut hasil = Konvergensi::BelumKonvergen;
+    for k in 1..=parameter.maks {
+        let lama = x.clone();
+        for i in 0..n {
+            let sumber = if metode == MetodeIteratif::Jacobi {
+                &lama
+            } else {
+                &x
+            };
+            let jumlah: f64 = (0..n)
+                .filter(|&j| j != i)
+                .map(|j| a[i][j] * sumber[j])
+                .sum();
+            let baru = (a[i][n] - jumlah) / a[i][i];
+            x[i] = if metode == MetodeIteratif::Sor {
+                (1.0 - parameter.omega) * lama[i] + parameter.omega * baru
+            } else {
+                baru
+            };
+        }
+        let e = x
+            .iter()
+            .zip(&lama)
+            .map(|(p, q)| (p - q).abs())
+            .fold(0.0, f64::max);
+        if !e.is_finite() || x.iter().any(|v| !v.is_finite() || v.abs() > BATAS_DIVERGEN) {
+            hasil = Konvergensi::Divergen(k);
+            break;
+        }
+        isi.push(
+            std::iter::once(k.to_string())
+                .chain(x.iter().map(|v| hampiran(*v)))
+                .chain(std::iter::once(galat(e)))
+                .collect(),
+        );
+        if e < parameter.toleransi {
+            hasil = Konvergensi::Konvergen(k);
+            break;
+        }
+    }
+
+    steps.tulis(|_| {
+        format!(
+            "\nTabel iterasi (galat = maks |xᵢ⁽ᵏ⁾ − xᵢ⁽ᵏ⁻¹⁾|, toleransi {}):\n",
+            galat(parameter.toleransi)
+        )
+    });
+    steps.tulis(|_| tabel(&judul, &isi));
+
+    match hasil {
+        Konvergensi::Konvergen(k) => {
+            let sisa = a
+                .iter()
+                .map(|b| {
+                    let ax: f64 = (0..n).map(|j| b[j] * x[j]).sum();
+                    (ax - b[n]).abs()
+                })
+                .fold(0.0, f64::max);
+            steps.tulis(|_| {
+                format!(
+                    "\nGalat < toleransi pada iterasi ke-{} → konvergen.\n\
+                 Pemeriksaan: maks |Ax − b| = {}\n",
+                    k,
+                    galat(sisa)
+                )
+            });
+        }
+        Konvergensi::Divergen(k) => steps.tulis(|_| {
+            format!(
+                "\nPada iterasi ke-{} nilai x membesar tanpa batas (melebihi {}) → divergen.\n\
+             Susun ulang persamaan agar matriks dominan diagonal{}.\n",
+                k,
+                galat(BATAS_DIVERGEN),
+                if metode == MetodeIteratif::Sor {
+                    ", atau pilih ω yang lebih kecil"
+                } else {
+                    ""
+                }
+            )
+        }),
+        _ => {
+            // Galat yang terus membesar menandakan iterasi menjauhi penyelesaian
+            let naik = isi.len() > 3
+                && isi[isi.len() - 3..]
+                    .windows(2)
+                    .all(|w| w[1][n + 1].parse::<f64>().ok() > w[0][n + 1].parse::<f64>().ok());
+            steps.tulis(|_| {
+                format!(
+                    "\nBatas {} iterasi tercapai sebelum galat < toleransi{}.\n",
+                    parameter.maks,
+                    if naik {
+                        "; galat terus membesar sehingga iterasi kemungkinan divergen"
+                    } else {
+                        ""
+                    }
+                )
+            });
+        }
+    }
+    Ok((hasil, x, steps))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::solver::pecahan::uji::{matriks, nama};
+
+    fn parameter(isian: [&str; 4], n: usize) -> Result<ParameterIteratif, (usize, String)> {
+        ParameterIteratif::baca(&isian.map(String::from), n, MetodeIteratif::Sor)
+    }
+
+    #[test]
+    fn parameter_dibaca() {
+        let p = ParameterIteratif::baca(&parameter_awal(), 3, MetodeIteratif::Jacobi).unwrap();
+        assert_eq!(p.awal, [0.0; 3]);
+        assert_eq!((p.toleransi, p.maks, p.omega), (0.0001, 25, 1.0));
+
+        let p = parameter(["1; 2", "1/1000", "10", "1.5"], 2).unwrap();
+        assert_eq!(p.awal, [1.0, 2.0]);
+        assert_eq!((p.toleransi, p.maks, p.omega), (0.001, 10, 1.5));
+
+        let galat = |isian, n| parameter(isian, n).err().map(|(i, _)| i);
+        assert_eq!(galat(["1; 2", "0.1", "10", "1"], 3), Some(0));
+        assert_eq!(galat(["0", "0", "10", "1"], 2), Some(1));
+        assert_eq!(galat(["0", "0.1", "2.5", "1"], 2), Some(2));
+        assert_eq!(galat(["0", "0.1", "10", "2"], 2), Some(3));
+    }
+
+    #[test]
+    fn iterasi_konvergen() {
+        // 4x₁ + x₂ = 5, x₁ + 3x₂ = 4 → x = (1, 1); dominan diagonal
+        let m = matriks(&[&[4, 1, 5], &[1, 3, 4]]);
+        for metode in MetodeIteratif::SEMUA {
+            let p = ParameterIteratif::baca(&parameter_awal(), 2, metode).unwrap();
+            let (konvergensi, x, _) = iteratif_proses(&m, &nama(2), metode, &p).unwrap();
+            assert!(
+                matches!(konvergensi, Konvergensi::Konvergen(_)),
+                "{:?}",
+                metode
+            );
+            assert!(x.iter().all(|v| (v - 1.0).abs() < 1e-3), "{:?}", metode);
+        }
+    }
+
+    #[test]
+    fn iterasi_gagal() {
+        let p = ParameterIteratif::baca(&parameter_awal(), 2, MetodeIteratif::Jacobi).unwrap();
+        let m = matriks(&[&[0, 1, 1], &[1, 0, 1]]);
+        let (konvergensi, _, _) =
+            iteratif_proses(&m, &nama(2), MetodeIteratif::Jacobi, &p).unwrap();
+        assert!(konvergensi == Konvergensi::DiagonalNol(0));
+
+        // x₁ + 3x₂ = 4, 2x₁ + x₂ = 3: jauh dari dominan diagonal
+        let m = matriks(&[&[1, 3, 4], &[2, 1, 3]]);
+        let (konvergensi, _, _) =
+            iteratif_proses(&m, &nama(2), MetodeIteratif::Jacobi, &p).unwrap();
+        assert!(!matches!(konvergensi, Konvergensi::Konvergen(_)));
+    }
+
+    #[test]
+    fn hampiran_enam_desimal() {
+        assert_eq!(hampiran(1.0 / 3.0), "0.333333");
+        assert_eq!(hampiran(-0.0000001), "0.000000");
+        assert_eq!(hampiran(-2.5), "-2.500000");
+    }
+}
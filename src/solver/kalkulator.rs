@@ -0,0 +1,437 @@
+use crate::solver::format::{Teks, kurung};
+use crate::solver::matriks::{Kurung, determinan, langkah_determinan, terpisah, tulis, tulis_sel};
+use crate::solver::pecahan::{Hitung, Pecahan};
+
+/// Operasi yang bisa dipilih di kalkulator matriks
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum OperasiMatriks {
+    Jumlah,
+    Kali,
+    Skalar,
+    Transpos,
+    Invers,
+    Determinan,
+    Rank,
+    Rref,
+}
+
+impl OperasiMatriks {
+    pub const SEMUA: [OperasiMatriks; 8] = [
+        OperasiMatriks::Jumlah,
+        OperasiMatriks::Kali,
+        OperasiMatriks::Skalar,
+        OperasiMatriks::Transpos,
+        OperasiMatriks::Invers,
+        OperasiMatriks::Determinan,
+        OperasiMatriks::Rank,
+        OperasiMatriks::Rref,
+    ];
+
+    pub fn nama(self) -> &'static str {
+        match self {
+            OperasiMatriks::Jumlah => "A + B",
+            OperasiMatriks::Kali => "A · B",
+            OperasiMatriks::Skalar => "kA",
+            OperasiMatriks::Transpos => "Aᵀ",
+            OperasiMatriks::Invers => "A⁻¹",
+            OperasiMatriks::Determinan => "det(A)",
+            OperasiMatriks::Rank => "rank(A)",
+            OperasiMatriks::Rref => "RREF(A)",
+        }
+    }
+
+    /// Operasi berikutnya, atau sebelumnya jika `mundur`
+    pub fn geser(self, mundur: bool) -> OperasiMatriks {
+        let n = Self::SEMUA.len();
+        let i = Self::SEMUA.iter().position(|&o| o == self).unwrap_or(0);
+        Self::SEMUA[if mundur { (i + n - 1) % n } else { (i + 1) % n }]
+    }
+
+    pub fn pakai_b(self) -> bool {
+        matches!(self, OperasiMatriks::Jumlah | OperasiMatriks::Kali)
+    }
+
+    pub fn pakai_k(self) -> bool {
+        self == OperasiMatriks::Skalar
+    }
+
+    /// Memeriksa ukuran A dan B (baris, kolom) untuk operasi ini
+    pub fn periksa(self, a: (usize, usize), b: (usize, usize)) -> Result<(), String> {
+        match self {
+            OperasiMatriks::Jumlah if a != b => Err(format!(
+                "Ukuran A ({}×{}) dan B ({}×{}) harus sama untuk A + B",
+                a.0, a.1, b.0, b.1
+            )),
+            OperasiMatriks::Kali if a.1 != b.0 => Err(format!(
+                "Banyak kolom A ({}) harus sama dengan banyak baris B ({}) untuk A · B",
+                a.1, b.0
+            )),
+            OperasiMatriks::Invers | OperasiMatriks::Determinan if a.0 != a.1 => Err(format!(
+                "A ({}×{}) harus persegi untuk {}",
+                a.0,
+                a.1,
+                self.nama()
+            )),
+            _ => Ok(()),
+        }
+    }
+}
+
+/// Hasil kalkulator matriks
+pub enum HasilMatriks {
+    Matriks(Vec<Vec<Pecahan>>),
+    Skalar(Pecahan),
+    /// A tidak memiliki invers
+    Singular,
+}
+
+type Matriks = Vec<Vec<Pecahan>>;
+
+fn identitas(n: usize) -> Matriks {
+    (0..n)
+        .map(|i| {
+            (0..n)
+                .map(|j| if i == j { Pecahan::SATU } else { Pecahan::NOL })
+                .collect()
+        })
+        .collect()
+}
+
+// Sel-sel teks hasil `f(i, j)` berukuran baris × kolom
+fn sel_teks(baris: usize, kolom: usize, f: impl Fn(usize, usize) -> String) -> Vec<Vec<String>> {
+    (0..baris)
+        .map(|i| (0..kolom).map(|j| f(i, j)).collect())
+        .collect()
+}
+
+/// Eliminasi Gauss–Jordan pada `kolom` kolom pertama. Pivot dipilih dari
+/// baris yang bernilai ±1 jika ada (agar mudah dihitung tangan), lalu
+/// dinormalkan menjadi 1 dan dinolkan di baris lain. Jika `wajib_pivot`,
+/// eliminasi berhenti pada kolom pertama yang tidak memiliki pivot.
+/// Mengembalikan kolom pivot.
+pub fn gauss_jordan(
+    m: &mut [Vec<Pecahan>],
+    kolom: usize,
+    pemisah: Option<usize>,
+    wajib_pivot: bool,
+) -> Hitung<(Vec<usize>, Teks)> {
+    let gambar = |m: &[Vec<Pecahan>], t| match pemisah {
+        Some(p) => terpisah(m, p, t),
+        None => tulis("", m, Kurung::Siku, "", t),
+    };
+    let baris = m.len();
+    let mut steps = Teks::default();
+    let mut pivot = Vec::new();
+    let mut r = 0;
+    for c in 0..kolom {
+        if r >= baris {
+            break;
+        }
+        let calon: Vec<usize> = (r..baris).filter(|&i| !m[i][c].is_nol()).collect();
+        let Some(&p) = calon
+            .iter()
+            .find(|&&i| m[i][c].abs() == Pecahan::SATU)
+            .or(calon.first())
+        else {
+            steps.tulis(|_| {
+                format!(
+                    "\nKolom {} tidak memiliki pivot (semua nol), {}.\n",
+                    c + 1,
+                    if wajib_pivot {
+                        "eliminasi dihentikan"
+                    } else {
+                        "lanjut ke kolom berikutnya"
+                    }
+                )
+            });
+            if wajib_pivot {
+                break;
+            }
+            continue;
+        };
+
+        steps.tulis(|_| format!("\nKolom {}:\n", c + 1));
+        if p != r {
+            m.swap(p, r);
+            steps.tulis(|_| format!("  B{} ↔ B{}\n", r + 1, p + 1));
+        }
+        let nilai = m[r][c];
+        if nilai != Pecahan::SATU {
+            for v in m[r].iter_mut() {
+                *v = v.bagi(nilai)?;
+            }
+            steps.tulis(|t| format!("  B{} ← B{} / {}\n", r + 1, r + 1, kurung(nilai, t)));
+        }
+        for i in (0..baris).filter(|&i| i != r) {
+            let faktor = m[i][c];
+            if faktor.is_nol() {
+                continue;
+            }
+            let acuan = m[r].clone();
+            for (v, a) in m[i].iter_mut().zip(&acuan) {
+                *v = v.kurang(faktor.kali(*a)?)?;
+            }
+            steps.tulis(|t| {
+                format!(
+                    "  B{} ← B{} − {}·B{}\n",
+                    i + 1,
+                    i + 1,
+                    kurung(faktor, t),
+                    r + 1
+                )
+            });
+        }
+        steps.gambar(|t| gambar(m, t));
+        pivot.push(c);
+        r += 1;
+    }
+    Ok((pivot, steps))
+}
+
+//
+// Kalkulator Matriks
+// A + B dan kA dihitung elemen demi elemen, A · B dengan c_ij = Σ a_ik·b_kj,
+// A⁻¹ dengan eliminasi Gauss–Jordan pada [A | I], det(A) dengan rumus
+// silang/Sarrus/kofaktor, rank dan RREF dengan eliminasi Gauss–Jordan.
+// Ukuran A dan B diperiksa lebih dulu dengan `OperasiMatriks::periksa`.
+//
+pub fn kalkulator_proses(
+    operasi: OperasiMatriks,
+    a: &[Vec<Pecahan>],
+    b: &[Vec<Pecahan>],
+    k: Pecahan,
+) -> Hitung<(HasilMatriks, Teks)> {
+    let (ra, ca) = (a.len(), a[0].len());
+    let mut steps = Teks::dari(|_| format!("Operasi: {}\n\n", operasi.nama()));
+    // Determinan sudah digambar bersama langkah perhitungannya
+    if operasi != OperasiMatriks::Determinan {
+        steps.gambar(|t| tulis("A = ", a, Kurung::Siku, "", t));
+    }
+    if operasi.pakai_b() {
+        steps.push('\n');
+        steps.gambar(|t| tulis("B = ", b, Kurung::Siku, "", t));
+    }
+    if operasi.pakai_k() {
+        steps.tulis(|t| format!("\nk = {}\n", k.tampil(t)));
+    }
+
+    let hasil = match operasi {
+        OperasiMatriks::Jumlah => {
+            steps.push_str("\nJumlahkan elemen yang seletak:\n");
+            steps.gambar(|t| {
+                tulis_sel(
+                    "A + B = ",
+                    &sel_teks(ra, ca, |i, j| {
+                        format!("{} + {}", a[i][j].tampil(t), kurung(b[i][j], t))
+                    }),
+                    Kurung::Siku,
+                    "",
+                )
+            });
+            HasilMatriks::Matriks(
+                a.iter()
+                    .zip(b)
+                    .map(|(p, q)| p.iter().zip(q).map(|(x, y)| x.tambah(*y)).collect())
+                    .collect::<Hitung<_>>()?,
+            )
+        }
+        OperasiMatriks::Kali => {
+            let cb = b[0].len();
+            steps.tulis(|_| format!(
+                "\nA ({}×{}) · B ({}×{}) menghasilkan matriks {}×{} dengan c_ij = Σ a_ik·b_kj:\n",
+                ra,
+                ca,
+                b.len(),
+                cb,
+                ra,
+                cb
+            ));
+            steps.gambar(|t| {
+                tulis_sel(
+                    "A · B = ",
+                    &sel_teks(ra, cb, |i, j| {
+                        (0..ca)
+                            .map(|p| format!("{}·{}", kurung(a[i][p], t), kurung(b[p][j], t)))
+                            .collect::<Vec<_>>()
+                            .join(" + ")
+                    }),
+                    Kurung::Siku,
+                    "",
+                )
+            });
+            HasilMatriks::Matriks(
+                (0..ra)
+                    .map(|i| {
+                        (0..cb)
+                            .map(|j| Pecahan::jumlah_perkalian((0..ca).map(|t| (a[i][t], b[t][j]))))
+                            .collect()
+                    })
+                    .collect::<Hitung<_>>()?,
+            )
+        }
+        OperasiMatriks::Skalar => {
+            steps.push_str("\nKalikan setiap elemen A dengan k:\n");
+            steps.gambar(|t| {
+                tulis_sel(
+                    "kA = ",
+                    &sel_teks(ra, ca, |i, j| {
+                        format!("{}·{}", kurung(k, t), kurung(a[i][j], t))
+                    }),
+                    Kurung::Siku,
+                    "",
+                )
+            });
+            HasilMatriks::Matriks(
+                a.iter()
+                    .map(|p| p.iter().map(|x| k.kali(*x)).collect())
+                    .collect::<Hitung<_>>()?,
+            )
+        }
+        OperasiMatriks::Transpos => {
+            steps.push_str("\nBaris ke-i matriks A menjadi kolom ke-i matriks Aᵀ.\n");
+            HasilMatriks::Matriks(
+                (0..ca)
+                    .map(|j| (0..ra).map(|i| a[i][j]).collect())
+                    .collect(),
+            )
+        }
+        OperasiMatriks::Determinan => {
+            steps.sambung(&langkah_determinan("det(A)", a)?);
+            HasilMatriks::Skalar(determinan(a)?)
+        }
+        OperasiMatriks::Invers => {
+            // A singular jika ada kolom ruas kiri yang tidak memiliki pivot
+            steps.push_str("\nMatriks [A | I]:\n");
+            let mut m: Matriks = a
+                .iter()
+                .zip(identitas(ra))
+                .map(|(p, q)| p.iter().copied().chain(q).collect())
+                .collect();
+            steps.gambar(|t| terpisah(&m, ca, t));
+            let (pivot, langkah) = gauss_jordan(&mut m, ca, Some(ca), true)?;
+            steps.sambung(&langkah);
+            if pivot.len() < ca {
+                steps.push_str(
+                    "\nRuas kiri tidak dapat menjadi I → A singular, tidak memiliki invers.\n",
+                );
+                HasilMatriks::Singular
+            } else {
+                steps.push_str("\nRuas kiri sudah menjadi I, sehingga ruas kanan adalah A⁻¹.\n");
+                HasilMatriks::Matriks(m.into_iter().map(|p| p[ca..].to_vec()).collect())
+            }
+        }
+        OperasiMatriks::Rank | OperasiMatriks::Rref => {
+            let mut m = a.to_vec();
+            let (pivot, langkah) = gauss_jordan(&mut m, ca, None, false)?;
+            steps.sambung(&langkah);
+            if operasi == OperasiMatriks::Rank {
+                steps.tulis(|_| {
+                    format!(
+                        "\nBanyak baris tak nol (pivot) = {} → rank(A) = {}\n",
+                        pivot.len(),
+                        pivot.len()
+                    )
+                });
+                HasilMatriks::Skalar(Pecahan::from(pivot.len() as i128))
+            } else {
+                steps.tulis(|_| {
+                    format!(
+                        "\nBentuk eselon baris tereduksi dengan {} pivot (kolom {}).\n",
+                        pivot.len(),
+                        pivot
+                            .iter()
+                            .map(|c| (c + 1).to_string())
+                            .collect::<Vec<_>>()
+                            .join(", ")
+                    )
+                });
+                HasilMatriks::Matriks(m)
+            }
+        }
+    };
+    Ok((hasil, steps))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::solver::pecahan::uji::matriks;
+
+    fn hitung(operasi: OperasiMatriks, a: &[&[i128]], b: &[&[i128]]) -> HasilMatriks {
+        kalkulator_proses(operasi, &matriks(a), &matriks(b), Pecahan::from(3))
+            .unwrap()
+            .0
+    }
+
+    fn isi(hasil: HasilMatriks) -> Option<Matriks> {
+        match hasil {
+            HasilMatriks::Matriks(m) => Some(m),
+            _ => None,
+        }
+    }
+
+    #[test]
+    fn ukuran_diperiksa() {
+        assert!(OperasiMatriks::Jumlah.periksa((2, 2), (2, 3)).is_err());
+        assert!(OperasiMatriks::Kali.periksa((2, 3), (3, 1)).is_ok());
+        assert!(OperasiMatriks::Kali.periksa((2, 3), (2, 3)).is_err());
+        assert!(OperasiMatriks::Invers.periksa((2, 3), (0, 0)).is_err());
+        assert!(OperasiMatriks::Transpos.periksa((2, 3), (0, 0)).is_ok());
+    }
+
+    #[test]
+    fn operasi_elemen_dan_perkalian() {
+        let a: &[&[i128]] = &[&[1, 2], &[3, 4]];
+        let b: &[&[i128]] = &[&[0, 1], &[1, 0]];
+        assert_eq!(
+            isi(hitung(OperasiMatriks::Jumlah, a, b)),
+            Some(matriks(&[&[1, 3], &[4, 4]]))
+        );
+        assert_eq!(
+            isi(hitung(OperasiMatriks::Kali, a, b)),
+            Some(matriks(&[&[2, 1], &[4, 3]]))
+        );
+        assert_eq!(
+            isi(hitung(OperasiMatriks::Skalar, a, b)),
+            Some(matriks(&[&[3, 6], &[9, 12]]))
+        );
+        assert_eq!(
+            isi(hitung(OperasiMatriks::Transpos, a, b)),
+            Some(matriks(&[&[1, 3], &[2, 4]]))
+        );
+    }
+
+    #[test]
+    fn invers_dan_singular() {
+        let inv = isi(hitung(OperasiMatriks::Invers, &[&[2, 1], &[5, 3]], &[]));
+        assert_eq!(inv, Some(matriks(&[&[3, -1], &[-5, 2]])));
+        assert!(matches!(
+            hitung(OperasiMatriks::Invers, &[&[1, 2], &[2, 4]], &[]),
+            HasilMatriks::Singular
+        ));
+        // Singularitas terlihat saat kolom 2 ruas kiri tidak memiliki pivot
+        let a = matriks(&[&[1, 2], &[2, 4]]);
+        let (_, langkah) =
+            kalkulator_proses(OperasiMatriks::Invers, &a, &[], Pecahan::NOL).unwrap();
+        assert!(
+            langkah
+                .pecahan
+                .teks()
+                .contains("Kolom 2 tidak memiliki pivot (semua nol), eliminasi dihentikan.")
+        );
+        assert!(!langkah.pecahan.teks().contains("det(A)"));
+    }
+
+    #[test]
+    fn rank_dan_rref() {
+        let a: &[&[i128]] = &[&[1, 2, 3], &[2, 4, 6], &[1, 0, 1]];
+        assert!(matches!(
+            hitung(OperasiMatriks::Rank, a, &[]),
+            HasilMatriks::Skalar(r) if r == Pecahan::from(2)
+        ));
+        assert_eq!(
+            isi(hitung(OperasiMatriks::Rref, a, &[])),
+            Some(matriks(&[&[1, 0, 1], &[0, 1, 1], &[0, 0, 0]]))
+        );
+    }
+}
@@ -0,0 +1,417 @@
+use crate::solver::pecahan::{MAKS_DIGIT, Pecahan, Tampilan, desimal};
+
+/// κ∞ di atas batas ini dianggap berkondisi buruk (≥ 3 angka penting hilang)
+pub const BATAS_KONDISI_BURUK: f64 = 1e3;
+
+// Sisa penyelesaian Ax = b, hanya ada jika b dan x diketahui
+struct Sisa {
+    /// ‖b − Ax‖∞ untuk penyelesaian eksak
+    eksak: Pecahan,
+    /// ‖b − Ax̃‖∞ dengan x̃ = x dibulatkan ke 4 angka desimal
+    bulat: Pecahan,
+    norma_b: Pecahan,
+    norma_x: Pecahan,
+}
+
+/// Bilangan kondisi matriks persegi A, beserta sisa penyelesaian Ax = b
+/// jika dihitung dari sebuah sistem
+pub struct Kondisi {
+    /// Nama matriks dan ruas kanan pada langkah, mis. ("AᵀA", "Aᵀb")
+    nama: (&'static str, &'static str),
+    norma_a: Pecahan,
+    norma_invers: Pecahan,
+    /// κ∞(A) = ‖A‖∞·‖A⁻¹‖∞
+    pub angka: f64,
+    /// Banyak desimal koefisien d dan galat relatif koefisien
+    /// ε = n·½·10⁻ᵈ/‖A‖∞; `None` jika semua koefisien bulat atau pecahan eksak
+    presisi: Option<(usize, f64)>,
+    sisa: Option<Sisa>,
+}
+
+impl Kondisi {
+    pub fn buruk(&self) -> bool {
+        self.angka >= BATAS_KONDISI_BURUK
+    }
+
+    /// Perkiraan banyak angka penting yang hilang, ≈ log₁₀ κ
+    pub fn digit_hilang(&self) -> usize {
+        self.angka.log10().max(0.0).round() as usize
+    }
+
+    /// Angka penting hasil yang dapat dipercaya, ≈ −log₁₀(κ∞·ε)
+    fn digit_dipercaya(&self, galat: f64) -> usize {
+        (-(self.angka * galat).log10()).floor().max(0.0) as usize
+    }
+
+    /// Ganti nama matriks pada langkah dan ambil presisi dari matriks
+    /// diperbesar asal [A | b], mis. untuk sistem normal AᵀA x = Aᵀb
+    pub fn untuk(
+        mut self,
+        matriks: &'static str,
+        ruas: &'static str,
+        koefisien: &[Vec<Pecahan>],
+    ) -> Self {
+        self.nama = (matriks, ruas);
+        self.presisi = presisi(koefisien, koefisien[0].len() - 1);
+        self
+    }
+
+    // "Sistem" jika ada sisa, "Matriks" jika hanya A yang diketahui
+    fn jenis(&self) -> &'static str {
+        if self.sisa.is_some() {
+            "Sistem"
+        } else {
+            "Matriks"
+        }
+    }
+
+    // Besaran yang dinilai kepekaannya: x untuk sistem, hasil untuk matriks
+    fn hasil(&self) -> &'static str {
+        if self.sisa.is_some() { "x" } else { "hasil" }
+    }
+
+    /// Teks lencana peringatan di layar hasil, hanya untuk kondisi buruk
+    pub fn peringatan(&self) -> Option<String> {
+        self.buruk().then(|| {
+            let awal = format!(
+                "{} berkondisi buruk (κ∞ ≈ {}): sekitar {} angka penting hilang",
+                self.jenis(),
+                angka(self.angka),
+                self.digit_hilang()
+            );
+            match self.presisi {
+                Some((d, galat)) => format!(
+                    "{}; dengan koefisien {} desimal hanya ±{} angka penting {} dapat dipercaya",
+                    awal,
+                    d,
+                    self.digit_dipercaya(galat),
+                    self.hasil()
+                ),
+                None => format!("{} jika koefisien dibulatkan", awal),
+            }
+        })
+    }
+
+    /// Ringkasan satu baris, atau langkah lengkap bilangan kondisi, sisa,
+    /// dan kepekaannya jika berkondisi buruk
+    pub fn langkah(&self, t: Tampilan) -> String {
+        let (a, b) = self.nama;
+        if !self.buruk() {
+            return match &self.sisa {
+                Some(sisa) => format!(
+                    "\nKondisi sistem: κ∞({}) ≈ {}, sisa ‖{} − {}x‖∞ = {} → berkondisi baik\n",
+                    a,
+                    angka(self.angka),
+                    b,
+                    a,
+                    sisa.eksak.tampil(t)
+                ),
+                None => format!(
+                    "\nKondisi matriks: κ∞({}) ≈ {} → berkondisi baik\n",
+                    a,
+                    angka(self.angka)
+                ),
+            };
+        }
+
+        // (AᵀA)⁻¹ diberi kurung agar tidak terbaca Aᵀ·A⁻¹
+        let inv = if a.chars().count() == 1 {
+            format!("{}⁻¹", a)
+        } else {
+            format!("({})⁻¹", a)
+        };
+        let mut steps = format!("\nKondisi {}:\n", self.jenis().to_lowercase());
+        steps.push_str(&format!(
+            "  ‖{}‖∞ = {} (jumlah mutlak baris terbesar)\n",
+            a,
+            self.norma_a.tampil(t)
+        ));
+        steps.push_str(&format!("  ‖{}‖∞ = {}\n", inv, self.norma_invers.tampil(t)));
+        steps.push_str(&format!(
+            "  κ∞({}) = ‖{}‖∞·‖{}‖∞ ≈ {}\n",
+            a,
+            a,
+            inv,
+            angka(self.angka)
+        ));
+        if let Some(sisa) = &self.sisa {
+            steps.push_str(&format!(
+                "  Sisa ‖{} − {}x‖∞ = {}{}\n",
+                b,
+                a,
+                sisa.eksak.tampil(t),
+                if sisa.eksak.is_nol() {
+                    " (penyelesaian eksak)"
+                } else {
+                    ""
+                }
+            ));
+            steps.push_str(&format!(
+                "  x̃ = x dibulatkan 4 desimal: ‖{} − {}x̃‖∞ ≈ {}\n",
+                b,
+                a,
+                angka(sisa.bulat.ke_f64())
+            ));
+            // ‖x − x̃‖/‖x‖ ≤ κ·‖b − Ax̃‖/‖b‖
+            if !sisa.norma_b.is_nol() && !sisa.norma_x.is_nol() {
+                steps.push_str(&format!(
+                    "  Galat relatif x̃ ≤ κ∞·‖{} − {}x̃‖∞/‖{}‖∞ ≈ {}\n",
+                    b,
+                    a,
+                    b,
+                    angka(self.angka * sisa.bulat.ke_f64() / sisa.norma_b.ke_f64())
+                ));
+            }
+        }
+        steps.push_str(&format!(
+            "  Galat koefisien dapat diperbesar hingga κ∞ kali pada {}\n",
+            self.hasil()
+        ));
+        steps.push_str(&format!(
+            "  Angka penting hilang ≈ log₁₀ κ∞ ≈ {}\n",
+            self.digit_hilang()
+        ));
+        match self.presisi {
+            Some((d, galat)) => {
+                steps.push_str(&format!(
+                    "  Koefisien hingga {} desimal: galat tiap koefisien ≤ ½·10⁻{}\n  \
+                     Galat relatif koefisien ε = n·½·10⁻{}/‖A‖∞ ≈ {}\n",
+                    d,
+                    superskrip(d),
+                    superskrip(d),
+                    angka(galat)
+                ));
+                steps.push_str(&format!(
+                    "  Galat relatif {} ≲ κ∞·ε ≈ {} → sekitar {} angka penting dapat dipercaya\n",
+                    self.hasil(),
+                    angka(self.angka * galat),
+                    self.digit_dipercaya(galat)
+                ));
+            }
+            None => steps.push_str(
+                "  Semua koefisien bulat atau pecahan eksak (tanpa pembulatan);\n  \
+                 jika koefisien dibulatkan, sekitar sebanyak itu angka penting hilang\n",
+            ),
+        }
+        steps.push_str(&format!(
+            "  ⚠ {} berkondisi buruk: perubahan kecil pada\n  \
+             koefisien dapat mengubah penyelesaian secara besar.\n",
+            self.jenis()
+        ));
+        steps
+    }
+}
+
+// Bilangan kecil atau besar ditulis dalam notasi ilmiah, mis. "4.00e4"
+fn angka(v: f64) -> String {
+    if v == 0.0 {
+        "0".to_string()
+    } else if (1e-3..1e4).contains(&v.abs()) {
+        desimal(v)
+    } else {
+        format!("{:.2e}", v)
+    }
+}
+
+// Pangkat superskrip, mis. 12 → "¹²"
+fn superskrip(n: usize) -> String {
+    const ANGKA: [char; 10] = ['⁰', '¹', '²', '³', '⁴', '⁵', '⁶', '⁷', '⁸', '⁹'];
+    n.to_string()
+        .bytes()
+        .map(|c| ANGKA[(c - b'0') as usize])
+        .collect()
+}
+
+// Banyak angka di belakang koma jika v desimal berhingga dan bukan bilangan
+// bulat, mis. 0.0125 → 4; `None` untuk bilangan bulat atau desimal tak berhingga
+fn banyak_desimal(v: Pecahan) -> Option<usize> {
+    if v.is_bulat() {
+        return None;
+    }
+    let mut pem = v.pembilang();
+    for d in 1..=MAKS_DIGIT {
+        pem = pem.checked_mul(10)?;
+        if pem % v.penyebut() == 0 {
+            return Some(d);
+        }
+    }
+    None
+}
+
+// Presisi koefisien [A | b] dengan `n` kolom A: desimal terbanyak d dan
+// galat relatif ε = n·½·10⁻ᵈ/‖A‖∞
+fn presisi(m: &[Vec<Pecahan>], n: usize) -> Option<(usize, f64)> {
+    let d = m
+        .iter()
+        .flatten()
+        .filter_map(|&v| banyak_desimal(v))
+        .max()?;
+    let a: Vec<Vec<Pecahan>> = m.iter().map(|b| b[..n].to_vec()).collect();
+    let norma = norma_matriks(&a)?.ke_f64();
+    (norma > 0.0).then(|| (d, n as f64 * 0.5 * 10f64.powi(-(d as i32)) / norma))
+}
+
+// ‖v‖∞ = maks |vᵢ|
+fn norma_vektor(v: &[Pecahan]) -> Pecahan {
+    v.iter().map(|x| x.abs()).max().unwrap_or(Pecahan::NOL)
+}
+
+// ‖A‖∞ = maks Σⱼ |aᵢⱼ|
+fn norma_matriks(a: &[Vec<Pecahan>]) -> Option<Pecahan> {
+    a.iter()
+        .map(|b| Pecahan::jumlah(b.iter().map(|x| x.abs())).ok())
+        .collect::<Option<Vec<_>>>()
+        .map(|jumlah| jumlah.into_iter().max().unwrap_or(Pecahan::NOL))
+}
+
+// Invers dengan eliminasi Gauss–Jordan, `None` jika A singular atau
+// hitungannya melampaui batas pecahan
+fn invers(a: &[Vec<Pecahan>]) -> Option<Vec<Vec<Pecahan>>> {
+    let n = a.len();
+    let mut m: Vec<Vec<Pecahan>> = a
+        .iter()
+        .enumerate()
+        .map(|(i, b)| {
+            b.iter()
+                .copied()
+                .chain((0..n).map(|j| if i == j { Pecahan::SATU } else { Pecahan::NOL }))
+                .collect()
+        })
+        .collect();
+    for c in 0..n {
+        let p = (c..n).find(|&i| !m[i][c].is_nol())?;
+        m.swap(p, c);
+        let pivot = m[c][c];
+        for v in m[c].iter_mut() {
+            *v = v.bagi(pivot).ok()?;
+        }
+        for i in (0..n).filter(|&i| i != c) {
+            let faktor = m[i][c];
+            if faktor.is_nol() {
+                continue;
+            }
+            let acuan = m[c].clone();
+            for (v, a) in m[i].iter_mut().zip(&acuan) {
+                *v = v.kurang(faktor.kali(*a).ok()?).ok()?;
+            }
+        }
+    }
+    Some(m.into_iter().map(|b| b[n..].to_vec()).collect())
+}
+
+// b − Ax
+fn sisa(a: &[Vec<Pecahan>], b: &[Pecahan], x: &[Pecahan]) -> Option<Vec<Pecahan>> {
+    a.iter()
+        .zip(b)
+        .map(|(baris, bi)| {
+            let ax = Pecahan::jumlah_perkalian(baris.iter().copied().zip(x.iter().copied()));
+            bi.kurang(ax.ok()?).ok()
+        })
+        .collect()
+}
+
+/// Bilangan kondisi matriks persegi A, `None` jika A bukan persegi,
+/// singular, atau hitungannya melampaui batas pecahan
+pub fn kondisi_matriks(a: &[Vec<Pecahan>]) -> Option<Kondisi> {
+    if a.iter().any(|b| b.len() != a.len()) {
+        return None;
+    }
+    let inv = invers(a)?;
+    let norma_a = norma_matriks(a)?;
+    let norma_invers = norma_matriks(&inv)?;
+    Some(Kondisi {
+        nama: ("A", "b"),
+        norma_a,
+        norma_invers,
+        angka: norma_a.ke_f64() * norma_invers.ke_f64(),
+        presisi: presisi(a, a.len()),
+        sisa: None,
+    })
+}
+
+/// Bilangan kondisi dan sisa untuk matriks diperbesar persegi [A | b]
+/// berukuran n × (n + 1) dengan penyelesaian tunggal `x`.
+/// `None` jika A bukan persegi, singular, atau hitungannya melampaui batas
+/// pecahan.
+pub fn kondisi_sistem(m: &[Vec<Pecahan>], x: &[Pecahan]) -> Option<Kondisi> {
+    let n = x.len();
+    if m.len() != n || m.iter().any(|b| b.len() != n + 1) {
+        return None;
+    }
+    let a: Vec<Vec<Pecahan>> = m.iter().map(|b| b[..n].to_vec()).collect();
+    let b: Vec<Pecahan> = m.iter().map(|b| b[n]).collect();
+    let bulat: Vec<Pecahan> = x
+        .iter()
+        .map(|v| Pecahan::coba((v.ke_f64() * 1e4).round() as i128, 10_000).ok())
+        .collect::<Option<_>>()?;
+    Some(Kondisi {
+        presisi: presisi(m, n),
+        sisa: Some(Sisa {
+            eksak: norma_vektor(&sisa(&a, &b, x)?),
+            bulat: norma_vektor(&sisa(&a, &b, &bulat)?),
+            norma_b: norma_vektor(&b),
+            norma_x: norma_vektor(x),
+        }),
+        ..kondisi_matriks(&a)?
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::solver::pecahan::uji::{matriks, p};
+
+    const P: Tampilan = Tampilan::Pecahan;
+
+    #[test]
+    fn sistem_berkondisi_baik_diringkas() {
+        let m = matriks(&[&[1, 2, 5], &[3, 4, 11]]);
+        let kondisi = kondisi_sistem(&m, &[p(1, 1), p(2, 1)]).unwrap();
+        assert!(!kondisi.buruk());
+        assert!(kondisi.presisi.is_none());
+        assert!(kondisi.peringatan().is_none());
+        let langkah = kondisi.langkah(P);
+        assert!(langkah.contains("sisa ‖b − Ax‖∞ = 0 → berkondisi baik"));
+        assert_eq!(langkah.lines().filter(|b| !b.is_empty()).count(), 1);
+    }
+
+    #[test]
+    fn sistem_berkondisi_buruk_diperingatkan() {
+        // x + y = 2, x + 1.0001y = 2.0001 → x = y = 1
+        let m = vec![
+            vec![p(1, 1), p(1, 1), p(2, 1)],
+            vec![p(1, 1), p(10001, 10000), p(20001, 10000)],
+        ];
+        let kondisi = kondisi_sistem(&m, &[p(1, 1), p(1, 1)]).unwrap();
+        assert!(kondisi.buruk());
+        assert_eq!(kondisi.presisi.map(|(d, _)| d), Some(4));
+        let peringatan = kondisi.peringatan().unwrap();
+        assert!(peringatan.starts_with("Sistem berkondisi buruk"));
+        assert!(peringatan.contains("dengan koefisien 4 desimal"));
+        assert!(kondisi.langkah(P).contains('⚠'));
+    }
+
+    #[test]
+    fn nama_matriks_diganti() {
+        let a = matriks(&[&[2, 0], &[0, 1]]);
+        let kondisi = kondisi_matriks(&a).unwrap();
+        assert_eq!(kondisi.angka, 2.0);
+        assert!(
+            kondisi
+                .langkah(P)
+                .starts_with("\nKondisi matriks: κ∞(A) ≈ 2")
+        );
+        let m = matriks(&[&[2, 0, 1], &[0, 1, 1]]);
+        let kondisi = kondisi_sistem(&m, &[p(1, 2), p(1, 1)])
+            .unwrap()
+            .untuk("AᵀA", "Aᵀb", &m);
+        assert!(kondisi.langkah(P).contains("κ∞(AᵀA)"));
+    }
+
+    #[test]
+    fn matriks_singular_atau_tidak_persegi() {
+        assert!(kondisi_matriks(&matriks(&[&[1, 2], &[2, 4]])).is_none());
+        assert!(kondisi_matriks(&matriks(&[&[1, 2, 3], &[4, 5, 6]])).is_none());
+        assert!(kondisi_sistem(&matriks(&[&[1, 2, 3]]), &[p(1, 1), p(1, 1)]).is_none());
+    }
+}
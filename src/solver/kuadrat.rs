@@ -0,0 +1,552 @@
+use crate::solver::format::{Teks, kombinasi_linear, kurung, nilai_akhir};
+use crate::solver::gauss::JenisSolusi;
+use crate::solver::pecahan::{GalatHitung, Hitung, Pecahan, Tampilan, akar_bulat, desimal, kpk};
+use crate::solver::splsv::{splsv_proses, suku_splsv};
+
+/// Akar-akar persamaan kuadrat ax² + bx + c = 0
+#[derive(Clone, Copy, PartialEq)]
+pub enum AkarKuadrat {
+    /// Dua akar p + q√r dan p - q√r dengan q > 0 dan r bebas kuadrat
+    /// (r = 1 berarti akarnya rasional). Jika `imajiner`, kedua akar
+    /// adalah bilangan kompleks sekawan p ± q√r·i.
+    Dua {
+        p: Pecahan,
+        q: Pecahan,
+        r: i128,
+        imajiner: bool,
+    },
+    Kembar(Pecahan),
+    /// a = 0: persamaan menjadi linear bx + c = 0
+    Linear(JenisSolusi, Option<Pecahan>),
+}
+
+impl AkarKuadrat {
+    /// Akar-akar real dari kecil ke besar, dipakai untuk grafik
+    pub fn real(&self) -> Vec<f64> {
+        match *self {
+            AkarKuadrat::Dua { imajiner: true, .. } => Vec::new(),
+            AkarKuadrat::Dua { p, q, r, .. } => {
+                let d = q.ke_f64() * (r as f64).sqrt();
+                vec![p.ke_f64() - d, p.ke_f64() + d]
+            }
+            AkarKuadrat::Kembar(x) => vec![x.ke_f64()],
+            AkarKuadrat::Linear(_, x) => x.map(Pecahan::ke_f64).into_iter().collect(),
+        }
+    }
+
+    /// Nilai akhir akar x₁ (`tanda` "+") atau x₂ (`tanda` "-"). Akar irasional
+    /// dan kompleks pada tampilan pecahan diberi nilai desimalnya di samping.
+    pub fn nilai(&self, tanda: &str, t: Tampilan) -> Hitung<String> {
+        Ok(match *self {
+            AkarKuadrat::Dua {
+                p,
+                q,
+                r: 1,
+                imajiner: false,
+            } => nilai_akhir(
+                if tanda == "+" {
+                    p.tambah(q)?
+                } else {
+                    p.kurang(q)?
+                },
+                t,
+            ),
+            AkarKuadrat::Dua { p, q, r, imajiner } => {
+                let eksak = bentuk_akar(p, q, r, imajiner, tanda, t)?;
+                if t == Tampilan::Pecahan {
+                    let hampiran = bentuk_akar(p, q, r, imajiner, tanda, Tampilan::Desimal)?;
+                    format!("{} ≈ {}", eksak, hampiran)
+                } else {
+                    eksak
+                }
+            }
+            AkarKuadrat::Kembar(x) | AkarKuadrat::Linear(_, Some(x)) => nilai_akhir(x, t),
+            AkarKuadrat::Linear(..) => String::new(),
+        })
+    }
+}
+
+// √x = k√r untuk x ≥ 0, dengan r = 1 tepat ketika x kuadrat sempurna.
+// Pembagian percobaan berhenti di 10⁴; sisa yang kuadrat sempurna ikut
+// dikeluarkan, faktor kuadrat lain yang lebih besar tetap di bawah akar.
+pub fn sederhana_akar(x: Pecahan) -> Hitung<(Pecahan, i128)> {
+    if let Some(k) = x.akar_eksak() {
+        return Ok((k, 1));
+    }
+    // √(n/d) = √(n·d)/d
+    let d = x.penyebut();
+    let mut m = x
+        .pembilang()
+        .checked_mul(d)
+        .ok_or(GalatHitung::TerlaluBesar)?;
+    let (mut k, mut r) = (1, 1);
+    let mut i: i128 = 2;
+    while i * i <= m && i <= 10_000 {
+        while m % (i * i) == 0 {
+            m /= i * i;
+            k *= i;
+        }
+        // Faktor tunggal dikeluarkan agar m cepat mengecil
+        if m % i == 0 {
+            m /= i;
+            r *= i;
+        }
+        i += 1;
+    }
+    let s = akar_bulat(m);
+    if s * s == m {
+        (k, m) = (k * s, 1);
+    }
+    Ok((Pecahan::coba(k, d)?, r * m))
+}
+
+// Menulis p (tanda) q√r dengan penyebut bersama, mis. "(-3 ± √5)/2",
+// "-1 + 2i", atau "±√5/2". `tanda` adalah "+", "-", atau "±".
+pub fn bentuk_akar(
+    p: Pecahan,
+    q: Pecahan,
+    r: i128,
+    imajiner: bool,
+    tanda: &str,
+    t: Tampilan,
+) -> Hitung<String> {
+    let tanpa_p = |suku: String| match tanda {
+        "+" => suku,
+        _ => format!("{}{}", tanda, suku),
+    };
+
+    // Akar rasional cukup ditulis sebagai satu bilangan
+    if r == 1 && !imajiner {
+        return Ok(match tanda {
+            "+" => p.tambah(q)?.tampil(t).to_string(),
+            "-" => p.kurang(q)?.tampil(t).to_string(),
+            _ if p.is_nol() => tanpa_p(q.tampil(t).to_string()),
+            _ => format!("{} ± {}", p.tampil(t), q.tampil(t)),
+        });
+    }
+
+    if t == Tampilan::Desimal {
+        let v = q.ke_f64() * (r as f64).sqrt();
+        if !imajiner {
+            return Ok(match tanda {
+                "+" => desimal(p.ke_f64() + v),
+                "-" => desimal(p.ke_f64() - v),
+                _ if p.is_nol() => tanpa_p(desimal(v)),
+                _ => format!("{} ± {}", p.tampil(t), desimal(v)),
+            });
+        }
+        let suku = format!("{}i", desimal(v));
+        return Ok(if p.is_nol() {
+            tanpa_p(suku)
+        } else {
+            format!("{} {} {}", p.tampil(t), tanda, suku)
+        });
+    }
+
+    // (u ± v√r)/w dengan u, v, w bilangan bulat
+    let w = kpk(p.penyebut(), q.penyebut()).ok_or(GalatHitung::TerlaluBesar)?;
+    let u = p.kali(Pecahan::from(w))?.pembilang();
+    let v = q.kali(Pecahan::from(w))?.pembilang();
+    let akar = match (imajiner, r) {
+        (true, 1) => "i".to_string(),
+        (true, _) => format!("i√{}", r),
+        (false, _) => format!("√{}", r),
+    };
+    let suku = if v == 1 {
+        akar
+    } else {
+        format!("{}{}", v, akar)
+    };
+    let pembilang = if u == 0 {
+        tanpa_p(suku)
+    } else {
+        format!("{} {} {}", u, tanda, suku)
+    };
+    Ok(match (w, u) {
+        (1, _) => pembilang,
+        (_, 0) => format!("{}/{}", pembilang, w),
+        _ => format!("({})/{}", pembilang, w),
+    })
+}
+
+// Ruas kiri ax² + bx + c
+pub fn suku_kuadrat(a: Pecahan, b: Pecahan, c: Pecahan, t: Tampilan) -> String {
+    if a.is_nol() && b.is_nol() {
+        return c.tampil(t).to_string();
+    }
+    let kiri = kombinasi_linear(Pecahan::NOL, &[(a, "x²"), (b, "x")], t);
+    if c.is_nol() {
+        kiri
+    } else if c.is_negatif() {
+        format!("{} - {}", kiri, (-c).tampil(t))
+    } else {
+        format!("{} + {}", kiri, c.tampil(t))
+    }
+}
+
+// x + h, tanpa "+ 0"
+fn x_tambah(h: Pecahan, t: Tampilan) -> String {
+    if h.is_nol() {
+        "x".to_string()
+    } else {
+        suku_splsv(Pecahan::SATU, h, t)
+    }
+}
+
+// Faktor linear dengan koefisien bulat yang bernilai nol di x, mis. "2x + 3" untuk x = -3/2
+fn faktor(x: Pecahan, t: Tampilan) -> String {
+    if x.is_nol() {
+        "x".to_string()
+    } else {
+        suku_splsv(
+            Pecahan::from(x.penyebut()),
+            -Pecahan::from(x.pembilang()),
+            t,
+        )
+    }
+}
+
+// Faktor di dalam perkalian: "x" tanpa kurung, lainnya diberi kurung
+fn faktor_kali(x: Pecahan, t: Tampilan) -> String {
+    if x.is_nol() {
+        "x".to_string()
+    } else {
+        format!("({})", faktor(x, t))
+    }
+}
+
+// Penyelesaian satu faktor, mis. "2x + 3 = 0 → x = -3/2"
+fn faktor_nol(x: Pecahan, t: Tampilan) -> String {
+    if x.is_nol() {
+        "x = 0".to_string()
+    } else {
+        format!("{} = 0 → x = {}", faktor(x, t), x.tampil(t))
+    }
+}
+
+//
+// Persamaan Kuadrat
+// ax² + bx + c = 0  →  D = b² - 4ac  →  x = (-b ± √D) / 2a
+// Akar dicari dengan pemfaktoran (jika D kuadrat sempurna), melengkapkan
+// kuadrat sempurna, dan rumus abc. Akar irasional ditulis dalam bentuk akar,
+// akar kompleks dalam bentuk p ± qi.
+//
+pub fn kuadrat_proses(a: Pecahan, b: Pecahan, c: Pecahan) -> Hitung<(AkarKuadrat, Teks)> {
+    if a.is_nol() {
+        let (jenis, x, langkah) = splsv_proses(b, c)?;
+        let mut steps = Teks::from(
+            "Koefisien x² (a) = 0 → bukan persamaan kuadrat, melainkan persamaan linear.\n",
+        );
+        steps.sambung(&langkah);
+        return Ok((AkarKuadrat::Linear(jenis, x), steps));
+    }
+    let mut steps = Teks::dari(|t| {
+        format!(
+            "Persamaan: {} = 0\na = {}, b = {}, c = {}\n",
+            suku_kuadrat(a, b, c, t),
+            a.tampil(t),
+            b.tampil(t),
+            c.tampil(t)
+        )
+    });
+
+    // Langkah 1: diskriminan menentukan jenis akar
+    let (dua, empat) = (Pecahan::from(2), Pecahan::from(4));
+    let (bb, ac) = (b.kali(b)?, a.kali(c)?);
+    let ac4 = empat.kali(ac)?;
+    let d = bb.kurang(ac4)?;
+    let dua_a = dua.kali(a)?;
+    steps.tulis(|t| {
+        format!(
+            "\nLangkah 1: Diskriminan\n  D = b² - 4ac = {}² - 4 × {} × {} = {} - {} = {}\n",
+            kurung(b, t),
+            kurung(a, t),
+            kurung(c, t),
+            bb.tampil(t),
+            kurung(ac4, t),
+            d.tampil(t)
+        )
+    });
+    let eksak = d.akar_eksak();
+    let rasional = eksak.is_some();
+    steps.push_str(if d.is_nol() {
+        "  D = 0 → dua akar real yang sama (akar kembar)\n"
+    } else if d.is_negatif() {
+        "  D < 0 → tidak ada akar real; kedua akarnya bilangan kompleks sekawan\n"
+    } else if rasional {
+        "  D > 0 dan D kuadrat sempurna → dua akar real berbeda yang rasional\n"
+    } else {
+        "  D > 0 tetapi D bukan kuadrat sempurna → dua akar real berbeda yang irasional\n"
+    });
+
+    // x = p ± q√r, dengan p = -b/2a dan q√r = √|D| / |2a|
+    let p = (-b).bagi(dua_a)?;
+    // Akar D kuadrat sempurna diambil langsung agar tidak bergantung pada
+    // batas pembagian percobaan sederhana_akar
+    let (q, r) = match eksak {
+        Some(s) => (s.bagi(dua_a.abs())?, 1),
+        None => sederhana_akar(d.abs().bagi(dua_a.kali(dua_a)?)?)?,
+    };
+    let imajiner = d.is_negatif();
+    let akar = if d.is_nol() {
+        AkarKuadrat::Kembar(p)
+    } else {
+        AkarKuadrat::Dua { p, q, r, imajiner }
+    };
+
+    // Langkah 2: pemfaktoran a(x - x₁)(x - x₂) dengan faktor berkoefisien bulat
+    steps.push_str("\nLangkah 2: Pemfaktoran\n");
+    if d.is_negatif() {
+        steps.push_str("  D negatif → tidak dapat difaktorkan atas bilangan real.\n");
+    } else if !rasional {
+        steps.push_str(
+            "  D bukan kuadrat sempurna → tidak dapat difaktorkan atas bilangan rasional.\n",
+        );
+    } else {
+        let (x1, x2) = (p.tambah(q)?, p.kurang(q)?);
+        if a.is_bulat() && b.is_bulat() && c.is_bulat() {
+            // Pecah bx menjadi mx + nx dengan m × n = ac dan m + n = b
+            let (m, n) = ((-a).kali(x1)?, (-a).kali(x2)?);
+            steps.tulis(|t| format!(
+                "  Cari dua bilangan yang hasil kalinya a × c = {} dan jumlahnya b = {}: {} dan {}\n",
+                ac.tampil(t),
+                b.tampil(t),
+                m.tampil(t),
+                n.tampil(t)
+            ));
+        }
+        let k = a.bagi(Pecahan::from(x1.penyebut()).kali(Pecahan::from(x2.penyebut()))?)?;
+        let depan = |t| {
+            if k == Pecahan::SATU {
+                String::new()
+            } else if k == -Pecahan::SATU {
+                "-".to_string()
+            } else if k.is_bulat() {
+                k.tampil(t).to_string()
+            } else {
+                format!("({})", k.tampil(t))
+            }
+        };
+        if d.is_nol() {
+            let kuadrat = |t| {
+                if x1.is_nol() {
+                    "x²".to_string()
+                } else {
+                    format!("({})²", faktor(x1, t))
+                }
+            };
+            steps.tulis(|t| {
+                format!(
+                    "  {}{} = 0\n  {}\n",
+                    depan(t),
+                    kuadrat(t),
+                    faktor_nol(x1, t)
+                )
+            });
+        } else {
+            // Faktor x ditulis di depan, mis. -x(x - 2)
+            let (x1, x2) = if x2.is_nol() { (x2, x1) } else { (x1, x2) };
+            steps.tulis(|t| {
+                format!(
+                    "  {}{}{} = 0\n  {}   atau   {}\n",
+                    depan(t),
+                    faktor_kali(x1, t),
+                    faktor_kali(x2, t),
+                    faktor_nol(x1, t),
+                    faktor_nol(x2, t)
+                )
+            });
+        }
+    }
+
+    // Langkah 3: melengkapkan kuadrat sempurna (x + h)² = D / 4a²
+    steps.push_str("\nLangkah 3: Melengkapkan kuadrat sempurna\n");
+    let (pb, pc) = (b.bagi(a)?, c.bagi(a)?);
+    if a != Pecahan::SATU {
+        steps.tulis(|t| {
+            format!(
+                "  Bagi kedua ruas dengan a = {}: {} = 0\n",
+                a.tampil(t),
+                suku_kuadrat(Pecahan::SATU, pb, pc, t)
+            )
+        });
+    }
+    let h = pb.bagi(dua)?;
+    let hh = h.kali(h)?;
+    let kanan = hh.kurang(pc)?;
+    steps.tulis(|t| {
+        format!(
+            "  Pindahkan konstanta: {} = {}\n",
+            suku_kuadrat(Pecahan::SATU, pb, Pecahan::NOL, t),
+            (-pc).tampil(t)
+        )
+    });
+    if !h.is_nol() {
+        steps.tulis(|t| {
+            format!(
+                "  Tambahkan (b/2a)² = {}² = {} ke kedua ruas:\n  ({})² = {} + {} = {}\n",
+                kurung(h, t),
+                hh.tampil(t),
+                x_tambah(h, t),
+                (-pc).tampil(t),
+                kurung(hh, t),
+                kanan.tampil(t)
+            )
+        });
+    }
+    if kanan.is_nol() {
+        steps.tulis(|t| format!("  {}\n", faktor_nol(p, t)));
+    } else {
+        if imajiner {
+            steps.tulis(|t| format!(
+                "  Ruas kanan negatif → tidak ada bilangan real yang kuadratnya {}.\n  Dengan i = √-1:\n",
+                kanan.tampil(t)
+            ));
+        }
+        steps.coba_tulis(|t| {
+            Ok(format!(
+                "  {} = ±√{} = {}\n",
+                x_tambah(h, t),
+                kurung(kanan, t),
+                bentuk_akar(Pecahan::NOL, q, r, imajiner, "±", t)?
+            ))
+        })?;
+        if !h.is_nol() {
+            steps.coba_tulis(|t| {
+                Ok(format!(
+                    "  x = {}\n",
+                    bentuk_akar(p, q, r, imajiner, "±", t)?
+                ))
+            })?;
+        }
+    }
+
+    // Langkah 4: rumus abc
+    steps.tulis(|t| {
+        format!(
+            "\nLangkah 4: Rumus abc\n  x = (-b ± √D) / 2a = ({} ± √{}) / {}\n",
+            (-b).tampil(t),
+            kurung(d, t),
+            kurung(dua_a, t)
+        )
+    });
+    if d.is_nol() {
+        steps.tulis(|t| {
+            format!(
+                "  D = 0 → x₁ = x₂ = -b / 2a = {} / {} = {}\n",
+                (-b).tampil(t),
+                kurung(dua_a, t),
+                p.tampil(t)
+            )
+        });
+    } else {
+        let (s, r_d) = sederhana_akar(d.abs())?;
+        steps.coba_tulis(|t| {
+            let mut teks = String::new();
+            let akar_d = bentuk_akar(Pecahan::NOL, s, r_d, imajiner, "+", t)?;
+            if akar_d != format!("√{}", d.tampil(t)) {
+                teks.push_str(&format!("  √{} = {}\n", kurung(d, t), akar_d));
+            }
+            // x₁ = p + q√r; jika a negatif, nilai itu berasal dari (-b - √D) / 2a
+            for (nama, tanda) in [("x₁", "+"), ("x₂", "-")] {
+                let tanda_d = match (tanda, a.is_negatif()) {
+                    ("+", false) | ("-", true) => "+",
+                    _ => "-",
+                };
+                let rumus = format!(
+                    "({} {} {}) / {}",
+                    (-b).tampil(t),
+                    tanda_d,
+                    akar_d,
+                    kurung(dua_a, t)
+                );
+                let hasil = bentuk_akar(p, q, r, imajiner, tanda, t)?;
+                if rumus.replace(") / ", ")/") == hasil {
+                    teks.push_str(&format!("  {} = {}\n", nama, hasil));
+                } else {
+                    teks.push_str(&format!("  {} = {} = {}\n", nama, rumus, hasil));
+                }
+            }
+            Ok(teks)
+        })?;
+    }
+
+    Ok((akar, steps))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::solver::pecahan::uji::p;
+
+    const P: Tampilan = Tampilan::Pecahan;
+
+    #[test]
+    fn akar_disederhanakan() {
+        assert_eq!(sederhana_akar(p(12, 1)).unwrap(), (p(2, 1), 3));
+        assert_eq!(sederhana_akar(p(9, 4)).unwrap(), (p(3, 2), 1));
+        // √(1/2) = √2/2
+        assert_eq!(sederhana_akar(p(1, 2)).unwrap(), (p(1, 2), 2));
+        assert_eq!(sederhana_akar(Pecahan::NOL).unwrap(), (Pecahan::NOL, 1));
+        assert_eq!(
+            bentuk_akar(Pecahan::NOL, p(1, 2), 2, false, "+", P).unwrap(),
+            "√2/2"
+        );
+    }
+
+    #[test]
+    fn faktor_prima_di_atas_batas_percobaan() {
+        // 10007 prima dan lebih besar dari batas pembagian percobaan
+        assert_eq!(
+            sederhana_akar(p(100_140_049, 1)).unwrap(),
+            (p(10_007, 1), 1)
+        );
+        assert_eq!(
+            sederhana_akar(p(2 * 100_140_049, 1)).unwrap(),
+            (p(10_007, 1), 2)
+        );
+
+        let (akar, langkah) = kuadrat_proses(p(1, 1), Pecahan::NOL, p(-100_140_049, 1)).unwrap();
+        assert_eq!(
+            (akar.nilai("+", P).unwrap(), akar.nilai("-", P).unwrap()),
+            ("10007".into(), "-10007".into())
+        );
+        assert!(
+            langkah
+                .pecahan
+                .teks()
+                .contains("(x - 10007)(x + 10007) = 0")
+        );
+
+        // D negatif: x² + 100140049 = 0 → x = ±10007i
+        let (akar, _) = kuadrat_proses(p(1, 1), Pecahan::NOL, p(100_140_049, 1)).unwrap();
+        assert_eq!(akar.nilai("+", P).unwrap(), "10007i ≈ 10007i");
+    }
+
+    #[test]
+    fn akar_rasional_dan_irasional() {
+        let (akar, langkah) = kuadrat_proses(p(1, 1), p(-5, 1), p(6, 1)).unwrap();
+        assert_eq!(
+            (akar.nilai("+", P).unwrap(), akar.nilai("-", P).unwrap()),
+            ("3".into(), "2".into())
+        );
+        assert!(langkah.pecahan.teks().contains("(x - 3)(x - 2) = 0"));
+
+        let (akar, _) = kuadrat_proses(p(1, 1), Pecahan::NOL, p(-2, 1)).unwrap();
+        assert_eq!(akar.nilai("+", P).unwrap(), "√2 ≈ 1.4142");
+        assert_eq!(akar.real().len(), 2);
+    }
+
+    #[test]
+    fn akar_kembar_kompleks_dan_linear() {
+        let (akar, _) = kuadrat_proses(p(1, 1), p(-4, 1), p(4, 1)).unwrap();
+        assert!(akar == AkarKuadrat::Kembar(p(2, 1)));
+
+        let (akar, _) = kuadrat_proses(p(1, 1), p(2, 1), p(5, 1)).unwrap();
+        assert!(matches!(akar, AkarKuadrat::Dua { imajiner: true, .. }));
+        assert!(akar.real().is_empty());
+
+        let (akar, _) = kuadrat_proses(Pecahan::NOL, p(2, 1), p(-1, 1)).unwrap();
+        assert!(akar == AkarKuadrat::Linear(JenisSolusi::Tunggal, Some(p(1, 2))));
+    }
+}
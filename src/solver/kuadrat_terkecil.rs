@@ -0,0 +1,174 @@
+use crate::solver::format::{Teks, kurung};
+use crate::solver::gauss::gauss_proses_berjudul;
+use crate::solver::kuadrat::{bentuk_akar, sederhana_akar};
+use crate::solver::matriks::{Kurung, tulis};
+use crate::solver::pecahan::{Hitung, Pecahan, Tampilan};
+
+/// Penyelesaian kuadrat terkecil beserta vektor sisanya
+pub struct KuadratTerkecil {
+    /// `None` jika kolom-kolom A bergantung linear (tidak tunggal)
+    pub x: Option<Vec<Pecahan>>,
+    /// Vektor sisa r = b − Ax (kosong jika penyelesaiannya tidak tunggal)
+    pub sisa: Vec<Pecahan>,
+}
+
+impl KuadratTerkecil {
+    /// Sistem semula konsisten jika sisanya nol
+    pub fn konsisten(&self) -> bool {
+        self.sisa.iter().all(Pecahan::is_nol)
+    }
+}
+
+// Vektor kolom sebagai matriks n × 1
+fn vektor(v: &[Pecahan]) -> Vec<Vec<Pecahan>> {
+    v.iter().map(|x| vec![*x]).collect()
+}
+
+/// Panjang vektor sisa ‖r‖ dalam bentuk akar sederhana, mis. "√6/3"
+pub fn norma(sisa: &[Pecahan], t: Tampilan) -> Hitung<String> {
+    let kuadrat = Pecahan::jumlah_perkalian(sisa.iter().map(|r| (*r, *r)))?;
+    let (q, r) = sederhana_akar(kuadrat)?;
+    bentuk_akar(Pecahan::NOL, q, r, false, "+", t)
+}
+
+/// Matriks diperbesar persamaan normal [AᵀA | Aᵀb] dari [A | b] dengan
+/// `n` variabel
+pub fn persamaan_normal(m: &[Vec<Pecahan>], n: usize) -> Hitung<Vec<Vec<Pecahan>>> {
+    (0..n)
+        .map(|i| {
+            (0..=n)
+                .map(|j| Pecahan::jumlah_perkalian(m.iter().map(|r| (r[i], r[j]))))
+                .collect()
+        })
+        .collect()
+}
+
+//
+// Kuadrat Terkecil (Least Squares)
+// Sistem Ax = b dengan m persamaan dan n variabel (biasanya m > n) yang
+// mungkin tidak konsisten. x dipilih agar ‖b − Ax‖ minimum, yaitu
+// penyelesaian persamaan normal AᵀA x = Aᵀb. Sisa r = b − Ax bernilai
+// nol tepat ketika sistem semula konsisten.
+//
+pub fn kuadrat_terkecil_proses(
+    m: &[Vec<Pecahan>],
+    nama: &[String],
+) -> Hitung<(KuadratTerkecil, Teks)> {
+    let n = nama.len();
+    let a: Vec<Vec<Pecahan>> = m.iter().map(|b| b[..n].to_vec()).collect();
+    let b: Vec<Pecahan> = m.iter().map(|b| b[n]).collect();
+
+    let mut steps = Teks::dari(|_| {
+        format!(
+            "Sistem Ax = b dengan {} persamaan dan {} variabel:\n",
+            m.len(),
+            n
+        )
+    });
+    steps.gambar(|t| tulis("A = ", &a, Kurung::Siku, "", t));
+    steps.gambar(|t| tulis("b = ", &vektor(&b), Kurung::Siku, "", t));
+
+    // Persamaan normal AᵀA x = Aᵀb
+    let normal = persamaan_normal(m, n)?;
+    let ata: Vec<Vec<Pecahan>> = normal.iter().map(|r| r[..n].to_vec()).collect();
+    let atb: Vec<Pecahan> = normal.iter().map(|r| r[n]).collect();
+    steps.push_str(
+        "\nPenyelesaian kuadrat terkecil meminimumkan ‖b − Ax‖ dan memenuhi\n\
+         persamaan normal AᵀA x = Aᵀb:\n",
+    );
+    steps.gambar(|t| tulis("AᵀA = ", &ata, Kurung::Siku, "", t));
+    steps.gambar(|t| tulis("Aᵀb = ", &vektor(&atb), Kurung::Siku, "", t));
+
+    let (_, x, _, langkah) = gauss_proses_berjudul(
+        normal,
+        nama,
+        "Matriks diperbesar persamaan normal [AᵀA | Aᵀb]",
+    )?;
+    steps.push_str("\nPersamaan normal diselesaikan dengan eliminasi Gauss.\n");
+    steps.sambung(&langkah);
+
+    let Some(x) = x else {
+        steps.push_str(
+            "\nKolom-kolom A bergantung linear sehingga AᵀA singular;\n\
+             penyelesaian kuadrat terkecil tidak tunggal.\n",
+        );
+        let hasil = KuadratTerkecil {
+            x: None,
+            sisa: Vec::new(),
+        };
+        return Ok((hasil, steps));
+    };
+
+    // Sisa r = b − Ax
+    let ax: Vec<Pecahan> = a
+        .iter()
+        .map(|r| Pecahan::jumlah_perkalian(r.iter().copied().zip(x.iter().copied())))
+        .collect::<Hitung<_>>()?;
+    let sisa: Vec<Pecahan> = b
+        .iter()
+        .zip(&ax)
+        .map(|(p, q)| p.kurang(*q))
+        .collect::<Hitung<_>>()?;
+    steps.push_str("\nVektor sisa r = b − Ax:\n");
+    steps.gambar(|t| tulis("Ax = ", &vektor(&ax), Kurung::Siku, "", t));
+    steps.gambar(|t| tulis("r = ", &vektor(&sisa), Kurung::Siku, "", t));
+    let kuadrat = Pecahan::jumlah_perkalian(sisa.iter().map(|r| (*r, *r)))?;
+    steps.coba_tulis(|t| {
+        let suku: Vec<String> = sisa.iter().map(|r| format!("{}²", kurung(*r, t))).collect();
+        Ok(format!(
+            "  ‖r‖² = {} = {}\n  ‖r‖ = {}\n",
+            suku.join(" + "),
+            kuadrat.tampil(t),
+            norma(&sisa, t)?
+        ))
+    })?;
+
+    let hasil = KuadratTerkecil { x: Some(x), sisa };
+    steps.push_str(if hasil.konsisten() {
+        "\nr = 0 → sistem konsisten, x adalah penyelesaian eksak.\n"
+    } else {
+        "\nr ≠ 0 → sistem tidak konsisten, x adalah penyelesaian hampiran terbaik\n\
+         (kuadrat terkecil).\n"
+    });
+    Ok((hasil, steps))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::solver::pecahan::uji::{matriks, nama};
+
+    const P: Tampilan = Tampilan::Pecahan;
+
+    #[test]
+    fn sistem_tidak_konsisten() {
+        // Garis terbaik melalui (1, 2), (2, 3), (3, 5), (4, 6)
+        let m = matriks(&[&[1, 1, 2], &[1, 2, 3], &[1, 3, 5], &[1, 4, 6]]);
+        assert_eq!(
+            persamaan_normal(&m, 2).unwrap(),
+            matriks(&[&[4, 10, 16], &[10, 30, 47]])
+        );
+        let (hasil, langkah) = kuadrat_terkecil_proses(&m, &nama(2)).unwrap();
+        assert_eq!(hasil.x, Some(vec![Pecahan::new(1, 2), Pecahan::new(7, 5)]));
+        assert!(!hasil.konsisten());
+        assert_eq!(norma(&hasil.sisa, P), Ok("√5/5".into()));
+        assert!(
+            langkah
+                .pecahan
+                .teks()
+                .contains("Matriks diperbesar persamaan normal [AᵀA | Aᵀb]:")
+        );
+    }
+
+    #[test]
+    fn sistem_konsisten_dan_kolom_bergantung() {
+        let m = matriks(&[&[1, 0, 1], &[0, 1, 2], &[1, 1, 3]]);
+        let (hasil, _) = kuadrat_terkecil_proses(&m, &nama(2)).unwrap();
+        assert_eq!(hasil.x, Some(vec![Pecahan::from(1), Pecahan::from(2)]));
+        assert!(hasil.konsisten());
+
+        let m = matriks(&[&[1, 2, 1], &[2, 4, 2], &[3, 6, 4]]);
+        let (hasil, _) = kuadrat_terkecil_proses(&m, &nama(2)).unwrap();
+        assert_eq!(hasil.x, None);
+    }
+}
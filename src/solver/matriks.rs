@@ -0,0 +1,283 @@
+use crate::solver::format::{Teks, kurung};
+use crate::solver::pecahan::{Hitung, Pecahan, Tampilan};
+
+/// Pengapit matriks: kurung siku untuk matriks, garis tegak untuk determinan
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum Kurung {
+    Siku,
+    Tegak,
+}
+
+/// Matriks atau determinan pada langkah penyelesaian. Isi selnya sudah
+/// berupa teks; kurung dan perataan kolom digambar oleh widget di layar hasil.
+#[derive(Clone, Debug, PartialEq)]
+pub struct GambarMatriks {
+    /// Label di kiri baris tengah, mis. "D = "
+    pub kiri: String,
+    pub sel: Vec<Vec<String>>,
+    pub kurung: Kurung,
+    /// Banyak kolom sebelum garis pemisah ruas kanan (matriks diperbesar)
+    pub pemisah: Option<usize>,
+    /// Kolom yang ditulis ulang di luar pengapit, mis. pada aturan Sarrus
+    pub tambahan: Vec<Vec<String>>,
+    /// Keterangan di kanan baris tengah, mis. " = -5"
+    pub kanan: String,
+}
+
+fn sel(a: &[Vec<Pecahan>], t: Tampilan) -> Vec<Vec<String>> {
+    a.iter()
+        .map(|b| b.iter().map(|v| v.tampil(t).to_string()).collect())
+        .collect()
+}
+
+/// Matriks (`Kurung::Siku`) atau determinan (`Kurung::Tegak`) dengan label
+/// di kiri dan keterangan di kanan, mis. `tulis("A = ", &a, Kurung::Siku, "", t)`
+pub fn tulis(
+    kiri: &str,
+    a: &[Vec<Pecahan>],
+    jenis: Kurung,
+    kanan: &str,
+    t: Tampilan,
+) -> GambarMatriks {
+    tulis_sel(kiri, &sel(a, t), jenis, kanan)
+}
+
+/// Seperti [`tulis`], tetapi isi selnya berupa teks, mis. "2 + 1"
+pub fn tulis_sel(kiri: &str, sel: &[Vec<String>], jenis: Kurung, kanan: &str) -> GambarMatriks {
+    GambarMatriks {
+        kiri: kiri.to_string(),
+        sel: sel.to_vec(),
+        kurung: jenis,
+        pemisah: None,
+        tambahan: Vec::new(),
+        kanan: kanan.to_string(),
+    }
+}
+
+/// Matriks dengan garis pemisah setelah `kolom` kolom pertama,
+/// mis. [A | I] pada perhitungan invers
+pub fn terpisah(m: &[Vec<Pecahan>], kolom: usize, t: Tampilan) -> GambarMatriks {
+    GambarMatriks {
+        pemisah: Some(kolom),
+        ..tulis("", m, Kurung::Siku, "", t)
+    }
+}
+
+/// Matriks diperbesar [A | b] dengan garis pemisah ruas kanan
+pub fn diperbesar(m: &[Vec<Pecahan>], t: Tampilan) -> GambarMatriks {
+    let n = m.first().map_or(1, Vec::len);
+    terpisah(m, n - 1, t)
+}
+
+/// Determinan matriks persegi dengan ekspansi kofaktor baris pertama
+pub fn determinan(a: &[Vec<Pecahan>]) -> Hitung<Pecahan> {
+    match a.len() {
+        0 => Ok(Pecahan::SATU),
+        1 => Ok(a[0][0]),
+        n => {
+            let mut d = Pecahan::NOL;
+            for j in 0..n {
+                let kofaktor = tanda(j).kali(determinan(&minor(a, 0, j))?)?;
+                d = d.tambah(a[0][j].kali(kofaktor)?)?;
+            }
+            Ok(d)
+        }
+    }
+}
+
+// (-1)^j
+fn tanda(j: usize) -> Pecahan {
+    if j.is_multiple_of(2) {
+        Pecahan::SATU
+    } else {
+        -Pecahan::SATU
+    }
+}
+
+/// Matriks tanpa baris ke-i dan kolom ke-j
+pub fn minor(a: &[Vec<Pecahan>], i: usize, j: usize) -> Vec<Vec<Pecahan>> {
+    a.iter()
+        .enumerate()
+        .filter(|(r, _)| *r != i)
+        .map(|(_, b)| {
+            b.iter()
+                .enumerate()
+                .filter(|(c, _)| *c != j)
+                .map(|(_, v)| *v)
+                .collect()
+        })
+        .collect()
+}
+
+// Hasil kali beberapa bilangan, mis. "2·(-1)·3"
+fn kali(faktor: &[Pecahan], t: Tampilan) -> String {
+    faktor
+        .iter()
+        .map(|v| kurung(*v, t))
+        .collect::<Vec<_>>()
+        .join("·")
+}
+
+// Nilai setiap hasil kali beserta jumlahnya
+fn nilai_kali(suku: &[Vec<Pecahan>]) -> Hitung<(Vec<Pecahan>, Pecahan)> {
+    let hasil = suku
+        .iter()
+        .map(|f| Pecahan::hasil_kali(f.iter().copied()))
+        .collect::<Hitung<Vec<_>>>()?;
+    let jumlah = Pecahan::jumlah(hasil.iter().copied())?;
+    Ok((hasil, jumlah))
+}
+
+// Penjumlahan hasil kali beserta nilainya, mis. "2·1·3 + (-1)·4·2 = 6 + (-8)"
+fn jumlah_kali(suku: &[Vec<Pecahan>], hasil: &[Pecahan], t: Tampilan) -> String {
+    let rumus: Vec<String> = suku.iter().map(|f| kali(f, t)).collect();
+    let nilai: Vec<String> = hasil.iter().map(|v| kurung(*v, t)).collect();
+    format!("{} = {}", rumus.join(" + "), nilai.join(" + "))
+}
+
+/// Gambar determinan `label` beserta langkah perhitungannya: rumus silang
+/// untuk 2 × 2, aturan Sarrus untuk 3 × 3, dan ekspansi kofaktor baris
+/// pertama untuk ukuran yang lebih besar
+pub fn langkah_determinan(label: &str, a: &[Vec<Pecahan>]) -> Hitung<Teks> {
+    let n = a.len();
+    let d = determinan(a)?;
+    let label_kiri = format!("{} = ", label);
+    let mut steps = Teks::default();
+    match n {
+        0 | 1 => steps.gambar(|t| {
+            tulis(
+                &label_kiri,
+                a,
+                Kurung::Tegak,
+                &format!(" = {}", d.tampil(t)),
+                t,
+            )
+        }),
+        2 => {
+            steps.gambar(|t| tulis(&label_kiri, a, Kurung::Tegak, "", t));
+            let (p, q) = (a[0][0].kali(a[1][1])?, a[0][1].kali(a[1][0])?);
+            steps.tulis(|t| {
+                format!(
+                    "  {} = {} − {} = {} − {} = {}\n",
+                    label,
+                    kali(&[a[0][0], a[1][1]], t),
+                    kali(&[a[0][1], a[1][0]], t),
+                    p.tampil(t),
+                    kurung(q, t),
+                    d.tampil(t)
+                )
+            });
+        }
+        3 => {
+            // Dua kolom pertama ditulis ulang di sebelah kanan determinan
+            steps.push_str("  Aturan Sarrus (dua kolom pertama ditulis ulang):\n");
+            steps.gambar(|t| {
+                let isi = sel(a, t);
+                GambarMatriks {
+                    tambahan: isi.iter().map(|b| b[..2].to_vec()).collect(),
+                    ..tulis_sel(&label_kiri, &isi, Kurung::Tegak, "")
+                }
+            });
+
+            let turun: Vec<Vec<Pecahan>> = (0..3)
+                .map(|j| (0..3).map(|i| a[i][(i + j) % 3]).collect())
+                .collect();
+            let naik: Vec<Vec<Pecahan>> = (0..3)
+                .map(|j| (0..3).map(|i| a[2 - i][(i + j) % 3]).collect())
+                .collect();
+            let (hasil_turun, s1) = nilai_kali(&turun)?;
+            let (hasil_naik, s2) = nilai_kali(&naik)?;
+            steps.tulis(|t| {
+                format!(
+                    "  Diagonal turun: {} = {}\n  Diagonal naik : {} = {}\n  {} = {} − {} = {}\n",
+                    jumlah_kali(&turun, &hasil_turun, t),
+                    s1.tampil(t),
+                    jumlah_kali(&naik, &hasil_naik, t),
+                    s2.tampil(t),
+                    label,
+                    s1.tampil(t),
+                    kurung(s2, t),
+                    d.tampil(t)
+                )
+            });
+        }
+        _ => {
+            steps.gambar(|t| tulis(&label_kiri, a, Kurung::Tegak, "", t));
+            steps.push_str("  Ekspansi kofaktor baris pertama:\n");
+            let minor = (0..n)
+                .map(|j| determinan(&minor(a, 0, j)))
+                .collect::<Hitung<Vec<_>>>()?;
+            steps.tulis(|t| {
+                let mut teks = String::new();
+                let mut suku = Vec::new();
+                for (j, m) in minor.iter().enumerate() {
+                    teks.push_str(&format!("    M1{} = {}\n", j + 1, m.tampil(t)));
+                    let kali = format!("{}·{}", kurung(a[0][j], t), kurung(*m, t));
+                    suku.push(if j == 0 {
+                        kali
+                    } else if j.is_multiple_of(2) {
+                        format!(" + {}", kali)
+                    } else {
+                        format!(" − {}", kali)
+                    });
+                }
+                teks.push_str(&format!(
+                    "  {} = {} = {}\n",
+                    label,
+                    suku.concat(),
+                    d.tampil(t)
+                ));
+                teks
+            });
+        }
+    }
+    Ok(steps)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::solver::format::Blok;
+    use crate::solver::pecahan::uji::matriks;
+
+    #[test]
+    fn determinan_ekspansi_kofaktor() {
+        assert_eq!(
+            determinan(&matriks(&[&[3, 8], &[4, 6]])),
+            Ok(Pecahan::from(-14))
+        );
+        assert_eq!(
+            determinan(&matriks(&[&[6, 1, 1], &[4, -2, 5], &[2, 8, 7]])),
+            Ok(Pecahan::from(-306))
+        );
+        // Dua baris sama → singular
+        let a = matriks(&[&[1, 2, 3, 4], &[0, 1, 0, 2], &[1, 2, 3, 4], &[5, 0, 1, 1]]);
+        assert_eq!(determinan(&a), Ok(Pecahan::NOL));
+    }
+
+    #[test]
+    fn minor_menghapus_baris_dan_kolom() {
+        let a = matriks(&[&[1, 2, 3], &[4, 5, 6], &[7, 8, 9]]);
+        assert_eq!(minor(&a, 1, 0), matriks(&[&[2, 3], &[8, 9]]));
+    }
+
+    #[test]
+    fn langkah_determinan_memakai_sarrus() {
+        let a = matriks(&[&[1, 2, 0], &[0, 1, 0], &[0, 0, 2]]);
+        let langkah = langkah_determinan("det(A)", &a).unwrap();
+        let teks = langkah.pecahan.teks();
+        assert!(teks.contains("Sarrus"));
+        assert!(teks.ends_with("= 2\n"));
+        // Gambar determinan disertai dua kolom pertama di luar garis tegak
+        let gambar = langkah.pecahan.blok().iter().find_map(|b| match b {
+            Blok::Matriks(g) => Some(g),
+            _ => None,
+        });
+        let gambar = gambar.expect("determinan digambar");
+        assert_eq!(gambar.kurung, Kurung::Tegak);
+        assert_eq!(
+            gambar.tambahan,
+            vec![vec!["1", "2"], vec!["0", "1"], vec!["0", "0"]]
+        );
+    }
+}
@@ -0,0 +1,580 @@
+use crate::solver::format::{Teks, bagi, kurung, persamaan};
+use crate::solver::matriks::{Kurung, determinan, langkah_determinan, minor, tulis};
+use crate::solver::pecahan::{Hitung, Pecahan, Tampilan};
+
+/// Metode penyelesaian yang bisa dipilih di layar input SPLDV dan SPLTV
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum Metode {
+    Campuran,
+    Eliminasi,
+    Substitusi,
+    Grafik,
+    Cramer,
+    Invers,
+}
+
+impl Metode {
+    pub const SPLDV: [Metode; 6] = [
+        Metode::Campuran,
+        Metode::Eliminasi,
+        Metode::Substitusi,
+        Metode::Grafik,
+        Metode::Cramer,
+        Metode::Invers,
+    ];
+    pub const SPLTV: [Metode; 5] = [
+        Metode::Campuran,
+        Metode::Eliminasi,
+        Metode::Substitusi,
+        Metode::Cramer,
+        Metode::Invers,
+    ];
+
+    pub fn nama(self) -> &'static str {
+        match self {
+            Metode::Campuran => "Campuran (Eliminasi–Substitusi)",
+            Metode::Eliminasi => "Eliminasi",
+            Metode::Substitusi => "Substitusi",
+            Metode::Grafik => "Grafik",
+            Metode::Cramer => "Determinan (Cramer)",
+            Metode::Invers => "Invers Matriks",
+        }
+    }
+
+    /// Metode berikutnya dalam `daftar`, atau sebelumnya jika `mundur`
+    pub fn geser(self, daftar: &[Metode], mundur: bool) -> Metode {
+        let n = daftar.len();
+        let i = daftar.iter().position(|&m| m == self).unwrap_or(0);
+        daftar[if mundur { (i + n - 1) % n } else { (i + 1) % n }]
+    }
+}
+
+// Sistem persamaan berlabel P1, P2, ... dengan variabel `var`
+// (indeks ke daftar nama global). Setiap baris berisi koefisien lalu ruas kanan.
+#[derive(Clone)]
+struct Sistem {
+    var: Vec<usize>,
+    baris: Vec<(usize, Vec<Pecahan>)>,
+}
+
+impl Sistem {
+    fn tulis(&self, i: usize, nama: &[&str], t: Tampilan) -> String {
+        let (label, k) = &self.baris[i];
+        let suku: Vec<(Pecahan, &str)> = self
+            .var
+            .iter()
+            .map(|&v| nama[v])
+            .zip(k)
+            .map(|(v, k)| (*k, v))
+            .collect();
+        format!("P{}: {}", label, persamaan(&suku, k[self.var.len()], t))
+    }
+
+    // Baris acuan untuk variabel ke-j: koefisien dengan nilai mutlak terbesar,
+    // atau koefisien ±1 lebih dulu jika `utamakan_satu` (untuk substitusi)
+    fn acuan(&self, j: usize, utamakan_satu: bool) -> usize {
+        let skor = |k: Pecahan| (utamakan_satu && k.abs() == Pecahan::SATU, k.abs());
+        let mut terbaik = 0;
+        for (i, (_, k)) in self.baris.iter().enumerate() {
+            if skor(k[j]) > skor(self.baris[terbaik].1[j]) {
+                terbaik = i;
+            }
+        }
+        terbaik
+    }
+}
+
+fn sistem_awal(m: &[Vec<Pecahan>]) -> Sistem {
+    Sistem {
+        var: (0..m.len()).collect(),
+        baris: m
+            .iter()
+            .cloned()
+            .enumerate()
+            .map(|(i, b)| (i + 1, b))
+            .collect(),
+    }
+}
+
+// Mengeliminasi variabel ke-j: setiap baris lain dikurangi kelipatan baris acuan,
+// lalu kolom variabel tersebut dibuang. Mengembalikan sistem baru dan baris acuan.
+fn eliminasi_variabel(
+    s: &Sistem,
+    j: usize,
+    nama: &[&str],
+    label: &mut usize,
+    steps: &mut Teks,
+) -> Hitung<(Sistem, (usize, Vec<Pecahan>))> {
+    let r = s.acuan(j, false);
+    let (lr, acuan) = &s.baris[r];
+    let mut var = s.var.clone();
+    var.remove(j);
+    let mut baru = Sistem {
+        var,
+        baris: Vec::new(),
+    };
+    for (li, k) in s.baris.iter().filter(|(l, _)| l != lr) {
+        if k[j].is_nol() {
+            steps.tulis(|_| format!("  P{} tidak memuat {}\n", li, nama[s.var[j]]));
+            let mut b = k.clone();
+            b.remove(j);
+            baru.baris.push((*li, b));
+            continue;
+        }
+        let (ka, ki) = (acuan[j], k[j]);
+        let mut b: Vec<Pecahan> = k
+            .iter()
+            .zip(acuan)
+            .map(|(v, a)| v.kali(ka)?.kurang(a.kali(ki)?))
+            .collect::<Hitung<_>>()?;
+        b.remove(j);
+        *label += 1;
+        baru.baris.push((*label, b));
+        steps.tulis(|t| {
+            format!(
+                "  P{} × {} − P{} × {}:\n    {}\n",
+                li,
+                kurung(ka, t),
+                lr,
+                kurung(ki, t),
+                baru.tulis(baru.baris.len() - 1, nama, t)
+            )
+        });
+    }
+    Ok((baru, (*lr, acuan.clone())))
+}
+
+// Substitusi balik pada baris `k` (variabel `var`) untuk variabel ke-j,
+// memakai nilai variabel lain yang sudah diketahui
+fn substitusi_balik(
+    var: &[usize],
+    k: &[Pecahan],
+    j: usize,
+    nilai: &[Option<Pecahan>],
+    nama: &[&str],
+) -> Hitung<(Pecahan, Teks)> {
+    let n = var.len();
+    let lain: Vec<(Pecahan, Pecahan)> = (0..n)
+        .filter(|&i| i != j && !k[i].is_nol())
+        .map(|i| (k[i], nilai[var[i]].unwrap_or(Pecahan::NOL)))
+        .collect();
+    let sisa = k[n].kurang(Pecahan::jumlah_perkalian(lain.iter().copied())?)?;
+    let x = sisa.bagi(k[j])?;
+    let pembilang = |t| {
+        if lain.is_empty() {
+            k[n].tampil(t).to_string()
+        } else {
+            let suku: Vec<String> = lain
+                .iter()
+                .map(|(a, v)| {
+                    if *a == Pecahan::SATU {
+                        format!(" − {}", kurung(*v, t))
+                    } else {
+                        format!(" − {} × {}", kurung(*a, t), kurung(*v, t))
+                    }
+                })
+                .collect();
+            if k[j] == Pecahan::SATU {
+                format!("{}{}", k[n].tampil(t), suku.concat())
+            } else {
+                format!("({}{})", k[n].tampil(t), suku.concat())
+            }
+        }
+    };
+    let teks = Teks::dari(|t| {
+        if k[j] == Pecahan::SATU {
+            format!("  {} = {} = {}\n", nama[var[j]], pembilang(t), x.tampil(t))
+        } else {
+            format!(
+                "  {} = {} / {} = {}\n",
+                nama[var[j]],
+                pembilang(t),
+                kurung(k[j], t),
+                x.tampil(t)
+            )
+        }
+    });
+    Ok((x, teks))
+}
+
+fn persamaan_awal(m: &[Vec<Pecahan>], nama: &[&str]) -> Teks {
+    let s = sistem_awal(m);
+    let mut steps = Teks::from("Persamaan:\n");
+    for i in 0..m.len() {
+        steps.tulis(|t| format!("  {}\n", s.tulis(i, nama, t)));
+    }
+    steps
+}
+
+/// Menyelesaikan sistem n × n yang determinannya tidak nol dengan metode
+/// pilihan. `m` adalah matriks diperbesar [A | b].
+pub fn selesaikan(
+    m: &[Vec<Pecahan>],
+    nama: &[&str],
+    metode: Metode,
+) -> Hitung<(Vec<Pecahan>, Teks)> {
+    let mut steps = Teks::dari(|_| format!("Metode: {}\n\n", metode.nama()));
+    steps.sambung(&persamaan_awal(m, nama));
+    let (x, langkah) = match metode {
+        Metode::Eliminasi => eliminasi(m, nama),
+        Metode::Substitusi => substitusi(m, nama),
+        Metode::Cramer => cramer(m, nama),
+        Metode::Invers => invers(m, nama),
+        // Grafik hanya tersedia untuk dua variabel dan ditangani oleh SPLDV
+        Metode::Campuran | Metode::Grafik => campuran(m, nama),
+    }?;
+    steps.sambung(&langkah);
+    Ok((x, steps))
+}
+
+// Eliminasi berurutan sampai tersisa satu variabel, lalu substitusi balik
+// melalui persamaan acuan setiap langkah
+fn campuran(m: &[Vec<Pecahan>], nama: &[&str]) -> Hitung<(Vec<Pecahan>, Teks)> {
+    let n = m.len();
+    let mut steps = Teks::default();
+    let mut label = n;
+    let mut s = sistem_awal(m);
+    let mut acuan = Vec::new();
+    for langkah in 1..n {
+        steps.tulis(|_| {
+            format!(
+                "\nLangkah {}: Eliminasi {} dengan acuan P{}\n",
+                langkah,
+                nama[s.var[0]],
+                s.baris[s.acuan(0, false)].0
+            )
+        });
+        let (baru, a) = eliminasi_variabel(&s, 0, nama, &mut label, &mut steps)?;
+        acuan.push((s.var.clone(), a));
+        s = baru;
+    }
+
+    let mut nilai = vec![None; n];
+    let (terakhir, k) = (s.var[0], &s.baris[0].1);
+    let (v, teks) = substitusi_balik(&s.var, k, 0, &nilai, nama)?;
+    steps.sambung(&teks);
+    nilai[terakhir] = Some(v);
+
+    for (langkah, (var, (l, k))) in acuan.iter().rev().enumerate() {
+        let diketahui: Vec<&str> = var[1..].iter().map(|&v| nama[v]).collect();
+        steps.tulis(|_| {
+            format!(
+                "\nLangkah {}: Substitusi {} ke P{}\n",
+                n + langkah,
+                diketahui.join(" dan "),
+                l
+            )
+        });
+        let (v, teks) = substitusi_balik(var, k, 0, &nilai, nama)?;
+        steps.sambung(&teks);
+        nilai[var[0]] = Some(v);
+    }
+    Ok((
+        nilai
+            .into_iter()
+            .map(|v| v.unwrap_or(Pecahan::NOL))
+            .collect(),
+        steps,
+    ))
+}
+
+// Setiap variabel dicari dengan mengeliminasi semua variabel lainnya.
+// Hasil eliminasi yang sama dengan pencarian sebelumnya dipakai ulang.
+fn eliminasi(m: &[Vec<Pecahan>], nama: &[&str]) -> Hitung<(Vec<Pecahan>, Teks)> {
+    let n = m.len();
+    let mut steps = Teks::default();
+    let mut label = n;
+    let mut x = vec![Pecahan::NOL; n];
+    let mut sebelumnya: Vec<(Vec<usize>, Sistem)> = Vec::new();
+    for (i, x_i) in x.iter_mut().enumerate() {
+        let urutan: Vec<usize> = (0..n).filter(|&v| v != i).collect();
+        let lain: Vec<&str> = urutan.iter().map(|&v| nama[v]).collect();
+        steps.tulis(|_| {
+            format!(
+                "\nMencari {}: eliminasi {}\n",
+                nama[i],
+                lain.join(", lalu ")
+            )
+        });
+        let mut s = sistem_awal(m);
+        for langkah in 1..n {
+            let dieliminasi = &urutan[..langkah];
+            if let Some((_, lama)) = sebelumnya.iter().find(|(e, _)| e == dieliminasi) {
+                let label_lama: Vec<String> =
+                    lama.baris.iter().map(|(l, _)| format!("P{}", l)).collect();
+                steps.tulis(|_| {
+                    format!(
+                        "  Eliminasi {} sudah dilakukan: {}\n",
+                        nama[urutan[langkah - 1]],
+                        label_lama.join(", ")
+                    )
+                });
+                s = lama.clone();
+                continue;
+            }
+            let j = s
+                .var
+                .iter()
+                .position(|&v| v == urutan[langkah - 1])
+                .unwrap_or(0);
+            s = eliminasi_variabel(&s, j, nama, &mut label, &mut steps)?.0;
+            sebelumnya.push((dieliminasi.to_vec(), s.clone()));
+        }
+        let (v, teks) = substitusi_balik(&s.var, &s.baris[0].1, 0, &[], nama)?;
+        steps.sambung(&teks);
+        *x_i = v;
+    }
+    Ok((x, steps))
+}
+
+// Nyatakan satu variabel dalam variabel lain, substitusikan ke persamaan
+// lainnya, ulangi, lalu substitusi balik
+fn substitusi(m: &[Vec<Pecahan>], nama: &[&str]) -> Hitung<(Vec<Pecahan>, Teks)> {
+    let n = m.len();
+    let mut steps = Teks::default();
+    let mut label = n;
+    let mut s = sistem_awal(m);
+    let mut rumus = Vec::new();
+    for langkah in 1..n {
+        let r = s.acuan(0, true);
+        let (lr, k) = s.baris[r].clone();
+        let v = s.var[0];
+        let suku: Vec<(Pecahan, &str)> =
+            (1..s.var.len()).map(|i| (-k[i], nama[s.var[i]])).collect();
+        steps.coba_tulis(|t| {
+            Ok(format!(
+                "\nLangkah {}: Dari P{}, nyatakan {} dalam variabel lain\n  {} = {}\n",
+                langkah,
+                lr,
+                nama[v],
+                nama[v],
+                bagi(k[s.var.len()], &suku, k[0], t)?
+            ))
+        })?;
+
+        let mut baru = Sistem {
+            var: s.var[1..].to_vec(),
+            baris: Vec::new(),
+        };
+        for (li, b) in s.baris.iter().filter(|(l, _)| *l != lr) {
+            let f = b[0].bagi(k[0])?;
+            let hasil: Vec<Pecahan> = b
+                .iter()
+                .zip(&k)
+                .skip(1)
+                .map(|(p, q)| p.kurang(f.kali(*q)?))
+                .collect::<Hitung<_>>()?;
+            if b[0].is_nol() {
+                baru.baris.push((*li, hasil));
+                steps.tulis(|_| format!("  P{} tidak memuat {}\n", li, nama[v]));
+                continue;
+            }
+            label += 1;
+            baru.baris.push((label, hasil));
+            steps.tulis(|t| {
+                format!(
+                    "  Substitusi ke P{}:\n    {}\n",
+                    li,
+                    baru.tulis(baru.baris.len() - 1, nama, t)
+                )
+            });
+        }
+        rumus.push((s.var.clone(), lr, k));
+        s = baru;
+    }
+
+    steps.tulis(|t| format!("\nLangkah {}: Selesaikan {}\n", n, s.tulis(0, nama, t)));
+    let mut nilai = vec![None; n];
+    let (v, teks) = substitusi_balik(&s.var, &s.baris[0].1, 0, &nilai, nama)?;
+    steps.sambung(&teks);
+    nilai[s.var[0]] = Some(v);
+
+    steps.push_str("\nSubstitusi balik:\n");
+    for (var, _, k) in rumus.iter().rev() {
+        // v = c/a − Σ (b/a)·x dengan nilai x yang sudah diketahui
+        let a = k[0];
+        let konstanta = k[var.len()].bagi(a)?;
+        let mut suku: Vec<(Pecahan, Pecahan)> = Vec::new();
+        for (i, &x) in var.iter().enumerate().skip(1) {
+            let f = (-k[i]).bagi(a)?;
+            if !f.is_nol() {
+                suku.push((f, nilai[x].unwrap_or(Pecahan::NOL)));
+            }
+        }
+        let v = konstanta.tambah(Pecahan::jumlah_perkalian(suku.iter().copied())?)?;
+        let teks = |t| {
+            let mut teks = konstanta.tampil(t).to_string();
+            for &(f, nilai_x) in &suku {
+                let tanda = if f.is_negatif() { "−" } else { "+" };
+                if f.abs() == Pecahan::SATU {
+                    teks.push_str(&format!(" {} {}", tanda, kurung(nilai_x, t)));
+                } else {
+                    teks.push_str(&format!(
+                        " {} {} × {}",
+                        tanda,
+                        kurung(f.abs(), t),
+                        kurung(nilai_x, t)
+                    ));
+                }
+            }
+            // Konstanta nol tidak perlu ditulis di depan suku lain
+            match teks.strip_prefix("0 ") {
+                Some(sisa) if konstanta.is_nol() => {
+                    sisa.strip_prefix("+ ").unwrap_or(sisa).to_string()
+                }
+                _ => teks,
+            }
+        };
+        steps.tulis(|t| format!("  {} = {} = {}\n", nama[var[0]], teks(t), v.tampil(t)));
+        nilai[var[0]] = Some(v);
+    }
+    Ok((
+        nilai
+            .into_iter()
+            .map(|v| v.unwrap_or(Pecahan::NOL))
+            .collect(),
+        steps,
+    ))
+}
+
+fn koefisien(m: &[Vec<Pecahan>]) -> Vec<Vec<Pecahan>> {
+    m.iter().map(|b| b[..m.len()].to_vec()).collect()
+}
+
+// Aturan Cramer: x_j = D_j / D, dengan D_j determinan matriks koefisien
+// yang kolom ke-j diganti ruas kanan
+fn cramer(m: &[Vec<Pecahan>], nama: &[&str]) -> Hitung<(Vec<Pecahan>, Teks)> {
+    let n = m.len();
+    let a = koefisien(m);
+    let d = determinan(&a)?;
+    let mut steps = Teks::from("\nD (determinan matriks koefisien):\n");
+    steps.sambung(&langkah_determinan("D", &a)?);
+    let (mut x, mut dd) = (Vec::new(), Vec::new());
+    for (j, v) in nama.iter().enumerate().take(n) {
+        let aj: Vec<Vec<Pecahan>> = m
+            .iter()
+            .map(|b| (0..n).map(|c| if c == j { b[n] } else { b[c] }).collect())
+            .collect();
+        let dj = determinan(&aj)?;
+        steps.push_str(&format!("\nD{} (kolom {} diganti ruas kanan):\n", v, v));
+        steps.sambung(&langkah_determinan(&format!("D{}", v), &aj)?);
+        dd.push(dj);
+        x.push(dj.bagi(d)?);
+    }
+    steps.push('\n');
+    for (j, (v, dj)) in x.iter().zip(&dd).enumerate() {
+        steps.tulis(|t| {
+            format!(
+                "  {} = D{} / D = {} / {} = {}\n",
+                nama[j],
+                nama[j],
+                kurung(*dj, t),
+                kurung(d, t),
+                v.tampil(t)
+            )
+        });
+    }
+    Ok((x, steps))
+}
+
+// X = A⁻¹·B dengan A⁻¹ = adj(A) / det(A)
+fn invers(m: &[Vec<Pecahan>], nama: &[&str]) -> Hitung<(Vec<Pecahan>, Teks)> {
+    let n = m.len();
+    let a = koefisien(m);
+    let b: Vec<Pecahan> = m.iter().map(|r| r[n]).collect();
+    let d = determinan(&a)?;
+    let mut steps = Teks::from("\nBentuk matriks A·X = B dengan\n");
+    steps.gambar(|t| tulis("A = ", &a, Kurung::Siku, "", t));
+    steps.push('\n');
+    steps.gambar(|t| {
+        tulis(
+            "B = ",
+            &b.iter().map(|v| vec![*v]).collect::<Vec<_>>(),
+            Kurung::Siku,
+            "",
+            t,
+        )
+    });
+    steps.tulis(|t| format!("\ndet(A) = {} ≠ 0 → A memiliki invers.\n", d.tampil(t)));
+
+    // Adjoin: transpos matriks kofaktor
+    let adj: Vec<Vec<Pecahan>> = (0..n)
+        .map(|i| {
+            (0..n)
+                .map(|j| {
+                    let tanda = if (i + j) % 2 == 0 {
+                        Pecahan::SATU
+                    } else {
+                        -Pecahan::SATU
+                    };
+                    if n == 1 {
+                        Ok(Pecahan::SATU)
+                    } else {
+                        tanda.kali(determinan(&minor(&a, j, i))?)
+                    }
+                })
+                .collect()
+        })
+        .collect::<Hitung<_>>()?;
+    steps.push_str("\nadj(A) (transpos matriks kofaktor):\n");
+    steps.gambar(|t| tulis("adj(A) = ", &adj, Kurung::Siku, "", t));
+    let inv: Vec<Vec<Pecahan>> = adj
+        .iter()
+        .map(|r| r.iter().map(|v| v.bagi(d)).collect())
+        .collect::<Hitung<_>>()?;
+    steps.tulis(|t| format!("\nA⁻¹ = adj(A) / det(A) = adj(A) / {}:\n", kurung(d, t)));
+    steps.gambar(|t| tulis("A⁻¹ = ", &inv, Kurung::Siku, "", t));
+
+    steps.push_str("\nX = A⁻¹·B:\n");
+    let mut x = Vec::new();
+    for (i, r) in inv.iter().enumerate() {
+        let suku = |t| {
+            r.iter()
+                .zip(&b)
+                .map(|(p, q)| format!("{} × {}", kurung(*p, t), kurung(*q, t)))
+                .collect::<Vec<_>>()
+                .join(" + ")
+        };
+        let v = Pecahan::jumlah_perkalian(r.iter().copied().zip(b.iter().copied()))?;
+        steps.tulis(|t| format!("  {} = {} = {}\n", nama[i], suku(t), v.tampil(t)));
+        x.push(v);
+    }
+    Ok((x, steps))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::solver::pecahan::uji::matriks;
+
+    #[test]
+    fn setiap_metode_memberi_hasil_sama() {
+        // x + y + z = 6, 2x − y + z = 3, x + 2y − z = 2 → (1, 2, 3)
+        let m = matriks(&[&[1, 1, 1, 6], &[2, -1, 1, 3], &[1, 2, -1, 2]]);
+        let harapan = [1, 2, 3].map(Pecahan::from);
+        for metode in Metode::SPLTV {
+            let (x, langkah) = selesaikan(&m, &["x", "y", "z"], metode).unwrap();
+            assert_eq!(x, harapan, "{:?}", metode);
+            assert!(
+                langkah
+                    .pecahan
+                    .teks()
+                    .starts_with(&format!("Metode: {}\n", metode.nama()))
+            );
+        }
+    }
+
+    #[test]
+    fn metode_digeser_melingkar() {
+        assert_eq!(
+            Metode::Campuran.geser(&Metode::SPLDV, false),
+            Metode::Eliminasi
+        );
+        assert_eq!(Metode::Campuran.geser(&Metode::SPLTV, true), Metode::Invers);
+        // Grafik tidak ada di daftar SPLTV sehingga mulai dari awal
+        assert_eq!(
+            Metode::Grafik.geser(&Metode::SPLTV, false),
+            Metode::Eliminasi
+        );
+    }
+}
@@ -0,0 +1,457 @@
+use crate::solver::format::{Teks, koefisien, kombinasi_linear, kurung};
+use crate::solver::gauss::JenisSolusi;
+use crate::solver::kuadrat::{bentuk_akar, sederhana_akar};
+use crate::solver::pecahan::{Hitung, Pecahan, Tampilan};
+
+/// Polinom c₀ + c₁k + c₂k² dalam parameter k
+pub type Polinom = [Pecahan; 3];
+
+fn is_nol(p: &Polinom) -> bool {
+    p.iter().all(Pecahan::is_nol)
+}
+
+fn derajat(p: &Polinom) -> usize {
+    (0..3).rev().find(|&i| !p[i].is_nol()).unwrap_or(0)
+}
+
+fn nilai(p: &Polinom, k: Pecahan) -> Hitung<Pecahan> {
+    Pecahan::jumlah([p[0], p[1].kali(k)?, Pecahan::hasil_kali([p[2], k, k])?])
+}
+
+// a·b − c·d untuk koefisien yang paling banyak linear dalam k
+fn selisih_kali(a: &Polinom, b: &Polinom, c: &Polinom, d: &Polinom) -> Hitung<Polinom> {
+    let kali = |x: &Polinom, y: &Polinom| -> Hitung<Polinom> {
+        Ok([
+            x[0].kali(y[0])?,
+            x[0].kali(y[1])?.tambah(x[1].kali(y[0])?)?,
+            x[1].kali(y[1])?,
+        ])
+    };
+    let (ab, cd) = (kali(a, b)?, kali(c, d)?);
+    Ok([
+        ab[0].kurang(cd[0])?,
+        ab[1].kurang(cd[1])?,
+        ab[2].kurang(cd[2])?,
+    ])
+}
+
+// Menulis polinom dalam parameter, mis. "k² + k - 6"
+fn tulis(p: &Polinom, nama: &str, t: Tampilan) -> String {
+    if p[2].is_nol() && p[1].is_nol() {
+        return p[0].tampil(t).to_string();
+    }
+    let kuadrat = format!("{}²", nama);
+    let kiri = kombinasi_linear(Pecahan::NOL, &[(p[2], &kuadrat), (p[1], nama)], t);
+    if p[0].is_nol() {
+        kiri
+    } else if p[0].is_negatif() {
+        format!("{} - {}", kiri, (-p[0]).tampil(t))
+    } else {
+        format!("{} + {}", kiri, p[0].tampil(t))
+    }
+}
+
+// Faktor di dalam perkalian, mis. "3", "(-2)", "k", atau "(k + 1)"
+fn faktor(p: &Polinom, nama: &str, t: Tampilan) -> String {
+    match derajat(p) {
+        0 => kurung(p[0], t),
+        _ if p[0].is_nol() && p[2].is_nol() && !p[1].is_negatif() => tulis(p, nama, t),
+        _ => format!("({})", tulis(p, nama, t)),
+    }
+}
+
+// Persamaan dengan koefisien berparameter, mis. "(k + 1)x + 2y = k"
+fn persamaan(suku: &[(&Polinom, &str)], kanan: &Polinom, nama: &str, t: Tampilan) -> String {
+    let mut kiri = String::new();
+    for (p, var) in suku.iter().filter(|(p, _)| !is_nol(p)) {
+        let (negatif, isi) = if derajat(p) == 0 {
+            (p[0].is_negatif(), format!("{}{}", koefisien(p[0], t), var))
+        } else if p[0].is_nol() && p[2].is_nol() {
+            (
+                p[1].is_negatif(),
+                format!("{}{}{}", koefisien(p[1], t), nama, var),
+            )
+        } else {
+            (false, format!("({}){}", tulis(p, nama, t), var))
+        };
+        if kiri.is_empty() {
+            kiri.push_str(if negatif { "-" } else { "" });
+        } else {
+            kiri.push_str(if negatif { " - " } else { " + " });
+        }
+        kiri.push_str(&isi);
+    }
+    if kiri.is_empty() {
+        kiri = "0x + 0y".to_string();
+    }
+    format!("{} = {}", kiri, tulis(kanan, nama, t))
+}
+
+// Pembagian dua polinom yang ditulis apa adanya, mis. "(k - 1)/(k² - 4)"
+fn pecahan(atas: &Polinom, bawah: &Polinom, nama: &str, t: Tampilan) -> Hitung<String> {
+    if derajat(bawah) == 0 {
+        let p: Polinom = [
+            atas[0].bagi(bawah[0])?,
+            atas[1].bagi(bawah[0])?,
+            atas[2].bagi(bawah[0])?,
+        ];
+        return Ok(tulis(&p, nama, t));
+    }
+    let bungkus = |p: &Polinom| {
+        if derajat(p) == 0 || (p[0].is_nol() && p[2].is_nol()) {
+            tulis(p, nama, t)
+        } else {
+            format!("({})", tulis(p, nama, t))
+        }
+    };
+    Ok(format!("{}/{}", bungkus(atas), bungkus(bawah)))
+}
+
+/// Nilai parameter yang membuat suatu polinom bernilai nol
+#[derive(Clone, PartialEq)]
+enum Akar {
+    Rasional(Pecahan),
+    /// Sepasang akar irasional dari polinom kuadrat (monik) ini
+    Irasional(Polinom),
+}
+
+impl Akar {
+    fn tulis(&self, t: Tampilan) -> Hitung<String> {
+        match self {
+            Akar::Rasional(k) => Ok(k.tampil(t).to_string()),
+            Akar::Irasional(m) => {
+                let p = (-m[1]).bagi(Pecahan::from(2))?;
+                let (q, r) = sederhana_akar(p.kali(p)?.kurang(m[0])?)?;
+                bentuk_akar(p, q, r, false, "±", t)
+            }
+        }
+    }
+
+    // Apakah polinom `p` bernilai nol di akar ini. Akar irasional adalah
+    // akar polinom minimalnya (monik), sehingga p harus kelipatan polinom tersebut.
+    fn nol(&self, p: &Polinom) -> Hitung<bool> {
+        match self {
+            Akar::Rasional(k) => Ok(nilai(p, *k)?.is_nol()),
+            Akar::Irasional(m) => {
+                for i in 0..3 {
+                    if p[i] != m[i].kali(p[2])? {
+                        return Ok(false);
+                    }
+                }
+                Ok(true)
+            }
+        }
+    }
+}
+
+// Akar real polinom yang tidak identik nol
+fn akar_real(p: &Polinom) -> Hitung<Vec<Akar>> {
+    Ok(match derajat(p) {
+        2 => {
+            let [c, b, a] = *p;
+            let d = b
+                .kali(b)?
+                .kurang(Pecahan::hasil_kali([Pecahan::from(4), a, c])?)?;
+            let dua_a = Pecahan::from(2).kali(a)?;
+            if d.is_negatif() {
+                Vec::new()
+            } else if d.is_nol() {
+                vec![Akar::Rasional((-b).bagi(dua_a)?)]
+            } else if let Some(s) = d.akar_eksak() {
+                vec![
+                    Akar::Rasional((-b).tambah(s)?.bagi(dua_a)?),
+                    Akar::Rasional((-b).kurang(s)?.bagi(dua_a)?),
+                ]
+            } else {
+                vec![Akar::Irasional([c.bagi(a)?, b.bagi(a)?, Pecahan::SATU])]
+            }
+        }
+        1 => vec![Akar::Rasional((-p[0]).bagi(p[1])?)],
+        _ => Vec::new(),
+    })
+}
+
+struct Sistem<'a> {
+    k: &'a [Polinom; 6],
+    d: Polinom,
+    dx: Polinom,
+    dy: Polinom,
+}
+
+impl Sistem<'_> {
+    // Jenis penyelesaian di suatu akar, atau untuk k secara umum (selain
+    // sejumlah berhingga nilai khusus) jika `di` kosong
+    fn jenis(&self, di: Option<&Akar>) -> Hitung<(JenisSolusi, &'static str)> {
+        let nol = |p: &Polinom| match di {
+            Some(akar) => akar.nol(p),
+            None => Ok(is_nol(p)),
+        };
+        if !nol(&self.d)? {
+            return Ok((JenisSolusi::Tunggal, "D ≠ 0 → penyelesaian tunggal"));
+        }
+        for baris in self.k.chunks(3) {
+            if nol(&baris[0])? && nol(&baris[1])? && !nol(&baris[2])? {
+                return Ok((
+                    JenisSolusi::TidakAda,
+                    "satu persamaan menjadi 0x + 0y = c dengan c ≠ 0 → tidak ada penyelesaian",
+                ));
+            }
+        }
+        Ok(if !nol(&self.dx)? || !nol(&self.dy)? {
+            (
+                JenisSolusi::TidakAda,
+                "D = 0 tetapi Dx atau Dy ≠ 0 → garis sejajar, tidak ada penyelesaian",
+            )
+        } else {
+            (
+                JenisSolusi::TakHingga,
+                "D = Dx = Dy = 0 → garis berimpit, tak hingga banyak penyelesaian",
+            )
+        })
+    }
+}
+
+//
+// SPLDV dengan Parameter
+// (a1)x + (b1)y = c1, (a2)x + (b2)y = c2 dengan koefisien linear dalam k
+// D, Dx, dan Dy menjadi polinom dalam k. Penyelesaian tunggal jika D ≠ 0;
+// di setiap akar D (atau, jika D ≡ 0, di akar Dx, Dy, dan ruas kanan)
+// sistem diperiksa apakah tidak konsisten atau bergantung.
+//
+pub fn spldv_parameter_proses(
+    k: [Polinom; 6],
+    nama: &str,
+) -> Hitung<(Vec<(JenisSolusi, Teks)>, Teks)> {
+    let [a1, b1, c1, a2, b2, c2] = &k;
+    let mut steps = Teks::dari(|t| {
+        format!(
+            "Persamaan (parameter {}):\n  P1: {}\n  P2: {}\n\n",
+            nama,
+            persamaan(&[(a1, "x"), (b1, "y")], c1, nama, t),
+            persamaan(&[(a2, "x"), (b2, "y")], c2, nama, t)
+        )
+    });
+
+    let sistem = Sistem {
+        k: &k,
+        d: selisih_kali(a1, b2, a2, b1)?,
+        dx: selisih_kali(c1, b2, c2, b1)?,
+        dy: selisih_kali(a1, c2, a2, c1)?,
+    };
+    steps.push_str("Determinan:\n");
+    for (label, rumus, [p, q, r, s], hasil) in [
+        ("D ", "a1·b2 − a2·b1", [a1, b2, a2, b1], &sistem.d),
+        ("Dx", "c1·b2 − c2·b1", [c1, b2, c2, b1], &sistem.dx),
+        ("Dy", "a1·c2 − a2·c1", [a1, c2, a2, c1], &sistem.dy),
+    ] {
+        steps.tulis(|t| {
+            format!(
+                "  {} = {} = {}·{} − {}·{} = {}\n",
+                label,
+                rumus,
+                faktor(p, nama, t),
+                faktor(q, nama, t),
+                faktor(r, nama, t),
+                faktor(s, nama, t),
+                tulis(hasil, nama, t)
+            )
+        });
+    }
+
+    // Nilai khusus: akar D, atau jika D ≡ 0, nilai yang mengubah Dx, Dy,
+    // atau ruas kanan persamaan yang koefisiennya hilang
+    let (umum, alasan_umum) = sistem.jenis(None)?;
+    let mut calon: Vec<&Polinom> = vec![&sistem.d];
+    steps.push_str("\nAnalisis kasus:\n");
+    if is_nol(&sistem.d) {
+        steps.tulis(|_| format!(
+            "  D = 0 untuk setiap {} → sistem tidak pernah memiliki penyelesaian tunggal.\n  Secara umum: {}.\n",
+            nama, alasan_umum
+        ));
+        calon = vec![&sistem.dx, &sistem.dy];
+        for baris in k.chunks(3) {
+            if is_nol(&baris[0]) && is_nol(&baris[1]) {
+                calon.push(&baris[2]);
+            }
+        }
+    } else {
+        steps.coba_tulis(|t| {
+            Ok(format!(
+                "  Penyelesaian tunggal jika D ≠ 0:\n    x = Dx/D = {}\n    y = Dy/D = {}\n",
+                pecahan(&sistem.dx, &sistem.d, nama, t)?,
+                pecahan(&sistem.dy, &sistem.d, nama, t)?
+            ))
+        })?;
+        if derajat(&sistem.d) == 0 {
+            steps.tulis(|t| {
+                format!(
+                    "  D = {} tidak bergantung pada {} → selalu penyelesaian tunggal.\n",
+                    sistem.d[0].tampil(t),
+                    nama
+                )
+            });
+        }
+    }
+
+    let mut akar: Vec<Akar> = Vec::new();
+    for p in calon.into_iter().filter(|p| !is_nol(p) && derajat(p) > 0) {
+        let baru = akar_real(p)?;
+        let daftar = |t| -> Hitung<String> {
+            let daftar: Vec<String> = baru
+                .iter()
+                .map(|a| Ok(format!("{} = {}", nama, a.tulis(t)?)))
+                .collect::<Hitung<_>>()?;
+            Ok(daftar.join(" atau "))
+        };
+        if tulis(p, nama, Tampilan::Pecahan) == nama {
+            // Persamaan k = 0 tidak perlu diselesaikan lagi
+        } else if baru.is_empty() {
+            steps.tulis(|t| format!("  {} = 0 tidak memiliki akar real.\n", tulis(p, nama, t)));
+        } else {
+            steps.coba_tulis(|t| Ok(format!("  {} = 0 → {}\n", tulis(p, nama, t), daftar(t)?)))?;
+        }
+        for a in baru {
+            if !akar.contains(&a) {
+                akar.push(a);
+            }
+        }
+    }
+
+    // Periksa sistem di setiap nilai khusus
+    let mut khusus: Vec<(JenisSolusi, &Akar)> = Vec::new();
+    for a in &akar {
+        let (jenis, alasan) = sistem.jenis(Some(a))?;
+        if jenis == umum {
+            continue;
+        }
+        steps.coba_tulis(|t| Ok(format!("\n  Untuk {} = {}:\n", nama, a.tulis(t)?)))?;
+        match a {
+            Akar::Rasional(v) => {
+                let mut n = [[Pecahan::NOL; 3]; 6];
+                for (n, p) in n.iter_mut().zip(&k) {
+                    n[0] = nilai(p, *v)?;
+                }
+                let [a1, b1, c1, a2, b2, c2] = &n;
+                let (d, dx, dy) = (
+                    nilai(&sistem.d, *v)?,
+                    nilai(&sistem.dx, *v)?,
+                    nilai(&sistem.dy, *v)?,
+                );
+                steps.tulis(|t| {
+                    format!(
+                        "    P1: {}\n    P2: {}\n    D = {}, Dx = {}, Dy = {}\n",
+                        persamaan(&[(a1, "x"), (b1, "y")], c1, nama, t),
+                        persamaan(&[(a2, "x"), (b2, "y")], c2, nama, t),
+                        d.tampil(t),
+                        dx.tampil(t),
+                        dy.tampil(t)
+                    )
+                });
+            }
+            Akar::Irasional(_) => {
+                let tanda =
+                    |p: &Polinom| -> Hitung<&str> { Ok(if a.nol(p)? { "=" } else { "≠" }) };
+                let (dx, dy) = (tanda(&sistem.dx)?, tanda(&sistem.dy)?);
+                steps.tulis(|_| format!("    D = 0, Dx {} 0, Dy {} 0\n", dx, dy));
+            }
+        }
+        steps.tulis(|_| format!("    {}\n", alasan));
+        khusus.push((jenis, a));
+    }
+
+    // Syarat parameter untuk setiap jenis penyelesaian
+    let kesimpulan = [
+        JenisSolusi::Tunggal,
+        JenisSolusi::TidakAda,
+        JenisSolusi::TakHingga,
+    ]
+    .into_iter()
+    .map(|jenis| {
+        let mut syarat = Teks::default();
+        syarat.coba_tulis(|t| {
+            Ok(if jenis == umum && khusus.is_empty() {
+                format!("setiap {}", nama)
+            } else if jenis == umum {
+                khusus
+                    .iter()
+                    .map(|(_, a)| Ok(format!("{} ≠ {}", nama, a.tulis(t)?)))
+                    .collect::<Hitung<Vec<_>>>()?
+                    .join(" dan ")
+            } else {
+                let nilai: Vec<String> = khusus
+                    .iter()
+                    .filter(|(j, _)| *j == jenis)
+                    .map(|(_, a)| Ok(format!("{} = {}", nama, a.tulis(t)?)))
+                    .collect::<Hitung<_>>()?;
+                if nilai.is_empty() {
+                    format!("tidak ada nilai {}", nama)
+                } else {
+                    nilai.join(" atau ")
+                }
+            })
+        })?;
+        Ok((jenis, syarat))
+    })
+    .collect::<Hitung<_>>()?;
+    Ok((kesimpulan, steps))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    // Polinom c₀ + c₁k
+    fn linear(c0: i128, c1: i128) -> Polinom {
+        [Pecahan::from(c0), Pecahan::from(c1), Pecahan::NOL]
+    }
+
+    fn syarat(hasil: &[(JenisSolusi, Teks)], jenis: JenisSolusi) -> String {
+        hasil
+            .iter()
+            .find(|(j, _)| *j == jenis)
+            .unwrap()
+            .1
+            .pecahan
+            .teks()
+    }
+
+    #[test]
+    fn nilai_khusus_dari_akar_determinan() {
+        // kx + y = 1, x + ky = 1 → D = k² − 1
+        let k = [
+            linear(0, 1),
+            linear(1, 0),
+            linear(1, 0),
+            linear(1, 0),
+            linear(0, 1),
+            linear(1, 0),
+        ];
+        let (hasil, langkah) = spldv_parameter_proses(k, "k").unwrap();
+        assert_eq!(syarat(&hasil, JenisSolusi::TidakAda), "k = -1");
+        assert_eq!(syarat(&hasil, JenisSolusi::TakHingga), "k = 1");
+        let tunggal = syarat(&hasil, JenisSolusi::Tunggal);
+        assert!(tunggal.contains("k ≠ 1") && tunggal.contains("k ≠ -1"));
+        assert!(
+            langkah
+                .pecahan
+                .teks()
+                .contains("D = 0 tetapi Dx atau Dy ≠ 0")
+        );
+    }
+
+    #[test]
+    fn determinan_tetap() {
+        // x + y = k, x − y = 1 → D = −2 untuk setiap k
+        let k = [
+            linear(1, 0),
+            linear(1, 0),
+            linear(0, 1),
+            linear(1, 0),
+            linear(-1, 0),
+            linear(1, 0),
+        ];
+        let (hasil, _) = spldv_parameter_proses(k, "k").unwrap();
+        assert_eq!(syarat(&hasil, JenisSolusi::Tunggal), "setiap k");
+        assert_eq!(syarat(&hasil, JenisSolusi::TidakAda), "tidak ada nilai k");
+    }
+}
@@ -0,0 +1,489 @@
+use std::cmp::Ordering;
+use std::fmt;
+use std::ops::Neg;
+use std::str::FromStr;
+
+/// Bilangan rasional eksak p/q yang selalu disimpan dalam bentuk sederhana
+/// dengan penyebut positif.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct Pecahan {
+    pem: i128,
+    peny: i128,
+}
+
+/// ⌊√n⌋ untuk n ≥ 0. Tebakan f64 dikoreksi karena tidak tepat untuk n
+/// di atas 2⁵³.
+pub fn akar_bulat(n: i128) -> i128 {
+    let mut r = (n as f64).sqrt() as i128;
+    while r.checked_mul(r).is_none_or(|k| k > n) {
+        r -= 1;
+    }
+    while (r + 1).checked_mul(r + 1).is_some_and(|k| k <= n) {
+        r += 1;
+    }
+    r
+}
+
+/// Cara angka ditulis pada langkah dan hasil, lihat [`Pecahan::tampil`]
+#[derive(Clone, Copy, PartialEq)]
+pub enum Tampilan {
+    Pecahan,
+    Desimal,
+}
+
+/// Banyak digit terbanyak pada satu angka yang diketik, agar hasil kali
+/// dua angka masih muat di i128
+pub const MAKS_DIGIT: usize = 18;
+
+/// Galat operasi pecahan
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum GalatHitung {
+    /// Hasil antara melampaui jangkauan i128
+    TerlaluBesar,
+    BagiNol,
+}
+
+impl fmt::Display for GalatHitung {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            GalatHitung::TerlaluBesar => write!(f, "angka terlalu besar"),
+            GalatHitung::BagiNol => write!(f, "pembagian dengan nol"),
+        }
+    }
+}
+
+// Pesan galat isian berupa teks, sehingga galat hitung bisa diteruskan dengan `?`
+impl From<GalatHitung> for String {
+    fn from(galat: GalatHitung) -> Self {
+        galat.to_string()
+    }
+}
+
+pub type Hitung<T> = Result<T, GalatHitung>;
+
+/// KPK dua bilangan positif, `None` jika meluap
+pub(crate) fn kpk(a: i128, b: i128) -> Option<i128> {
+    (a / fpb(a, b)).checked_mul(b)
+}
+
+/// Faktor persekutuan terbesar (selalu non-negatif)
+pub(crate) fn fpb(mut a: i128, mut b: i128) -> i128 {
+    while b != 0 {
+        (a, b) = (b, a % b);
+    }
+    a.abs()
+}
+
+impl Pecahan {
+    pub const NOL: Pecahan = Pecahan { pem: 0, peny: 1 };
+    pub const SATU: Pecahan = Pecahan { pem: 1, peny: 1 };
+
+    /// Pecahan dari bilangan yang pasti sah, mis. tetapan di dalam kode.
+    /// Hasil perhitungan memakai [`Pecahan::coba`].
+    pub fn new(pem: i128, peny: i128) -> Self {
+        Self::coba(pem, peny).expect("pecahan tidak sah")
+    }
+
+    /// Pecahan pem/peny dalam bentuk sederhana. i128::MIN ditolak karena
+    /// tandanya tidak dapat dibalik.
+    pub fn coba(pem: i128, peny: i128) -> Hitung<Self> {
+        if peny == 0 {
+            return Err(GalatHitung::BagiNol);
+        }
+        if pem == i128::MIN || peny == i128::MIN {
+            return Err(GalatHitung::TerlaluBesar);
+        }
+        let f = fpb(pem, peny);
+        let tanda = if peny < 0 { -1 } else { 1 };
+        Ok(Self {
+            pem: tanda * (pem / f),
+            peny: tanda * (peny / f),
+        })
+    }
+
+    pub fn pembilang(self) -> i128 {
+        self.pem
+    }
+
+    pub fn penyebut(self) -> i128 {
+        self.peny
+    }
+
+    pub fn is_nol(&self) -> bool {
+        self.pem == 0
+    }
+
+    pub fn is_bulat(&self) -> bool {
+        self.peny == 1
+    }
+
+    pub fn is_negatif(&self) -> bool {
+        self.pem < 0
+    }
+
+    pub fn abs(self) -> Self {
+        Self {
+            pem: self.pem.abs(),
+            peny: self.peny,
+        }
+    }
+
+    /// Pendekatan rasional untuk bilangan real (pecahan berlanjut,
+    /// penyebut maksimal satu juta)
+    pub fn dari_f64(v: f64) -> Option<Self> {
+        if !v.is_finite() || v.abs() > 1e15 {
+            return None;
+        }
+        let (mut h0, mut h1, mut k0, mut k1) = (0i128, 1i128, 1i128, 0i128);
+        let mut sisa = v.abs();
+        for _ in 0..40 {
+            let a = sisa.floor() as i128;
+            let (h2, k2) = (a * h1 + h0, a * k1 + k0);
+            if k2 > 1_000_000 {
+                break;
+            }
+            (h0, h1, k0, k1) = (h1, h2, k1, k2);
+            let pecahan = sisa - sisa.floor();
+            if pecahan < 1e-12 || (h1 as f64 / k1 as f64 - v.abs()).abs() < 1e-12 {
+                break;
+            }
+            sisa = 1.0 / pecahan;
+        }
+        let tanda = if v < 0.0 { -1 } else { 1 };
+        Some(Self::new(tanda * h1, k1))
+    }
+
+    /// Akar kuadrat eksak jika pembilang dan penyebut kuadrat sempurna
+    pub fn akar_eksak(self) -> Option<Self> {
+        if self.pem < 0 {
+            return None;
+        }
+        let akar = |n: i128| {
+            let r = akar_bulat(n);
+            (r * r == n).then_some(r)
+        };
+        Some(Self {
+            pem: akar(self.pem)?,
+            peny: akar(self.peny)?,
+        })
+    }
+
+    /// Pangkat bilangan bulat
+    pub fn pangkat(self, n: i32) -> Hitung<Self> {
+        let besar = GalatHitung::TerlaluBesar;
+        let pem = self.pem.checked_pow(n.unsigned_abs()).ok_or(besar)?;
+        let peny = self.peny.checked_pow(n.unsigned_abs()).ok_or(besar)?;
+        if n < 0 {
+            Self::coba(peny, pem)
+        } else {
+            Self::coba(pem, peny)
+        }
+    }
+
+    pub fn tambah(self, o: Pecahan) -> Hitung<Pecahan> {
+        // Samakan penyebut dengan KPK, bukan hasil kali penyebut
+        let f = fpb(self.peny, o.peny);
+        let kiri = self.pem.checked_mul(o.peny / f);
+        let kanan = o.pem.checked_mul(self.peny / f);
+        dari_bagian(
+            kiri.zip(kanan).and_then(|(a, b)| a.checked_add(b)),
+            (self.peny / f).checked_mul(o.peny),
+        )
+    }
+
+    pub fn kurang(self, o: Pecahan) -> Hitung<Pecahan> {
+        self.tambah(-o)
+    }
+
+    pub fn kali(self, o: Pecahan) -> Hitung<Pecahan> {
+        // Sederhanakan silang lebih dulu agar tidak cepat meluap
+        let f1 = fpb(self.pem, o.peny).max(1);
+        let f2 = fpb(o.pem, self.peny).max(1);
+        dari_bagian(
+            (self.pem / f1).checked_mul(o.pem / f2),
+            (self.peny / f2).checked_mul(o.peny / f1),
+        )
+    }
+
+    pub fn bagi(self, o: Pecahan) -> Hitung<Pecahan> {
+        if o.is_nol() {
+            return Err(GalatHitung::BagiNol);
+        }
+        let f1 = fpb(self.pem, o.pem).max(1);
+        let f2 = fpb(self.peny, o.peny).max(1);
+        dari_bagian(
+            (self.pem / f1).checked_mul(o.peny / f2),
+            (self.peny / f2).checked_mul(o.pem / f1),
+        )
+    }
+
+    /// Jumlah semua suku
+    pub fn jumlah(suku: impl IntoIterator<Item = Pecahan>) -> Hitung<Pecahan> {
+        suku.into_iter().try_fold(Pecahan::NOL, Pecahan::tambah)
+    }
+
+    /// Jumlah dari hasil kali setiap pasangan (a, b)
+    pub fn jumlah_perkalian(
+        pasangan: impl IntoIterator<Item = (Pecahan, Pecahan)>,
+    ) -> Hitung<Pecahan> {
+        pasangan
+            .into_iter()
+            .try_fold(Pecahan::NOL, |s, (a, b)| s.tambah(a.kali(b)?))
+    }
+
+    /// Hasil kali semua faktor
+    pub fn hasil_kali(faktor: impl IntoIterator<Item = Pecahan>) -> Hitung<Pecahan> {
+        faktor.into_iter().try_fold(Pecahan::SATU, Pecahan::kali)
+    }
+
+    pub fn ke_f64(self) -> f64 {
+        self.pem as f64 / self.peny as f64
+    }
+
+    /// Ditulis dengan tampilan `t`, mis. `format!("{}", x.tampil(t))`
+    pub fn tampil(self, t: Tampilan) -> Tampil {
+        Tampil(self, t)
+    }
+
+    /// Menulis dalam bentuk pecahan
+    pub fn pecahan(&self) -> String {
+        if self.peny == 1 {
+            format!("{}", self.pem)
+        } else {
+            format!("{}/{}", self.pem, self.peny)
+        }
+    }
+
+    /// Menulis dalam bentuk desimal (maksimal 4 angka di belakang koma)
+    pub fn desimal(&self) -> String {
+        if self.peny == 1 {
+            return format!("{}", self.pem);
+        }
+        desimal(self.ke_f64())
+    }
+}
+
+/// Menulis bilangan real dalam bentuk desimal (maksimal 4 angka di belakang koma)
+pub fn desimal(v: f64) -> String {
+    let s = format!("{:.4}", v);
+    let s = s.trim_end_matches('0').trim_end_matches('.');
+    if s == "-0" {
+        "0".to_string()
+    } else {
+        s.to_string()
+    }
+}
+
+/// Bilangan bulat sebagai pecahan. `n` tidak boleh i128::MIN.
+impl From<i128> for Pecahan {
+    fn from(n: i128) -> Self {
+        Self::new(n, 1)
+    }
+}
+
+/// Mengurai bilangan bulat atau desimal ("-2", "0.25") secara eksak
+impl FromStr for Pecahan {
+    type Err = ();
+
+    fn from_str(s: &str) -> Result<Self, ()> {
+        let (negatif, s) = match s.strip_prefix('-') {
+            Some(sisa) => (true, sisa),
+            None => (false, s),
+        };
+        let (bulat, koma) = s.split_once('.').unwrap_or((s, ""));
+        let bulat = bulat.trim_start_matches('0');
+        let koma = koma.trim_end_matches('0');
+        if s.is_empty()
+            || s == "."
+            || !bulat
+                .chars()
+                .chain(koma.chars())
+                .all(|c| c.is_ascii_digit())
+            || bulat.len() + koma.len() > MAKS_DIGIT
+        {
+            return Err(());
+        }
+        let digit = format!("0{}{}", bulat, koma);
+        let pem: i128 = digit.parse().map_err(|_| ())?;
+        let peny = 10i128.pow(koma.len() as u32);
+        Ok(Self::new(if negatif { -pem } else { pem }, peny))
+    }
+}
+
+/// Pecahan beserta tampilannya, dibuat oleh [`Pecahan::tampil`]
+#[derive(Clone, Copy)]
+pub struct Tampil(Pecahan, Tampilan);
+
+impl fmt::Display for Tampil {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let Tampil(x, t) = self;
+        f.pad(&match t {
+            Tampilan::Pecahan => x.pecahan(),
+            Tampilan::Desimal => x.desimal(),
+        })
+    }
+}
+
+// Pecahan dari hasil bagian yang mungkin meluap
+fn dari_bagian(pem: Option<i128>, peny: Option<i128>) -> Hitung<Pecahan> {
+    match (pem, peny) {
+        (Some(pem), Some(peny)) => Pecahan::coba(pem, peny),
+        _ => Err(GalatHitung::TerlaluBesar),
+    }
+}
+
+impl Neg for Pecahan {
+    type Output = Pecahan;
+    fn neg(self) -> Pecahan {
+        Pecahan {
+            pem: -self.pem,
+            peny: self.peny,
+        }
+    }
+}
+
+impl PartialOrd for Pecahan {
+    fn partial_cmp(&self, o: &Pecahan) -> Option<Ordering> {
+        Some(self.cmp(o))
+    }
+}
+
+impl Ord for Pecahan {
+    fn cmp(&self, o: &Pecahan) -> Ordering {
+        match (self.pem.checked_mul(o.peny), o.pem.checked_mul(self.peny)) {
+            (Some(a), Some(b)) => a.cmp(&b),
+            _ => banding(self.pem, self.peny, o.pem, o.peny),
+        }
+    }
+}
+
+// Membandingkan a/b dengan c/d (b, d > 0) tanpa perkalian silang: bagian
+// bulat dibandingkan lebih dulu, lalu sisanya r₁/b vs r₂/d sama dengan
+// d/r₂ vs b/r₁ (langkah algoritma Euklides, pasti berhenti)
+fn banding(mut a: i128, mut b: i128, mut c: i128, mut d: i128) -> Ordering {
+    loop {
+        let (q1, r1) = (a.div_euclid(b), a.rem_euclid(b));
+        let (q2, r2) = (c.div_euclid(d), c.rem_euclid(d));
+        if q1 != q2 {
+            return q1.cmp(&q2);
+        }
+        match (r1 == 0, r2 == 0) {
+            (true, true) => return Ordering::Equal,
+            (true, false) => return Ordering::Less,
+            (false, true) => return Ordering::Greater,
+            _ => (a, b, c, d) = (d, r2, b, r1),
+        }
+    }
+}
+
+/// Pembantu bersama untuk pengujian modul-modul penyelesai
+#[cfg(test)]
+pub mod uji {
+    use super::Pecahan;
+
+    pub fn p(pem: i128, peny: i128) -> Pecahan {
+        Pecahan::new(pem, peny)
+    }
+
+    /// Larik bilangan bulat sebagai larik pecahan
+    pub fn larik<const N: usize>(v: [i128; N]) -> [Pecahan; N] {
+        v.map(Pecahan::from)
+    }
+
+    /// Matriks bilangan bulat sebagai matriks pecahan
+    pub fn matriks(m: &[&[i128]]) -> Vec<Vec<Pecahan>> {
+        m.iter()
+            .map(|b| b.iter().map(|&v| Pecahan::from(v)).collect())
+            .collect()
+    }
+
+    /// Nama variabel x1, x2, …, xn
+    pub fn nama(n: usize) -> Vec<String> {
+        (1..=n).map(|i| format!("x{}", i)).collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::uji::p;
+    use super::*;
+
+    #[test]
+    fn disederhanakan_dengan_penyebut_positif() {
+        assert_eq!(p(6, 8), p(3, 4));
+        assert_eq!(p(6, -8).pembilang(), -3);
+        assert_eq!(p(6, -8).penyebut(), 4);
+        assert_eq!(p(-6, -8), p(3, 4));
+        assert_eq!(p(0, -5), Pecahan::NOL);
+    }
+
+    #[test]
+    fn operasi_dasar() {
+        assert_eq!(p(1, 2).tambah(p(1, 3)), Ok(p(5, 6)));
+        assert_eq!(p(1, 2).kurang(p(3, 4)), Ok(p(-1, 4)));
+        assert_eq!(p(-2, 3).kali(p(9, 4)), Ok(p(-3, 2)));
+        assert_eq!(p(1, 2).bagi(p(-1, 4)), Ok(Pecahan::from(-2)));
+        assert_eq!(
+            Pecahan::jumlah([p(1, 2), p(1, 3), p(1, 6)]),
+            Ok(Pecahan::SATU)
+        );
+        assert!(p(-1, 3) < p(-1, 4));
+        assert!(p(2, 3) > p(3, 5));
+    }
+
+    #[test]
+    fn mengurai_desimal() {
+        assert_eq!("0.25".parse(), Ok(p(1, 4)));
+        assert_eq!("-2".parse(), Ok(Pecahan::from(-2)));
+        assert_eq!("000012.5000000000000000".parse(), Ok(p(25, 2)));
+        assert_eq!("".parse::<Pecahan>(), Err(()));
+        assert_eq!(".".parse::<Pecahan>(), Err(()));
+        assert_eq!("1.2.3".parse::<Pecahan>(), Err(()));
+        assert_eq!("1234567890123456789".parse::<Pecahan>(), Err(()));
+    }
+
+    #[test]
+    fn luapan_dan_pembagian_nol_menjadi_galat() {
+        let besar = Pecahan::from(i128::MAX / 2);
+        assert_eq!(besar.kali(besar), Err(GalatHitung::TerlaluBesar));
+        assert_eq!(
+            Pecahan::jumlah([besar, besar, besar]),
+            Err(GalatHitung::TerlaluBesar)
+        );
+        assert_eq!(
+            p(1, i128::MAX).kurang(p(1, i128::MAX - 1)),
+            Err(GalatHitung::TerlaluBesar)
+        );
+        assert_eq!(Pecahan::SATU.bagi(Pecahan::NOL), Err(GalatHitung::BagiNol));
+        assert_eq!(Pecahan::coba(1, 0), Err(GalatHitung::BagiNol));
+        assert_eq!(Pecahan::coba(i128::MIN, 1), Err(GalatHitung::TerlaluBesar));
+        assert_eq!(p(2, 3).pangkat(-2), Ok(p(9, 4)));
+        assert_eq!(Pecahan::NOL.pangkat(-1), Err(GalatHitung::BagiNol));
+        assert_eq!(besar.pangkat(2), Err(GalatHitung::TerlaluBesar));
+    }
+
+    #[test]
+    fn akar_bulat_dan_eksak() {
+        assert_eq!(akar_bulat(0), 0);
+        assert_eq!(akar_bulat(15), 3);
+        assert_eq!(akar_bulat(16), 4);
+        let besar: i128 = 3_037_000_499_976_050_000;
+        assert_eq!(akar_bulat(besar * besar), besar);
+        assert_eq!(akar_bulat(besar * besar - 1), besar - 1);
+        assert_eq!(
+            Pecahan::from(100_140_049).akar_eksak(),
+            Some(Pecahan::from(10_007))
+        );
+        assert_eq!(p(9, 8).akar_eksak(), None);
+        assert_eq!(Pecahan::from(-4).akar_eksak(), None);
+    }
+
+    #[test]
+    fn perbandingan_tanpa_luapan() {
+        let a = p(i128::MAX - 1, i128::MAX);
+        let b = p(i128::MAX - 2, i128::MAX - 1);
+        assert!(a > b);
+        assert_eq!(a.cmp(&a), Ordering::Equal);
+        assert!(p(-i128::MAX, 3) < p(i128::MAX, 7));
+    }
+}
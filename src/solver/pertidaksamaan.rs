@@ -0,0 +1,217 @@
+use crate::solver::format::{Teks, kombinasi_linear, kurung};
+use crate::solver::pecahan::{Hitung, Pecahan, Tampilan};
+use crate::solver::splsv::suku_splsv;
+
+/// Tanda pertidaksamaan yang bisa dipilih di layar input
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum Relasi {
+    Kurang,
+    KurangSama,
+    Lebih,
+    LebihSama,
+}
+
+impl Relasi {
+    pub const SEMUA: [Relasi; 4] = [
+        Relasi::Kurang,
+        Relasi::KurangSama,
+        Relasi::Lebih,
+        Relasi::LebihSama,
+    ];
+
+    pub fn simbol(self) -> &'static str {
+        match self {
+            Relasi::Kurang => "<",
+            Relasi::KurangSama => "≤",
+            Relasi::Lebih => ">",
+            Relasi::LebihSama => "≥",
+        }
+    }
+
+    /// Tanda setelah kedua ruas dikali atau dibagi bilangan negatif
+    pub fn balik(self) -> Relasi {
+        match self {
+            Relasi::Kurang => Relasi::Lebih,
+            Relasi::KurangSama => Relasi::LebihSama,
+            Relasi::Lebih => Relasi::Kurang,
+            Relasi::LebihSama => Relasi::KurangSama,
+        }
+    }
+
+    /// Apakah batasnya termasuk penyelesaian (≤ dan ≥)
+    pub fn tertutup(self) -> bool {
+        matches!(self, Relasi::KurangSama | Relasi::LebihSama)
+    }
+
+    /// Tanda yang sama termasuk batasnya: < menjadi ≤, > menjadi ≥
+    pub fn tutup(self) -> Relasi {
+        match self {
+            Relasi::Kurang => Relasi::KurangSama,
+            Relasi::Lebih => Relasi::LebihSama,
+            r => r,
+        }
+    }
+
+    /// Apakah `kiri` (relasi) `kanan` benar
+    pub fn dipenuhi(self, kiri: Pecahan, kanan: Pecahan) -> bool {
+        match self {
+            Relasi::Kurang => kiri < kanan,
+            Relasi::KurangSama => kiri <= kanan,
+            Relasi::Lebih => kiri > kanan,
+            Relasi::LebihSama => kiri >= kanan,
+        }
+    }
+
+    /// Relasi berikutnya, atau sebelumnya jika `mundur`
+    pub fn geser(self, mundur: bool) -> Relasi {
+        let n = Relasi::SEMUA.len();
+        let i = Relasi::SEMUA.iter().position(|&r| r == self).unwrap_or(0);
+        Relasi::SEMUA[if mundur { (i + n - 1) % n } else { (i + 1) % n }]
+    }
+}
+
+/// Himpunan penyelesaian pertidaksamaan linear satu variabel
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum Himpunan {
+    /// x (relasi) batas
+    Selang(Relasi, Pecahan),
+    Semua,
+    Kosong,
+}
+
+impl Himpunan {
+    pub fn notasi_selang(self, t: Tampilan) -> String {
+        match self {
+            Himpunan::Selang(r, c) => match r {
+                Relasi::Kurang => format!("(−∞, {})", c.tampil(t)),
+                Relasi::KurangSama => format!("(−∞, {}]", c.tampil(t)),
+                Relasi::Lebih => format!("({}, ∞)", c.tampil(t)),
+                Relasi::LebihSama => format!("[{}, ∞)", c.tampil(t)),
+            },
+            Himpunan::Semua => "(−∞, ∞)".to_string(),
+            Himpunan::Kosong => "∅".to_string(),
+        }
+    }
+
+    pub fn notasi_himpunan(self, t: Tampilan) -> String {
+        match self {
+            Himpunan::Selang(r, c) => format!("{{x | x {} {}, x ∈ ℝ}}", r.simbol(), c.tampil(t)),
+            Himpunan::Semua => "{x | x ∈ ℝ}".to_string(),
+            Himpunan::Kosong => "{ } = ∅".to_string(),
+        }
+    }
+}
+
+//
+// Pertidaksamaan Linear Satu Variabel
+// ax + b (relasi) 0  →  ax (relasi) -b  →  x (relasi) -b / a
+// Tanda pertidaksamaan dibalik jika kedua ruas dibagi a < 0.
+//
+pub fn ptlsv_proses(a: Pecahan, b: Pecahan, relasi: Relasi) -> Hitung<(Himpunan, Teks)> {
+    let r = relasi.simbol();
+    let mut steps = Teks::dari(|t| format!("Pertidaksamaan: {} {} 0\n\n", suku_splsv(a, b, t), r));
+
+    if a.is_nol() {
+        let benar = relasi.dipenuhi(b, Pecahan::NOL);
+        steps.tulis(|t| {
+            format!(
+                "Koefisien x (a) = 0 → pertidaksamaan menjadi {} {} 0 yang {}.\n",
+                b.tampil(t),
+                r,
+                if benar {
+                    "selalu benar"
+                } else {
+                    "tidak pernah benar"
+                }
+            )
+        });
+        let himpunan = if benar {
+            steps.push_str("Setiap nilai x memenuhi pertidaksamaan.\n");
+            Himpunan::Semua
+        } else {
+            steps.push_str("Tidak ada nilai x yang memenuhi pertidaksamaan.\n");
+            Himpunan::Kosong
+        };
+        return Ok((himpunan, steps));
+    }
+
+    let x = (-b).bagi(a)?;
+    steps.tulis(|t| {
+        format!(
+            "Pindahkan b ke kanan:\n  {} {} {}\nBagi kedua ruas dengan a = {}:\n",
+            kombinasi_linear(Pecahan::NOL, &[(a, "x")], t),
+            r,
+            (-b).tampil(t),
+            a.tampil(t)
+        )
+    });
+    let akhir = if a.is_negatif() {
+        let dibalik = relasi.balik();
+        steps.tulis(|_| {
+            format!(
+                "  a negatif → tanda pertidaksamaan dibalik ({} menjadi {})\n",
+                r,
+                dibalik.simbol()
+            )
+        });
+        dibalik
+    } else {
+        steps.push_str("  a positif → tanda pertidaksamaan tetap\n");
+        relasi
+    };
+    steps.tulis(|t| {
+        format!(
+            "  x {} {} / {}\n  x {} {}\n",
+            akhir.simbol(),
+            kurung(-b, t),
+            kurung(a, t),
+            akhir.simbol(),
+            x.tampil(t)
+        )
+    });
+
+    Ok((Himpunan::Selang(akhir, x), steps))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const P: Tampilan = Tampilan::Pecahan;
+
+    #[test]
+    fn tanda_dibalik_jika_dibagi_negatif() {
+        // −2x + 4 < 0 → x > 2
+        let (himpunan, langkah) =
+            ptlsv_proses(Pecahan::from(-2), Pecahan::from(4), Relasi::Kurang).unwrap();
+        assert_eq!(himpunan, Himpunan::Selang(Relasi::Lebih, Pecahan::from(2)));
+        assert_eq!(himpunan.notasi_selang(P), "(2, ∞)");
+        assert_eq!(himpunan.notasi_himpunan(P), "{x | x > 2, x ∈ ℝ}");
+        assert!(!langkah.pecahan.teks().is_empty());
+
+        // 3x − 1 ≥ 0 → x ≥ 1/3
+        let (himpunan, _) =
+            ptlsv_proses(Pecahan::from(3), Pecahan::from(-1), Relasi::LebihSama).unwrap();
+        assert_eq!(himpunan.notasi_selang(P), "[1/3, ∞)");
+    }
+
+    #[test]
+    fn koefisien_nol() {
+        let (himpunan, _) = ptlsv_proses(Pecahan::NOL, Pecahan::SATU, Relasi::Lebih).unwrap();
+        assert_eq!(himpunan, Himpunan::Semua);
+        let (himpunan, _) = ptlsv_proses(Pecahan::NOL, Pecahan::NOL, Relasi::Kurang).unwrap();
+        assert_eq!(himpunan, Himpunan::Kosong);
+        assert_eq!(himpunan.notasi_selang(P), "∅");
+    }
+
+    #[test]
+    fn relasi() {
+        for r in Relasi::SEMUA {
+            assert_eq!(r.balik().balik(), r);
+            assert!(r.tutup().tertutup());
+            assert_eq!(r.geser(false).geser(true), r);
+        }
+        assert!(Relasi::KurangSama.dipenuhi(Pecahan::SATU, Pecahan::SATU));
+        assert!(!Relasi::Kurang.dipenuhi(Pecahan::SATU, Pecahan::SATU));
+    }
+}
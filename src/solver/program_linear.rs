@@ -0,0 +1,833 @@
+use crate::solver::daerah::{
+    Batasan, Daerah, JenisDaerah, arah_tak_terbatas, daerah_proses, huruf,
+};
+use crate::solver::format::{Teks, hubungan, kombinasi_linear, kurung};
+use crate::solver::pecahan::{Hitung, Pecahan, Tampilan};
+use crate::solver::pertidaksamaan::Relasi;
+
+/// Kendala a1x1 + ... + anxn (≤ atau ≥) b, disimpan sebagai [a1, ..., an, b]
+pub type Kendala = (Vec<Pecahan>, Relasi);
+
+/// Kesimpulan program linear
+#[derive(Clone, Debug, PartialEq)]
+pub enum Optimum {
+    /// Nilai optimum beserta titik-titik yang mencapainya. Lebih dari satu titik
+    /// berarti optimum ganda; `sinar` adalah arah sepanjang mana nilai optimum
+    /// tetap tercapai pada daerah yang tidak terbatas.
+    Ada {
+        nilai: Pecahan,
+        titik: Vec<Vec<Pecahan>>,
+        sinar: Option<Vec<Pecahan>>,
+    },
+    TidakTerbatas,
+    TidakLayak,
+    /// Simpleks berhenti karena batas iterasi sebelum tabel optimal
+    TidakKonvergen,
+}
+
+// Batas iterasi simpleks. Dengan aturan Bland tabel tidak berputar, jadi
+// batas ini hanya menjaga masalah yang sangat besar.
+const MAKS_ITERASI: usize = 50;
+
+// Akhir iterasi tabel simpleks
+#[derive(Clone, Copy, Debug, PartialEq)]
+enum HasilIterasi {
+    Optimal,
+    TakTerbatas,
+    BatasIterasi,
+}
+
+/// Nama variabel keputusan: x, y untuk dua variabel, x1, x2, ... untuk lebih
+pub fn nama_variabel(n: usize) -> Vec<String> {
+    if n == 2 {
+        vec!["x".to_string(), "y".to_string()]
+    } else {
+        (1..=n).map(|i| format!("x{}", i)).collect()
+    }
+}
+
+// Menulis fungsi tujuan, mis. "3x + 2y + 5"
+fn tulis_tujuan(tujuan: &[Pecahan], nama: &[&str], t: Tampilan) -> String {
+    let (koef, k) = tujuan.split_at(nama.len());
+    let suku: Vec<(Pecahan, &str)> = koef.iter().copied().zip(nama.iter().copied()).collect();
+    let tanpa_k = kombinasi_linear(Pecahan::NOL, &suku, t);
+    match k[0] {
+        k if k.is_nol() => tanpa_k,
+        k if k.is_negatif() => format!("{} - {}", tanpa_k, (-k).tampil(t)),
+        k => format!("{} + {}", tanpa_k, k.tampil(t)),
+    }
+}
+
+// Nilai fungsi tujuan di suatu titik
+fn nilai_tujuan(tujuan: &[Pecahan], titik: &[Pecahan]) -> Hitung<Pecahan> {
+    let n = titik.len();
+    Pecahan::jumlah_perkalian(tujuan[..n].iter().copied().zip(titik.iter().copied()))?
+        .tambah(tujuan[n])
+}
+
+//
+// Program Linear
+// Maksimumkan atau minimumkan Z = c1x1 + ... + cnxn + k terhadap kendala
+// linear ≤ / ≥ dengan x1, ..., xn ≥ 0. Dua variabel diselesaikan dengan
+// metode titik pojok, lebih dari dua variabel dengan metode simpleks dua fase.
+//
+pub fn program_linear_proses(
+    tujuan: &[Pecahan],
+    kendala: &[Kendala],
+    maksimum: bool,
+) -> Hitung<(Optimum, Option<Daerah>, Teks)> {
+    let n = tujuan.len() - 1;
+    let nama = nama_variabel(n);
+    let nama: Vec<&str> = nama.iter().map(String::as_str).collect();
+    let mut steps = Teks::dari(|t| {
+        format!(
+            "Fungsi tujuan: {} Z = {}\n",
+            if maksimum {
+                "maksimumkan"
+            } else {
+                "minimumkan"
+            },
+            tulis_tujuan(tujuan, &nama, t)
+        )
+    });
+
+    if n == 2 {
+        let (optimum, daerah) = titik_pojok(tujuan, kendala, maksimum, &mut steps)?;
+        return Ok((optimum, Some(daerah), steps));
+    }
+
+    steps.push_str("Kendala:\n");
+    for (i, (k, r)) in kendala.iter().enumerate() {
+        let suku: Vec<(Pecahan, &str)> = k.iter().copied().zip(nama.iter().copied()).collect();
+        steps.tulis(|t| format!("  K{}: {}\n", i + 1, hubungan(&suku, r.simbol(), k[n], t)));
+    }
+    steps.tulis(|_| format!("  {} ≥ 0\n", nama.join(", ")));
+    let optimum = simpleks(tujuan, kendala, maksimum, &nama, &mut steps)?;
+    Ok((optimum, None, steps))
+}
+
+// Metode titik pojok untuk dua variabel: gambar daerah penyelesaian
+// (termasuk x ≥ 0 dan y ≥ 0), lalu uji setiap titik pojok pada fungsi tujuan
+fn titik_pojok(
+    tujuan: &[Pecahan],
+    kendala: &[Kendala],
+    maksimum: bool,
+    steps: &mut Teks,
+) -> Hitung<(Optimum, Daerah)> {
+    let (nol, satu) = (Pecahan::NOL, Pecahan::SATU);
+    let mut batasan: Vec<Batasan> = kendala
+        .iter()
+        .map(|(k, r)| ([k[0], k[1], k[2]], *r))
+        .collect();
+    batasan.push(([satu, nol, nol], Relasi::LebihSama));
+    batasan.push(([nol, satu, nol], Relasi::LebihSama));
+
+    let (daerah, langkah) = daerah_proses(&batasan)?;
+    steps.push('\n');
+    steps.sambung(&langkah);
+    let kata = if maksimum { "maksimum" } else { "minimum" };
+
+    if daerah.jenis == JenisDaerah::Kosong {
+        steps.tulis(|_| {
+            format!(
+                "\nTidak ada titik yang memenuhi semua kendala, sehingga tidak ada nilai {}.\n",
+                kata
+            )
+        });
+        return Ok((Optimum::TidakLayak, daerah));
+    }
+
+    let (p, q) = (tujuan[0], tujuan[1]);
+    let rumus = |t| tulis_tujuan(tujuan, &["x", "y"], t);
+    steps.push_str("\nLangkah 4: Uji titik pojok pada fungsi tujuan\n");
+    let label = |i: usize, t| {
+        let ((x, y), _) = daerah.pojok[i];
+        format!("{}({}, {})", huruf(i), x.tampil(t), y.tampil(t))
+    };
+    let nilai: Vec<Pecahan> = daerah
+        .pojok
+        .iter()
+        .map(|((x, y), _)| nilai_tujuan(tujuan, &[*x, *y]))
+        .collect::<Hitung<_>>()?;
+    steps.tulis(|t| {
+        let lebar = (0..nilai.len())
+            .map(|i| label(i, t).chars().count())
+            .chain(std::iter::once("Titik pojok".len()))
+            .max()
+            .unwrap_or(0);
+        let mut tabel = vec![format!("{:<lebar$} │ Z = {}", "Titik pojok", rumus(t))];
+        for (i, ((x, y), _)) in daerah.pojok.iter().enumerate() {
+            let mut hitung = format!(
+                "{} × {} + {} × {}",
+                kurung(p, t),
+                kurung(*x, t),
+                kurung(q, t),
+                kurung(*y, t)
+            );
+            if !tujuan[2].is_nol() {
+                hitung.push_str(&format!(" + {}", kurung(tujuan[2], t)));
+            }
+            tabel.push(format!(
+                "{:<lebar$} │ {} = {}",
+                label(i, t),
+                hitung,
+                nilai[i].tampil(t)
+            ));
+        }
+        // Semua baris dibuat sama panjang agar kolomnya tetap lurus saat ditampilkan di tengah
+        let panjang = tabel.iter().map(|b| b.chars().count()).max().unwrap_or(0);
+        tabel
+            .iter()
+            .map(|b| format!("  {:<panjang$}\n", b))
+            .collect::<String>()
+    });
+
+    // Z tidak terbatas jika bertambah (atau berkurang) ke arah tak terbatas daerah
+    let arah = arah_tak_terbatas(&batasan)?;
+    // Laju perubahan Z ke setiap arah tak terbatas
+    let laju: Vec<Pecahan> = arah
+        .iter()
+        .map(|&(dx, dy)| p.kali(dx)?.tambah(q.kali(dy)?))
+        .collect::<Hitung<_>>()?;
+    let membaik = |laju: Pecahan| {
+        if maksimum {
+            laju > Pecahan::NOL
+        } else {
+            laju < Pecahan::NOL
+        }
+    };
+    if let Some(&(dx, dy)) = arah
+        .iter()
+        .zip(&laju)
+        .find(|(_, l)| membaik(**l))
+        .map(|(d, _)| d)
+    {
+        steps.tulis(|t| {
+            format!(
+                "\nDaerah tidak terbatas dan Z terus {} ke arah ({}, {}),\n\
+             sehingga tidak ada nilai {}.\n",
+                if maksimum { "bertambah" } else { "berkurang" },
+                dx.tampil(t),
+                dy.tampil(t),
+                kata
+            )
+        });
+        return Ok((Optimum::TidakTerbatas, daerah));
+    }
+
+    let terbaik = if maksimum {
+        nilai.iter().max()
+    } else {
+        nilai.iter().min()
+    }
+    .copied()
+    .unwrap_or(Pecahan::NOL);
+    let optimal: Vec<usize> = (0..nilai.len()).filter(|&i| nilai[i] == terbaik).collect();
+    steps.tulis(|t| {
+        format!(
+            "\nNilai {} Z = {} dicapai di {}.\n",
+            kata,
+            terbaik.tampil(t),
+            optimal
+                .iter()
+                .map(|&i| label(i, t))
+                .collect::<Vec<_>>()
+                .join(" dan ")
+        )
+    });
+    if optimal.len() > 1 {
+        steps.tulis(|t| {
+            format!(
+                "Optimum ganda: setiap titik pada ruas garis di antara titik-titik\n\
+             tersebut juga menghasilkan Z = {}.\n",
+                terbaik.tampil(t)
+            )
+        });
+    }
+    let sinar = arah
+        .iter()
+        .zip(&laju)
+        .find(|(_, l)| l.is_nol())
+        .map(|(&(dx, dy), _)| vec![dx, dy]);
+    if let Some(d) = &sinar {
+        steps.tulis(|t| {
+            format!(
+                "Daerah tidak terbatas dan Z tetap ke arah ({}, {}), sehingga Z = {}\n\
+             juga dicapai di sepanjang sinar dari {} ke arah tersebut.\n",
+                d[0].tampil(t),
+                d[1].tampil(t),
+                terbaik.tampil(t),
+                label(optimal[0], t)
+            )
+        });
+    }
+
+    let titik = optimal
+        .iter()
+        .map(|&i| {
+            let ((x, y), _) = daerah.pojok[i];
+            vec![x, y]
+        })
+        .collect();
+    let optimum = Optimum::Ada {
+        nilai: terbaik,
+        titik,
+        sinar,
+    };
+    Ok((optimum, daerah))
+}
+
+// Tabel simpleks: setiap baris berisi koefisien lalu nilai kanan (NK)
+#[derive(Clone)]
+struct Tabel {
+    kolom: Vec<String>,
+    baris: Vec<Vec<Pecahan>>,
+    basis: Vec<usize>,
+    tujuan: Vec<Pecahan>,
+    nama_tujuan: &'static str,
+    /// Kolom kunci dipilih dengan aturan Bland setelah pivot degenerasi
+    bland: bool,
+}
+
+impl Tabel {
+    fn tulis(&self, t: Tampilan) -> String {
+        let mut sel: Vec<Vec<String>> = vec![
+            std::iter::once("Basis".to_string())
+                .chain(self.kolom.iter().cloned())
+                .chain(std::iter::once("NK".to_string()))
+                .collect(),
+        ];
+        for (b, &j) in self.baris.iter().zip(&self.basis) {
+            sel.push(
+                std::iter::once(self.kolom[j].clone())
+                    .chain(b.iter().map(|v| v.tampil(t).to_string()))
+                    .collect(),
+            );
+        }
+        sel.push(
+            std::iter::once(self.nama_tujuan.to_string())
+                .chain(self.tujuan.iter().map(|v| v.tampil(t).to_string()))
+                .collect(),
+        );
+
+        let lebar: Vec<usize> = (0..sel[0].len())
+            .map(|j| sel.iter().map(|b| b[j].chars().count()).max().unwrap_or(0))
+            .collect();
+        let akhir = lebar.len() - 1;
+        let mut hasil = String::new();
+        for (i, b) in sel.iter().enumerate() {
+            let tengah: Vec<String> = (1..akhir)
+                .map(|j| format!("{:>w$}", b[j], w = lebar[j]))
+                .collect();
+            hasil.push_str(&format!(
+                "  {:<w0$} │ {} │ {:>wn$}\n",
+                b[0],
+                tengah.join("  "),
+                b[akhir],
+                w0 = lebar[0],
+                wn = lebar[akhir]
+            ));
+            // Garis pemisah di bawah judul dan di atas baris tujuan
+            if i == 0 || i == sel.len() - 2 {
+                let tengah: usize = lebar[1..akhir].iter().sum::<usize>() + 2 * (akhir - 2);
+                hasil.push_str(&format!(
+                    "  {}─┼─{}─┼─{}\n",
+                    "─".repeat(lebar[0]),
+                    "─".repeat(tengah),
+                    "─".repeat(lebar[akhir])
+                ));
+            }
+        }
+        hasil
+    }
+
+    fn pivot(&mut self, r: usize, c: usize) -> Hitung<()> {
+        let kunci = self.baris[r][c];
+        for v in &mut self.baris[r] {
+            *v = v.bagi(kunci)?;
+        }
+        let baris_kunci = self.baris[r].clone();
+        for (i, b) in self.baris.iter_mut().enumerate() {
+            let f = b[c];
+            if i != r && !f.is_nol() {
+                for (v, k) in b.iter_mut().zip(&baris_kunci) {
+                    *v = v.kurang(f.kali(*k)?)?;
+                }
+            }
+        }
+        let f = self.tujuan[c];
+        if !f.is_nol() {
+            for (v, k) in self.tujuan.iter_mut().zip(&baris_kunci) {
+                *v = v.kurang(f.kali(*k)?)?;
+            }
+        }
+        self.basis[r] = c;
+        Ok(())
+    }
+
+    // Baris kunci dengan rasio NK / elemen positif terkecil, beserta keterangan
+    // rasionya. Rasio yang sama dipilih yang variabel basisnya berindeks
+    // terkecil (aturan Bland).
+    fn baris_kunci(&self, c: usize) -> Hitung<(Option<usize>, Teks)> {
+        let mut terpilih: Option<(usize, Pecahan)> = None;
+        let mut semua: Vec<Option<Pecahan>> = Vec::new();
+        for (i, b) in self.baris.iter().enumerate() {
+            let a = b[c];
+            if a > Pecahan::NOL {
+                let r = b.last().unwrap().bagi(a)?;
+                if terpilih.is_none_or(|(t, rt)| r < rt || r == rt && self.basis[i] < self.basis[t])
+                {
+                    terpilih = Some((i, r));
+                }
+                semua.push(Some(r));
+            } else {
+                semua.push(None);
+            }
+        }
+        let rasio = Teks::dari(|t| {
+            self.baris
+                .iter()
+                .zip(&semua)
+                .enumerate()
+                .map(|(i, (b, r))| {
+                    let (a, nk) = (b[c], *b.last().unwrap());
+                    let nama = &self.kolom[self.basis[i]];
+                    match r {
+                        Some(r) => format!(
+                            "{}: {} / {} = {}",
+                            nama,
+                            nk.tampil(t),
+                            kurung(a, t),
+                            r.tampil(t)
+                        ),
+                        None => format!("{}: —", nama),
+                    }
+                })
+                .collect::<Vec<_>>()
+                .join(", ")
+        });
+        Ok((terpilih.map(|(i, _)| i), rasio))
+    }
+
+    // Nilai setiap kolom pada penyelesaian basis saat ini
+    fn penyelesaian(&self) -> Vec<Pecahan> {
+        let mut x = vec![Pecahan::NOL; self.kolom.len()];
+        for (b, &j) in self.baris.iter().zip(&self.basis) {
+            x[j] = *b.last().unwrap();
+        }
+        x
+    }
+
+    // Iterasi sampai baris tujuan tidak memiliki nilai negatif pada `aktif`
+    // kolom pertama. Kolom kunci biasanya yang paling negatif; setelah pivot
+    // degenerasi (rasio 0) dipakai aturan Bland agar tabel tidak berputar.
+    fn jalankan(
+        &mut self,
+        aktif: usize,
+        iterasi: &mut usize,
+        steps: &mut Teks,
+    ) -> Hitung<HasilIterasi> {
+        loop {
+            let negatif = (0..aktif).filter(|&j| self.tujuan[j] < Pecahan::NOL);
+            let masuk = if self.bland {
+                negatif.min()
+            } else {
+                negatif.min_by(|&i, &j| self.tujuan[i].cmp(&self.tujuan[j]).then(i.cmp(&j)))
+            };
+            let Some(c) = masuk else {
+                steps.tulis(|_| {
+                    format!(
+                        "  Tidak ada nilai negatif pada baris {} → tabel sudah optimal.\n",
+                        self.nama_tujuan
+                    )
+                });
+                return Ok(HasilIterasi::Optimal);
+            };
+            if *iterasi >= MAKS_ITERASI {
+                steps.tulis(|_| {
+                    format!(
+                        "\nIterasi dihentikan setelah {} iterasi, padahal baris {} masih\n\
+                     memiliki nilai negatif → tabel belum optimal.\n",
+                        MAKS_ITERASI, self.nama_tujuan
+                    )
+                });
+                return Ok(HasilIterasi::BatasIterasi);
+            }
+            *iterasi += 1;
+            steps.tulis(|t| {
+                format!(
+                    "\nIterasi {}:\n  Kolom kunci: {} ({} pada baris {}: {})\n",
+                    iterasi,
+                    self.kolom[c],
+                    if self.bland {
+                        "aturan Bland, kolom pertama yang negatif"
+                    } else {
+                        "nilai paling negatif"
+                    },
+                    self.nama_tujuan,
+                    self.tujuan[c].tampil(t)
+                )
+            });
+            let (keluar, rasio) = self.baris_kunci(c)?;
+            steps.tulis(|t| format!("  Rasio: {}\n", rasio.tampil(t)));
+            let Some(r) = keluar else {
+                steps.tulis(|_| {
+                    format!(
+                        "  Kolom {} tidak memiliki elemen positif, sehingga {} bisa\n\
+                     \x20 diperbesar tanpa batas → tujuan tidak terbatas.\n",
+                        self.kolom[c], self.kolom[c]
+                    )
+                });
+                return Ok(HasilIterasi::TakTerbatas);
+            };
+            steps.tulis(|t| format!(
+                "  Baris kunci: {} (rasio terkecil), elemen kunci: {}\n  {} masuk basis, {} keluar basis\n\n",
+                self.kolom[self.basis[r]],
+                self.baris[r][c].tampil(t),
+                self.kolom[c],
+                self.kolom[self.basis[r]]
+            ));
+            if !self.bland && self.baris[r].last().unwrap().is_nol() {
+                self.bland = true;
+                steps.push_str(
+                    "  Rasio 0: pivot degenerasi, nilai tujuan tidak berubah. Mulai\n\
+                     \x20 iterasi berikutnya kolom kunci dipilih dengan aturan Bland\n\
+                     \x20 (kolom negatif pertama) agar tabel tidak berputar.\n\n",
+                );
+            }
+            self.pivot(r, c)?;
+            steps.tulis(|t| self.tulis(t));
+        }
+    }
+}
+
+// Metode simpleks dua fase. Kendala ≤ mendapat variabel slack, kendala ≥
+// mendapat variabel surplus dan variabel buatan yang dihilangkan pada fase 1.
+fn simpleks(
+    tujuan: &[Pecahan],
+    kendala: &[Kendala],
+    maksimum: bool,
+    nama: &[&str],
+    steps: &mut Teks,
+) -> Hitung<Optimum> {
+    let n = nama.len();
+    let m = kendala.len();
+    let kata = if maksimum { "maksimum" } else { "minimum" };
+
+    // Ruas kanan harus tidak negatif
+    let mut kendala: Vec<Kendala> = kendala.to_vec();
+    for (i, (k, r)) in kendala.iter_mut().enumerate() {
+        if k[n].is_negatif() {
+            for v in k.iter_mut() {
+                *v = -*v;
+            }
+            *r = r.balik();
+            steps.tulis(|_| {
+                format!(
+                    "K{} dikalikan -1 agar ruas kanannya tidak negatif (tanda dibalik).\n",
+                    i + 1
+                )
+            });
+        }
+    }
+
+    let buatan: Vec<usize> = (0..m)
+        .filter(|&i| kendala[i].1.tutup() == Relasi::LebihSama)
+        .collect();
+    let mut kolom: Vec<String> = nama.iter().map(|s| s.to_string()).collect();
+    kolom.extend((1..=m).map(|i| format!("s{}", i)));
+    kolom.extend(buatan.iter().map(|i| format!("a{}", i + 1)));
+    let lebar = n + m + buatan.len();
+
+    steps.push_str("\nBentuk baku (s = slack/surplus, a = variabel buatan):\n");
+    let mut baris = Vec::new();
+    let mut basis = Vec::new();
+    for (i, (k, r)) in kendala.iter().enumerate() {
+        let mut b = vec![Pecahan::NOL; lebar + 1];
+        b[..n].copy_from_slice(&k[..n]);
+        b[lebar] = k[n];
+        let ge = r.tutup() == Relasi::LebihSama;
+        b[n + i] = if ge { -Pecahan::SATU } else { Pecahan::SATU };
+        if let Some(p) = buatan.iter().position(|&j| j == i) {
+            b[n + m + p] = Pecahan::SATU;
+            basis.push(n + m + p);
+        } else {
+            basis.push(n + i);
+        }
+        let suku: Vec<(Pecahan, &str)> = b[..lebar]
+            .iter()
+            .copied()
+            .zip(kolom.iter().map(String::as_str))
+            .collect();
+        steps.tulis(|t| format!("  K{}: {}\n", i + 1, hubungan(&suku, "=", b[lebar], t)));
+        baris.push(b);
+    }
+    if !maksimum {
+        steps.push_str("Meminimumkan Z sama dengan memaksimumkan Z' = -Z.\n");
+    }
+
+    let mut tabel = Tabel {
+        kolom,
+        baris,
+        basis,
+        tujuan: vec![Pecahan::NOL; lebar + 1],
+        nama_tujuan: "W",
+        bland: false,
+    };
+    let mut iterasi = 0;
+
+    // Fase 1: maksimumkan W = -(jumlah variabel buatan) hingga semuanya nol
+    if !buatan.is_empty() {
+        steps.push_str(
+            "\nFase 1: hilangkan variabel buatan dengan memaksimumkan W = -(a1 + a2 + ...)\n\
+             Baris W dikurangi setiap baris yang basisnya variabel buatan.\n\n",
+        );
+        for j in n + m..lebar {
+            tabel.tujuan[j] = Pecahan::SATU;
+        }
+        for i in 0..m {
+            if tabel.basis[i] >= n + m {
+                let b = tabel.baris[i].clone();
+                for (v, k) in tabel.tujuan.iter_mut().zip(&b) {
+                    *v = v.kurang(*k)?;
+                }
+            }
+        }
+        steps.tulis(|t| tabel.tulis(t));
+        if tabel.jalankan(lebar, &mut iterasi, steps)? == HasilIterasi::BatasIterasi {
+            steps.tulis(|_| format!("Tidak konvergen: nilai {} tidak ditemukan.\n", kata));
+            return Ok(Optimum::TidakKonvergen);
+        }
+        if tabel.tujuan[lebar] < Pecahan::NOL {
+            steps.tulis(|t| {
+                format!(
+                    "\nNilai W optimum = {} < 0: variabel buatan tidak bisa dihilangkan.\n\
+                 Tidak ada titik yang memenuhi semua kendala, sehingga tidak ada nilai {}.\n",
+                    tabel.tujuan[lebar].tampil(t),
+                    kata
+                )
+            });
+            return Ok(Optimum::TidakLayak);
+        }
+
+        // Variabel buatan yang masih menjadi basis (bernilai nol) dikeluarkan
+        let mut i = 0;
+        while i < tabel.baris.len() {
+            if tabel.basis[i] >= n + m {
+                match (0..n + m).find(|&j| !tabel.baris[i][j].is_nol()) {
+                    Some(j) => tabel.pivot(i, j)?,
+                    None => {
+                        steps.tulis(|_| format!(
+                            "Baris {} hanya berisi variabel buatan → kendala berlebih, dihapus.\n",
+                            tabel.kolom[tabel.basis[i]]
+                        ));
+                        tabel.baris.remove(i);
+                        tabel.basis.remove(i);
+                        continue;
+                    }
+                }
+            }
+            i += 1;
+        }
+        for b in &mut tabel.baris {
+            let nk = b[lebar];
+            b.truncate(n + m);
+            b.push(nk);
+        }
+        tabel.kolom.truncate(n + m);
+        steps.push_str("\nW = 0: semua variabel buatan bernilai nol dan kolomnya dihapus.\n");
+    }
+
+    // Fase 2: baris Z berisi -c (atau c untuk minimum), lalu dinolkan pada kolom basis
+    let lebar = n + m;
+    let tanda = |c: Pecahan| if maksimum { -c } else { c };
+    tabel.tujuan = tujuan[..n]
+        .iter()
+        .map(|c| tanda(*c))
+        .chain(std::iter::repeat_n(Pecahan::NOL, m + 1))
+        .collect();
+    for i in 0..tabel.baris.len() {
+        let f = tabel.tujuan[tabel.basis[i]];
+        if !f.is_nol() {
+            let b = tabel.baris[i].clone();
+            for (v, k) in tabel.tujuan.iter_mut().zip(&b) {
+                *v = v.kurang(f.kali(*k)?)?;
+            }
+        }
+    }
+    tabel.nama_tujuan = if maksimum { "Z" } else { "Z'" };
+    steps.tulis(|t| {
+        format!(
+            "\n{}Tabel awal (baris {}: {} - ({}) = 0):\n\n",
+            if buatan.is_empty() { "" } else { "Fase 2: " },
+            tabel.nama_tujuan,
+            tabel.nama_tujuan,
+            tulis_tujuan(
+                &tujuan[..n]
+                    .iter()
+                    .map(|c| -tanda(*c))
+                    .chain(std::iter::once(Pecahan::NOL))
+                    .collect::<Vec<_>>(),
+                nama,
+                t,
+            )
+        )
+    });
+    steps.tulis(|t| tabel.tulis(t));
+    match tabel.jalankan(lebar, &mut iterasi, steps)? {
+        HasilIterasi::Optimal => {}
+        HasilIterasi::TakTerbatas => {
+            steps.tulis(|_| format!("Tidak ada nilai {}.\n", kata));
+            return Ok(Optimum::TidakTerbatas);
+        }
+        HasilIterasi::BatasIterasi => {
+            steps.tulis(|_| format!("Tidak konvergen: nilai {} tidak ditemukan.\n", kata));
+            return Ok(Optimum::TidakKonvergen);
+        }
+    }
+
+    let x = tabel.penyelesaian();
+    let nilai = nilai_tujuan(tujuan, &x[..n])?;
+    steps.push_str("\nPenyelesaian optimum:\n");
+    steps.tulis(|t| {
+        let isi: Vec<String> = (0..n)
+            .map(|j| format!("{} = {}", nama[j], x[j].tampil(t)))
+            .collect();
+        format!("  {}\n", isi.join(", "))
+    });
+    let z = if maksimum {
+        tabel.tujuan[lebar]
+    } else {
+        steps.tulis(|t| format!("  Z' = {}\n", tabel.tujuan[lebar].tampil(t)));
+        -tabel.tujuan[lebar]
+    };
+    if tujuan[n].is_nol() {
+        steps.tulis(|t| format!("  Z = {}\n", nilai.tampil(t)));
+    } else {
+        steps.tulis(|t| {
+            format!(
+                "  Z = {} + {} = {}\n",
+                z.tampil(t),
+                kurung(tujuan[n], t),
+                nilai.tampil(t)
+            )
+        });
+    }
+
+    // Variabel nonbasis dengan nilai nol pada baris tujuan menandakan optimum ganda
+    let mut titik = vec![x[..n].to_vec()];
+    for j in 0..lebar {
+        if tabel.basis.contains(&j) || !tabel.tujuan[j].is_nol() {
+            continue;
+        }
+        let (Some(r), _) = tabel.baris_kunci(j)? else {
+            continue;
+        };
+        let mut lain = tabel.clone();
+        lain.pivot(r, j)?;
+        let y = lain.penyelesaian();
+        if y[..n] != x[..n] {
+            steps.tulis(|t| {
+                format!(
+                    "\nNilai {} pada baris {} adalah 0 padahal {} bukan basis.\n\
+                 Memasukkan {} ke basis menghasilkan titik optimum lain:\n  {}\n\
+                 Optimum ganda: setiap titik pada ruas garis di antara keduanya juga optimal.\n",
+                    tabel.kolom[j],
+                    tabel.nama_tujuan,
+                    tabel.kolom[j],
+                    tabel.kolom[j],
+                    (0..n)
+                        .map(|k| format!("{} = {}", nama[k], y[k].tampil(t)))
+                        .collect::<Vec<_>>()
+                        .join(", ")
+                )
+            });
+            titik.push(y[..n].to_vec());
+            break;
+        }
+    }
+
+    Ok(Optimum::Ada {
+        nilai,
+        titik,
+        sinar: None,
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::solver::pecahan::uji::p;
+
+    fn kendala(koef: &[i128], r: Relasi) -> Kendala {
+        (koef.iter().map(|&k| Pecahan::from(k)).collect(), r)
+    }
+
+    fn nilai(optimum: &Optimum) -> Option<Pecahan> {
+        match optimum {
+            Optimum::Ada { nilai, .. } => Some(*nilai),
+            _ => None,
+        }
+    }
+
+    #[test]
+    fn contoh_beale_tidak_berputar() {
+        // Contoh klasik yang berputar dengan kolom paling negatif saja
+        let tujuan = [p(3, 4), p(-20, 1), p(1, 2), p(-6, 1), Pecahan::NOL];
+        let kendala = [
+            (
+                vec![p(1, 4), p(-8, 1), p(-1, 1), p(9, 1), Pecahan::NOL],
+                Relasi::KurangSama,
+            ),
+            (
+                vec![p(1, 2), p(-12, 1), p(-1, 2), p(3, 1), Pecahan::NOL],
+                Relasi::KurangSama,
+            ),
+            kendala(&[0, 0, 1, 0, 1], Relasi::KurangSama),
+        ];
+        let (optimum, _, steps) = program_linear_proses(&tujuan, &kendala, true).unwrap();
+        assert_eq!(nilai(&optimum), Some(p(5, 4)));
+        assert!(steps.pecahan.teks().contains("aturan Bland"));
+    }
+
+    #[test]
+    fn simpleks_degenerasi() {
+        let tujuan = [1, 1, 1, 0].map(Pecahan::from);
+        let kendala = [
+            kendala(&[1, 0, 0, 1], Relasi::KurangSama),
+            kendala(&[0, 1, 0, 1], Relasi::KurangSama),
+            kendala(&[1, 1, 0, 2], Relasi::KurangSama),
+            kendala(&[0, 0, 1, 0], Relasi::KurangSama),
+        ];
+        let (optimum, _, _) = program_linear_proses(&tujuan, &kendala, true).unwrap();
+        assert_eq!(nilai(&optimum), Some(Pecahan::from(2)));
+    }
+
+    #[test]
+    fn titik_pojok_degenerasi() {
+        // Tiga garis batas melalui (1, 1)
+        let tujuan = [1, 1, 0].map(Pecahan::from);
+        let kendala = [
+            kendala(&[1, 0, 1], Relasi::KurangSama),
+            kendala(&[0, 1, 1], Relasi::KurangSama),
+            kendala(&[1, 1, 2], Relasi::KurangSama),
+        ];
+        let (optimum, daerah, _) = program_linear_proses(&tujuan, &kendala, true).unwrap();
+        assert_eq!(nilai(&optimum), Some(Pecahan::from(2)));
+        assert_eq!(daerah.unwrap().jenis, JenisDaerah::Terbatas);
+    }
+
+    #[test]
+    fn tidak_terbatas_dan_tidak_layak() {
+        let tujuan = [1, 1, 1, 0].map(Pecahan::from);
+        let terbuka = [kendala(&[1, -1, 0, 1], Relasi::KurangSama)];
+        let (optimum, _, _) = program_linear_proses(&tujuan, &terbuka, true).unwrap();
+        assert_eq!(optimum, Optimum::TidakTerbatas);
+        let bertentangan = [
+            kendala(&[1, 1, 1, 1], Relasi::KurangSama),
+            kendala(&[1, 1, 1, 2], Relasi::LebihSama),
+        ];
+        let (optimum, _, _) = program_linear_proses(&tujuan, &bertentangan, true).unwrap();
+        assert_eq!(optimum, Optimum::TidakLayak);
+    }
+}
@@ -0,0 +1,328 @@
+use crate::solver::format::{Teks, kombinasi_linear, kurung, nilai_akhir};
+use crate::solver::kuadrat::{bentuk_akar, sederhana_akar};
+use crate::solver::pecahan::{Hitung, Pecahan, Tampilan, desimal};
+
+/// Batas pembilang, penyebut, dan bilangan di bawah akar agar bentuk eksak
+/// r dan R² masih ditampilkan, mis. √6/3 atau 2/3
+const BATAS_RINGKAS: i128 = 100;
+
+// Bentuk eksak q√r cukup ringkas untuk dibaca
+fn ringkas(q: Pecahan, r: i128) -> bool {
+    q.pembilang().abs() <= BATAS_RINGKAS && q.penyebut() <= BATAS_RINGKAS && r <= BATAS_RINGKAS
+}
+
+/// Garis regresi y = mx + c beserta ukuran kecocokannya
+pub struct Regresi {
+    pub m: Pecahan,
+    pub c: Pecahan,
+    /// Koefisien determinasi R², `None` jika semua nilai y sama
+    pub r2: Option<Pecahan>,
+    /// Nilai r positif atau negatif (tanda kemiringan)
+    pub negatif: bool,
+    /// Titik prediksi (x, ŷ) jika nilai x diisi
+    pub prediksi: Option<(Pecahan, Pecahan)>,
+}
+
+impl Regresi {
+    /// Persamaan garis, mis. "y = (9/10)x + 9/10"
+    pub fn persamaan(&self, t: Tampilan) -> String {
+        if self.m.is_nol() {
+            return format!("y = {}", self.c.tampil(t));
+        }
+        let mut teks = format!(
+            "y = {}",
+            kombinasi_linear(Pecahan::NOL, &[(self.m, "x")], t)
+        );
+        if !self.c.is_nol() {
+            let tanda = if self.c.is_negatif() { "-" } else { "+" };
+            teks.push_str(&format!(" {} {}", tanda, self.c.abs().tampil(t)));
+        }
+        teks
+    }
+
+    /// Koefisien korelasi r = ±√R² sebagai desimal 4 angka, didahului
+    /// bentuk akar sederhana jika ringkas, mis. "√6/3 ≈ 0.8165"
+    pub fn r(&self, t: Tampilan) -> Hitung<Option<String>> {
+        let Some(r2) = self.r2 else {
+            return Ok(None);
+        };
+        let (q, r) = sederhana_akar(r2)?;
+        let tanda = if self.negatif && !r2.is_nol() {
+            "-"
+        } else {
+            ""
+        };
+        let nilai = desimal(r2.ke_f64().sqrt());
+        Ok(Some(if r == 1 && q.is_bulat() {
+            format!("{}{}", tanda, q.tampil(t))
+        } else if ringkas(q, r) && t == Tampilan::Pecahan {
+            let akar = bentuk_akar(Pecahan::NOL, q, r, false, "+", t)?;
+            format!("{}{} ≈ {}{}", tanda, akar, tanda, nilai)
+        } else {
+            format!("{}{}", tanda, nilai)
+        }))
+    }
+
+    /// Koefisien determinasi R² sebagai desimal 4 angka, didahului
+    /// pecahannya jika ringkas, mis. "2/3 ≈ 0.6667"
+    pub fn r2_teks(&self, t: Tampilan) -> Option<String> {
+        self.r2.map(|r2| {
+            if ringkas(r2, 1) {
+                nilai_akhir(r2, t)
+            } else {
+                r2.desimal()
+            }
+        })
+    }
+}
+
+// Tabel dengan kolom rata kanan dan garis pemisah di bawah judul dan di atas baris Σ
+fn tabel(judul: &[&str], isi: &[Vec<String>], jumlah: &[String]) -> String {
+    let lebar: Vec<usize> = (0..judul.len())
+        .map(|j| {
+            isi.iter()
+                .chain(std::iter::once(&jumlah.to_vec()))
+                .map(|b| b[j].chars().count())
+                .chain(std::iter::once(judul[j].chars().count()))
+                .max()
+                .unwrap_or(1)
+        })
+        .collect();
+    let baris = |sel: &[String]| {
+        let kolom: Vec<String> = sel
+            .iter()
+            .zip(&lebar)
+            .map(|(s, &w)| format!(" {:>w$} ", s))
+            .collect();
+        format!("  {}\n", kolom.join("│"))
+    };
+    let garis = format!(
+        "  {}\n",
+        lebar
+            .iter()
+            .map(|w| "─".repeat(w + 2))
+            .collect::<Vec<_>>()
+            .join("┼")
+    );
+    let judul: Vec<String> = judul.iter().map(|s| s.to_string()).collect();
+    let mut teks = baris(&judul);
+    teks.push_str(&garis);
+    for b in isi {
+        teks.push_str(&baris(b));
+    }
+    teks.push_str(&garis);
+    teks.push_str(&baris(jumlah));
+    teks
+}
+
+//
+// Regresi Linear (garis kuadrat terkecil)
+// Untuk n titik data (x, y), garis y = mx + c yang meminimumkan jumlah
+// kuadrat selisih memiliki
+//   m = (nΣxy − ΣxΣy) / (nΣx² − (Σx)²),   c = (Σy − mΣx) / n
+// dan koefisien korelasi
+//   r = (nΣxy − ΣxΣy) / √((nΣx² − (Σx)²)(nΣy² − (Σy)²)).
+//
+pub fn regresi_proses(
+    data: &[(Pecahan, Pecahan)],
+    prediksi: Option<Pecahan>,
+) -> Hitung<(Option<Regresi>, Teks)> {
+    let n = Pecahan::from(data.len() as i128);
+    // x², xy, dan y² setiap titik
+    let kali: Vec<[Pecahan; 3]> = data
+        .iter()
+        .map(|(x, y)| Ok([x.kali(*x)?, x.kali(*y)?, y.kali(*y)?]))
+        .collect::<Hitung<_>>()?;
+    let sx = Pecahan::jumlah(data.iter().map(|(x, _)| *x))?;
+    let sy = Pecahan::jumlah(data.iter().map(|(_, y)| *y))?;
+    let sxx = Pecahan::jumlah(kali.iter().map(|k| k[0]))?;
+    let sxy = Pecahan::jumlah(kali.iter().map(|k| k[1]))?;
+    let syy = Pecahan::jumlah(kali.iter().map(|k| k[2]))?;
+
+    let mut steps = Teks::dari(|_| format!("Tabel penjumlahan (n = {}):\n", data.len()));
+    steps.tulis(|t| {
+        let isi: Vec<Vec<String>> = data
+            .iter()
+            .zip(&kali)
+            .enumerate()
+            .map(|(i, ((x, y), [xx, xy, yy]))| {
+                vec![
+                    (i + 1).to_string(),
+                    x.tampil(t).to_string(),
+                    y.tampil(t).to_string(),
+                    xx.tampil(t).to_string(),
+                    xy.tampil(t).to_string(),
+                    yy.tampil(t).to_string(),
+                ]
+            })
+            .collect();
+        let jumlah = vec![
+            "Σ".to_string(),
+            sx.tampil(t).to_string(),
+            sy.tampil(t).to_string(),
+            sxx.tampil(t).to_string(),
+            sxy.tampil(t).to_string(),
+            syy.tampil(t).to_string(),
+        ];
+        tabel(&["i", "x", "y", "x²", "xy", "y²"], &isi, &jumlah)
+    });
+
+    let pembilang = n.kali(sxy)?.kurang(sx.kali(sy)?)?;
+    let sxx_n = n.kali(sxx)?.kurang(sx.kali(sx)?)?;
+    let syy_n = n.kali(syy)?.kurang(sy.kali(sy)?)?;
+    if data.len() < 2 || sxx_n.is_nol() {
+        steps.tulis(|t| {
+            format!(
+                "\nnΣx² − (Σx)² = {}·{} − {}² = 0, sehingga kemiringan tidak dapat dihitung.\n\
+             Diperlukan paling sedikit dua titik dengan nilai x berbeda.\n",
+                n.tampil(t),
+                kurung(sxx, t),
+                kurung(sx, t)
+            )
+        });
+        return Ok((None, steps));
+    }
+
+    let m = pembilang.bagi(sxx_n)?;
+    let c = sy.kurang(m.kali(sx)?)?.bagi(n)?;
+    steps.tulis(|t| format!(
+        "\nKemiringan:\n  m = (nΣxy − ΣxΣy) / (nΣx² − (Σx)²)\n    = ({}·{} − {}·{}) / ({}·{} − {}²)\n    = {} / {} = {}\n",
+        n.tampil(t),
+        kurung(sxy, t),
+        kurung(sx, t),
+        kurung(sy, t),
+        n.tampil(t),
+        kurung(sxx, t),
+        kurung(sx, t),
+        pembilang.tampil(t),
+        kurung(sxx_n, t),
+        m.tampil(t)
+    ));
+    steps.tulis(|t| {
+        format!(
+            "\nTitik potong sumbu y:\n  c = (Σy − mΣx) / n = ({} − {}·{}) / {} = {}\n",
+            sy.tampil(t),
+            kurung(m, t),
+            kurung(sx, t),
+            n.tampil(t),
+            c.tampil(t)
+        )
+    });
+
+    let mut hasil = Regresi {
+        m,
+        c,
+        r2: None,
+        negatif: pembilang.is_negatif(),
+        prediksi: None,
+    };
+    steps.tulis(|t| format!("\nGaris regresi: {}\n", hasil.persamaan(t)));
+
+    steps
+        .push_str("\nKoefisien korelasi:\n  r = (nΣxy − ΣxΣy) / √((nΣx² − (Σx)²)(nΣy² − (Σy)²))\n");
+    if syy_n.is_nol() {
+        steps.push_str(
+            "  nΣy² − (Σy)² = 0 (semua nilai y sama), sehingga r dan R² tidak terdefinisi.\n",
+        );
+    } else {
+        let r2 = pembilang.kali(pembilang)?.bagi(sxx_n.kali(syy_n)?)?;
+        hasil.r2 = Some(r2);
+        steps.coba_tulis(|t| {
+            Ok(format!(
+                "    = {} / √({}·{}) = {}\n  R² = r² = {}\n",
+                pembilang.tampil(t),
+                kurung(sxx_n, t),
+                kurung(syy_n, t),
+                hasil.r(t)?.unwrap_or_default(),
+                hasil.r2_teks(t).unwrap_or_default()
+            ))
+        })?;
+        steps.tulis(|_| {
+            format!(
+                "  Sekitar {}% variasi y dijelaskan oleh garis regresi.\n",
+                desimal(r2.ke_f64() * 100.0)
+            )
+        });
+    }
+
+    if let Some(x) = prediksi {
+        let y = m.kali(x)?.tambah(c)?;
+        hasil.prediksi = Some((x, y));
+        steps.tulis(|t| {
+            format!(
+                "\nPrediksi untuk x = {}:\n  ŷ = {}·{} + {} = {}\n",
+                x.tampil(t),
+                kurung(m, t),
+                kurung(x, t),
+                kurung(c, t),
+                y.tampil(t)
+            )
+        });
+    }
+    Ok((Some(hasil), steps))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const P: Tampilan = Tampilan::Pecahan;
+
+    fn data(titik: &[(i128, i128)]) -> Vec<(Pecahan, Pecahan)> {
+        titik
+            .iter()
+            .map(|&(x, y)| (Pecahan::from(x), Pecahan::from(y)))
+            .collect()
+    }
+
+    #[test]
+    fn garis_regresi_dan_prediksi() {
+        let titik = data(&[(1, 2), (2, 3), (3, 5), (4, 4), (5, 6)]);
+        let (hasil, _) = regresi_proses(&titik, Some(Pecahan::from(6))).unwrap();
+        let hasil = hasil.unwrap();
+        assert_eq!(
+            (hasil.m, hasil.c),
+            (Pecahan::new(9, 10), Pecahan::new(13, 10))
+        );
+        assert_eq!(hasil.persamaan(P), "y = (9/10)x + 13/10");
+        assert_eq!(
+            hasil.prediksi,
+            Some((Pecahan::from(6), Pecahan::new(67, 10)))
+        );
+        assert_eq!(hasil.r(P).unwrap().as_deref(), Some("9/10 ≈ 0.9"));
+        assert_eq!(hasil.r2_teks(P).as_deref(), Some("81/100 ≈ 0.81"));
+    }
+
+    #[test]
+    fn korelasi_akar_ringkas_atau_desimal() {
+        // r = -2√5/5
+        let (hasil, _) = regresi_proses(&data(&[(0, 0), (1, 0), (2, -1), (3, -1)]), None).unwrap();
+        assert_eq!(
+            hasil.unwrap().r(P).unwrap().as_deref(),
+            Some("-2√5/5 ≈ -0.8944")
+        );
+
+        // Bentuk eksak yang panjang cukup ditulis desimal
+        let titik = data(&[(1, 23), (2, 31), (3, 59), (5, 44), (7, 67)]);
+        let r = regresi_proses(&titik, None)
+            .unwrap()
+            .0
+            .unwrap()
+            .r(P)
+            .unwrap()
+            .unwrap();
+        assert!(!r.contains('√'));
+    }
+
+    #[test]
+    fn data_tidak_cukup() {
+        assert!(
+            regresi_proses(&data(&[(2, 1), (2, 5)]), None)
+                .unwrap()
+                .0
+                .is_none()
+        );
+        let (hasil, _) = regresi_proses(&data(&[(1, 4), (2, 4), (3, 4)]), None).unwrap();
+        assert_eq!(hasil.unwrap().r(P), Ok(None));
+    }
+}
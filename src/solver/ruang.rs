@@ -0,0 +1,247 @@
+use crate::solver::format::{Teks, kombinasi_linear};
+use crate::solver::kalkulator::gauss_jordan;
+use crate::solver::matriks::{Kurung, tulis};
+use crate::solver::pecahan::{Hitung, Pecahan, Tampilan};
+
+/// Rank, nulitas, dan basis ruang-ruang fundamental matriks A (m × n)
+pub struct Ruang {
+    pub rank: usize,
+    pub nulitas: usize,
+    /// Baris tak nol bentuk eselon baris tereduksi A
+    pub basis_baris: Vec<Vec<Pecahan>>,
+    /// Kolom-kolom A pada posisi pivot
+    pub basis_kolom: Vec<Vec<Pecahan>>,
+    /// Satu vektor untuk setiap variabel bebas
+    pub basis_nol: Vec<Vec<Pecahan>>,
+}
+
+/// Vektor ditulis mendatar, mis. "(1, -2, 0)", dengan akhiran ᵀ jika `kolom`
+pub fn vektor(v: &[Pecahan], kolom: bool, t: Tampilan) -> String {
+    let isi: Vec<String> = v.iter().map(|x| x.tampil(t).to_string()).collect();
+    format!("({}){}", isi.join(", "), if kolom { "ᵀ" } else { "" })
+}
+
+/// Rentang basis, mis. "span{(1, 0)ᵀ, (0, 1)ᵀ}", atau {0} jika basisnya kosong
+pub fn span(basis: &[Vec<Pecahan>], kolom: bool, t: Tampilan) -> String {
+    if basis.is_empty() {
+        return "{0}".to_string();
+    }
+    let isi: Vec<String> = basis.iter().map(|v| vektor(v, kolom, t)).collect();
+    format!("span{{{}}}", isi.join(", "))
+}
+
+// ℝⁿ dengan pangkat superskrip; ukuran editor tidak lebih dari 9
+fn real(n: usize) -> String {
+    const SUPERSKRIP: [&str; 10] = ["⁰", "¹", "²", "³", "⁴", "⁵", "⁶", "⁷", "⁸", "⁹"];
+    format!("ℝ{}", SUPERSKRIP[n.min(9)])
+}
+
+// Daftar nomor kolom berbasis 1, mis. "1, 3"
+fn daftar(kolom: &[usize]) -> String {
+    kolom
+        .iter()
+        .map(|c| (c + 1).to_string())
+        .collect::<Vec<_>>()
+        .join(", ")
+}
+
+/// Basis ruang nol dari bentuk eselon baris tereduksi `r` dengan kolom
+/// `pivot`: satu vektor untuk setiap variabel bebas x_f, yaitu x_f = 1,
+/// variabel bebas lain 0, dan xₚ = −r_if untuk baris pivot i
+pub fn basis_nol(r: &[Vec<Pecahan>], pivot: &[usize]) -> Vec<Vec<Pecahan>> {
+    let n = r[0].len();
+    (0..n)
+        .filter(|f| !pivot.contains(f))
+        .map(|f| {
+            let mut v = vec![Pecahan::NOL; n];
+            v[f] = Pecahan::SATU;
+            for (i, &p) in pivot.iter().enumerate() {
+                v[p] = -r[i][f];
+            }
+            v
+        })
+        .collect()
+}
+
+//
+// Ruang Fundamental Matriks
+// A direduksi ke bentuk eselon baris tereduksi R dengan eliminasi Gauss–Jordan.
+// Baris tak nol R adalah basis Row(A), kolom A pada posisi pivot adalah basis
+// Col(A), dan penyelesaian umum Rx = 0 (satu vektor per variabel bebas)
+// adalah basis Null(A). Teorema Rank–Nulitas: rank(A) + nulitas(A) = n.
+//
+pub fn ruang_proses(a: &[Vec<Pecahan>]) -> Hitung<(Ruang, Teks)> {
+    let (m, n) = (a.len(), a[0].len());
+    let mut steps = Teks::default();
+    steps.gambar(|t| tulis("A = ", a, Kurung::Siku, &format!("  ({}×{})", m, n), t));
+
+    steps.push_str("\nReduksi A ke bentuk eselon baris tereduksi (RREF):\n");
+    let mut r = a.to_vec();
+    let (pivot, langkah) = gauss_jordan(&mut r, n, None, false)?;
+    steps.sambung(&langkah);
+    let bebas: Vec<usize> = (0..n).filter(|c| !pivot.contains(c)).collect();
+    let nama: Vec<String> = (1..=n).map(|i| format!("x{}", i)).collect();
+
+    steps.push('\n');
+    steps.gambar(|t| tulis("RREF(A) = ", &r, Kurung::Siku, "", t));
+    if pivot.is_empty() {
+        steps.push_str("Tidak ada pivot: A adalah matriks nol.\n");
+    } else {
+        steps.tulis(|_| format!("Kolom pivot: {}\n", daftar(&pivot)));
+    }
+    if bebas.is_empty() {
+        steps.push_str("Tidak ada variabel bebas.\n");
+    } else {
+        let nama_bebas: Vec<&str> = bebas.iter().map(|&c| nama[c].as_str()).collect();
+        steps.tulis(|_| format!("Variabel bebas: {}\n", nama_bebas.join(", ")));
+    }
+
+    let rank = pivot.len();
+    steps.tulis(|_| format!("\nRank:\n  rank(A) = banyak pivot = {}\n", rank));
+
+    // Ruang baris
+    let basis_baris: Vec<Vec<Pecahan>> = r[..rank].to_vec();
+    steps.tulis(|_| format!("\nRuang baris Row(A) ⊆ {}:\n", real(n)));
+    if rank > 0 {
+        steps.push_str("  Baris tak nol RREF(A) saling bebas linear dan merentang ruang baris\n");
+        steps.push_str("  (operasi baris elementer tidak mengubah ruang baris):\n");
+    }
+    steps.tulis(|t| format!("  Row(A) = {}\n", span(&basis_baris, false, t)));
+    steps.tulis(|_| format!("  dim Row(A) = {}\n", rank));
+
+    // Ruang kolom
+    let basis_kolom: Vec<Vec<Pecahan>> = pivot
+        .iter()
+        .map(|&c| a.iter().map(|b| b[c]).collect())
+        .collect();
+    steps.tulis(|_| format!("\nRuang kolom Col(A) ⊆ {}:\n", real(m)));
+    if rank > 0 {
+        steps.tulis(|_| {
+            format!(
+                "  Kolom A (bukan RREF) pada posisi pivot {} membentuk basis:\n",
+                daftar(&pivot)
+            )
+        });
+    }
+    steps.tulis(|t| format!("  Col(A) = {}\n", span(&basis_kolom, true, t)));
+    steps.tulis(|_| format!("  dim Col(A) = {}\n", rank));
+
+    // Ruang nol
+    steps.tulis(|_| format!("\nRuang nol Null(A) = {{x | Ax = 0}} ⊆ {}:\n", real(n)));
+    let basis_nol = basis_nol(&r, &pivot);
+    if bebas.is_empty() {
+        steps.push_str("  Setiap kolom memiliki pivot, sehingga Ax = 0 hanya memiliki\n");
+        steps.push_str("  penyelesaian trivial x = 0.\n");
+    } else {
+        steps.push_str("  Dari RREF(A)x = 0:\n");
+        for (i, &p) in pivot.iter().enumerate() {
+            let suku: Vec<(Pecahan, &str)> = bebas
+                .iter()
+                .map(|&f| (-r[i][f], nama[f].as_str()))
+                .collect();
+            steps.tulis(|t| {
+                format!(
+                    "    {} = {}\n",
+                    nama[p],
+                    kombinasi_linear(Pecahan::NOL, &suku, t)
+                )
+            });
+        }
+        let parameter: Vec<String> = if bebas.len() == 1 {
+            vec!["t".to_string()]
+        } else {
+            (1..=bebas.len()).map(|i| format!("t{}", i)).collect()
+        };
+        let misal: Vec<String> = bebas
+            .iter()
+            .zip(&parameter)
+            .map(|(&f, t)| format!("{} = {}", nama[f], t))
+            .collect();
+        let kombinasi: Vec<String> = parameter
+            .iter()
+            .enumerate()
+            .map(|(k, t)| format!("{}·v{}", t, k + 1))
+            .collect();
+        steps.tulis(|_| {
+            format!(
+                "  Misalkan {}:\n    x = {}, dengan\n",
+                misal.join(", "),
+                kombinasi.join(" + ")
+            )
+        });
+        for (k, v) in basis_nol.iter().enumerate() {
+            steps.tulis(|t| format!("    v{} = {}\n", k + 1, vektor(v, true, t)));
+        }
+        steps.push_str("  Periksa:\n");
+        for (k, v) in basis_nol.iter().enumerate() {
+            let av: Vec<Pecahan> = a
+                .iter()
+                .map(|b| Pecahan::jumlah_perkalian(b.iter().copied().zip(v.iter().copied())))
+                .collect::<Hitung<_>>()?;
+            steps.tulis(|t| {
+                format!(
+                    "    A·v{} = {} {}\n",
+                    k + 1,
+                    vektor(&av, true, t),
+                    if av.iter().all(|x| x.is_nol()) {
+                        "✓"
+                    } else {
+                        "✗"
+                    }
+                )
+            });
+        }
+    }
+    steps.tulis(|t| format!("  Null(A) = {}\n", span(&basis_nol, true, t)));
+    let nulitas = basis_nol.len();
+    steps.tulis(|_| format!("  nulitas(A) = dim Null(A) = {}\n", nulitas));
+
+    steps.tulis(|_| {
+        format!(
+            "\nTeorema Rank–Nulitas:\n  \
+         rank(A) + nulitas(A) = banyak kolom A\n  \
+         {} + {} = {} ✓\n  \
+         Dari {} variabel, {} terikat pada pivot dan {} bebas.\n  \
+         dim Row(A) = dim Col(A) = rank(A) = {}\n",
+            rank, nulitas, n, n, rank, nulitas, rank
+        )
+    });
+
+    Ok((
+        Ruang {
+            rank,
+            nulitas,
+            basis_baris,
+            basis_kolom,
+            basis_nol,
+        },
+        steps,
+    ))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::solver::pecahan::uji::matriks;
+
+    const P: Tampilan = Tampilan::Pecahan;
+
+    #[test]
+    fn rank_nulitas_dan_basis() {
+        let a = matriks(&[&[1, 2, 3], &[2, 4, 6]]);
+        let (ruang, langkah) = ruang_proses(&a).unwrap();
+        assert_eq!((ruang.rank, ruang.nulitas), (1, 2));
+        assert_eq!(ruang.basis_baris, matriks(&[&[1, 2, 3]]));
+        assert_eq!(ruang.basis_kolom, matriks(&[&[1, 2]]));
+        assert_eq!(ruang.basis_nol, matriks(&[&[-2, 1, 0], &[-3, 0, 1]]));
+        assert!(!langkah.pecahan.teks().contains('✗'));
+    }
+
+    #[test]
+    fn matriks_berpangkat_penuh() {
+        let (ruang, _) = ruang_proses(&matriks(&[&[1, 1], &[0, 1]])).unwrap();
+        assert_eq!((ruang.rank, ruang.nulitas), (2, 0));
+        assert!(ruang.basis_nol.is_empty());
+        assert_eq!(span(&ruang.basis_nol, true, P), "{0}");
+    }
+}
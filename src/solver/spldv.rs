@@ -0,0 +1,240 @@
+use crate::solver::format::{Teks, bagi, kurung, persamaan};
+use crate::solver::gauss::{JenisSolusi, Penyelesaian};
+use crate::solver::metode::{Metode, selesaikan};
+use crate::solver::pecahan::{Hitung, Pecahan};
+
+//
+// Sistem Persamaan Linear Dua Variabel
+// a1x + b1y = c1
+// a2x + b2y = c2
+// Jenis sistem ditentukan dari D = a1b2 − a2b1, Dx = c1b2 − c2b1, dan Dy = a1c2 − a2c1.
+// Jika D ≠ 0, sistem diselesaikan dengan metode pilihan pengguna.
+// Jika tidak, langkah berisi penjelasan geometris dan (jika bergantung)
+// solusi umum dalam bentuk parameter t, yang juga dikembalikan untuk hasil akhir.
+//
+pub fn spldv_proses(k: [Pecahan; 6], metode: Metode) -> Hitung<Penyelesaian<(Pecahan, Pecahan)>> {
+    let [a1, b1, c1, a2, b2, c2] = k;
+    let d = a1.kali(b2)?.kurang(a2.kali(b1)?)?;
+    let dx = c1.kali(b2)?.kurang(c2.kali(b1)?)?;
+    let dy = a1.kali(c2)?.kurang(a2.kali(c1)?)?;
+
+    if !d.is_nol() {
+        let (x, y) = (dx.bagi(d)?, dy.bagi(d)?);
+        let steps = if metode == Metode::Grafik {
+            grafik(k, x, y)?
+        } else {
+            let m = vec![vec![a1, b1, c1], vec![a2, b2, c2]];
+            selesaikan(&m, &["x", "y"], metode)?.1
+        };
+        return Ok((JenisSolusi::Tunggal, Some((x, y)), None, steps));
+    }
+
+    let mut steps = Teks::dari(|t| {
+        format!(
+            "Persamaan:\n  P1: {}\n  P2: {}\n\n",
+            persamaan(&[(a1, "x"), (b1, "y")], c1, t),
+            persamaan(&[(a2, "x"), (b2, "y")], c2, t)
+        )
+    });
+    steps.tulis(|t| format!(
+        "Determinan:\n  D  = a1·b2 − a2·b1 = {}\n  Dx = c1·b2 − c2·b1 = {}\n  Dy = a1·c2 − a2·c1 = {}\n\n",
+        d.tampil(t), dx.tampil(t), dy.tampil(t)
+    ));
+
+    // Persamaan 0x + 0y = c dengan c ≠ 0 tidak pernah benar
+    let trivial1 = a1.is_nol() && b1.is_nol();
+    let trivial2 = a2.is_nol() && b2.is_nol();
+    if (trivial1 && !c1.is_nol()) || (trivial2 && !c2.is_nol()) {
+        steps.push_str(
+            "Salah satu persamaan berbentuk 0x + 0y = c dengan c ≠ 0, yang tidak pernah benar.\n\
+             Jenis sistem: tidak konsisten → Tidak ada solusi.\n",
+        );
+        return Ok((JenisSolusi::TidakAda, None, None, steps));
+    }
+
+    if trivial1 && trivial2 {
+        let umum = Teks::from("x = s, y = t  (s, t bilangan real)");
+        steps.push_str(
+            "Kedua persamaan berbentuk 0x + 0y = 0, sehingga dipenuhi oleh setiap titik.\n\
+             Jenis sistem: bergantung → Tak hingga banyak solusi.\n\n\
+             Solusi umum:\n  ",
+        );
+        steps.sambung(&umum);
+        steps.push_str(
+            "\n\nSecara geometris, tidak ada garis yang terbentuk: seluruh bidang xy adalah solusi.\n",
+        );
+        return Ok((JenisSolusi::TakHingga, None, Some(umum), steps));
+    }
+
+    if !dx.is_nol() || !dy.is_nol() {
+        steps.push_str(
+            "D = 0 tetapi Dx atau Dy ≠ 0.\n\
+             Jenis sistem: tidak konsisten → Tidak ada solusi.\n\n\
+             Secara geometris, kedua garis sejajar: gradiennya sama tetapi\n\
+             letaknya berbeda, sehingga tidak pernah berpotongan.\n",
+        );
+        return Ok((JenisSolusi::TidakAda, None, None, steps));
+    }
+
+    steps.push_str(
+        "D = Dx = Dy = 0.\n\
+         Jenis sistem: bergantung → Tak hingga banyak solusi.\n\n",
+    );
+
+    // Gunakan persamaan yang tidak trivial untuk menyusun solusi umum
+    let (a, b, c, p) = if trivial1 {
+        (a2, b2, c2, 2)
+    } else {
+        (a1, b1, c1, 1)
+    };
+    let keluarga = |t| {
+        Ok(if !b.is_nol() {
+            format!("x = t, y = {}", bagi(c, &[(-a, "t")], b, t)?)
+        } else {
+            format!("x = {}, y = t", bagi(c, &[], a, t)?)
+        })
+    };
+    steps.coba_tulis(|t| {
+        Ok(format!(
+            "Solusi umum dari P{} (t bilangan real):\n  {}\n",
+            p,
+            keluarga(t)?
+        ))
+    })?;
+    let mut umum = Teks::default();
+    umum.coba_tulis(|t| Ok(format!("{}  (t bilangan real)", keluarga(t)?)))?;
+
+    if trivial1 || trivial2 {
+        steps.push_str(
+            "\nSecara geometris, satu persamaan selalu benar (0 = 0), sehingga\n\
+             solusinya adalah semua titik pada garis persamaan yang lain.\n",
+        );
+    } else {
+        steps.push_str(
+            "\nSecara geometris, kedua garis berimpit (persamaan yang satu adalah\n\
+             kelipatan persamaan yang lain), sehingga setiap titik pada garis adalah solusi.\n",
+        );
+    }
+
+    Ok((JenisSolusi::TakHingga, None, Some(umum), steps))
+}
+
+// Metode grafik: dua titik pada setiap garis, titik potong, lalu pemeriksaan
+fn grafik(k: [Pecahan; 6], x: Pecahan, y: Pecahan) -> Hitung<Teks> {
+    let [a1, b1, c1, a2, b2, c2] = k;
+    let mut steps = Teks::dari(|t| {
+        format!(
+            "Metode: {}\n\nPersamaan:\n  P1: {}\n  P2: {}\n",
+            Metode::Grafik.nama(),
+            persamaan(&[(a1, "x"), (b1, "y")], c1, t),
+            persamaan(&[(a2, "x"), (b2, "y")], c2, t)
+        )
+    });
+
+    steps.push_str("\nLangkah 1: Tentukan dua titik pada setiap garis\n");
+    for (p, (a, b, c)) in [(a1, b1, c1), (a2, b2, c2)].into_iter().enumerate() {
+        let [(px, py), (qx, qy)] = dua_titik(a, b, c)?;
+        let m = if b.is_nol() {
+            None
+        } else {
+            Some((-a).bagi(b)?)
+        };
+        let gradien = |t| match m {
+            Some(m) => m.tampil(t).to_string(),
+            None => "tegak (tidak terdefinisi)".to_string(),
+        };
+        steps.tulis(|t| {
+            format!(
+                "  P{}: melalui ({}, {}) dan ({}, {}), gradien m{} = {}\n",
+                p + 1,
+                px.tampil(t),
+                py.tampil(t),
+                qx.tampil(t),
+                qy.tampil(t),
+                p + 1,
+                gradien(t)
+            )
+        });
+    }
+
+    steps.tulis(|t| {
+        format!(
+            "\nLangkah 2: Gambar kedua garis melalui titik-titik tersebut\n\
+         \x20 Gradien kedua garis berbeda → garis berpotongan tepat di satu titik.\n\
+         \x20 Titik potong: ({}, {})\n",
+            x.tampil(t),
+            y.tampil(t)
+        )
+    });
+
+    steps.push_str("\nLangkah 3: Periksa titik potong pada kedua persamaan\n");
+    for (p, (a, b, c)) in [(a1, b1, c1), (a2, b2, c2)].into_iter().enumerate() {
+        steps.tulis(|t| {
+            format!(
+                "  P{}: {} × {} + {} × {} = {} ✓\n",
+                p + 1,
+                kurung(a, t),
+                kurung(x, t),
+                kurung(b, t),
+                kurung(y, t),
+                c.tampil(t)
+            )
+        });
+    }
+    Ok(steps)
+}
+
+// Dua titik berbeda pada garis ax + by = c (a dan b tidak keduanya nol)
+fn dua_titik(a: Pecahan, b: Pecahan, c: Pecahan) -> Hitung<[(Pecahan, Pecahan); 2]> {
+    let satu = Pecahan::SATU;
+    Ok(if b.is_nol() {
+        // Garis tegak x = c/a
+        let x = c.bagi(a)?;
+        [(x, Pecahan::NOL), (x, satu)]
+    } else if a.is_nol() {
+        // Garis mendatar y = c/b
+        let y = c.bagi(b)?;
+        [(Pecahan::NOL, y), (satu, y)]
+    } else if c.is_nol() {
+        // Garis melalui titik asal
+        [(Pecahan::NOL, Pecahan::NOL), (b, -a)]
+    } else {
+        // Titik potong dengan sumbu y dan sumbu x
+        [(Pecahan::NOL, c.bagi(b)?), (c.bagi(a)?, Pecahan::NOL)]
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::solver::pecahan::uji::larik;
+
+    #[test]
+    fn semua_metode_memberi_penyelesaian_yang_sama() {
+        // x + y = 5, x − y = 1
+        for metode in Metode::SPLDV {
+            let (jenis, xy, _, _) = spldv_proses(larik([1, 1, 5, 1, -1, 1]), metode).unwrap();
+            assert!(jenis == JenisSolusi::Tunggal);
+            assert_eq!(xy, Some((Pecahan::from(3), Pecahan::from(2))));
+        }
+    }
+
+    #[test]
+    fn sejajar_dan_berimpit() {
+        let (jenis, xy, umum, langkah) =
+            spldv_proses(larik([1, 2, 3, 2, 4, 7]), Metode::Campuran).unwrap();
+        assert!(jenis == JenisSolusi::TidakAda);
+        assert_eq!(xy, None);
+        assert_eq!(umum, None);
+        assert!(langkah.pecahan.teks().contains("sejajar"));
+
+        let (jenis, _, umum, langkah) =
+            spldv_proses(larik([1, 2, 3, 2, 4, 6]), Metode::Campuran).unwrap();
+        assert!(jenis == JenisSolusi::TakHingga);
+        assert!(langkah.pecahan.teks().contains("x = t, y = (3 - t)/2"));
+        assert_eq!(
+            umum.map(|u| u.pecahan.teks()),
+            Some("x = t, y = (3 - t)/2  (t bilangan real)".to_string())
+        );
+    }
+}
@@ -0,0 +1,330 @@
+use crate::solver::format::{Teks, kurung};
+use crate::solver::kuadrat::{bentuk_akar, sederhana_akar, suku_kuadrat};
+use crate::solver::pecahan::{Hitung, Pecahan, Tampilan};
+
+/// Bilangan p + q√r dengan r bebas kuadrat. Bilangan rasional selalu
+/// disimpan dengan q = 0 dan r = 1.
+#[derive(Clone, Copy, PartialEq)]
+pub struct BentukAkar {
+    pub p: Pecahan,
+    pub q: Pecahan,
+    pub r: i128,
+}
+
+impl BentukAkar {
+    pub fn rasional(p: Pecahan) -> Self {
+        Self {
+            p,
+            q: Pecahan::NOL,
+            r: 1,
+        }
+    }
+
+    /// p + q√r, dengan q√r digabung ke p jika r = 1
+    pub fn baru(p: Pecahan, q: Pecahan, r: i128) -> Hitung<Self> {
+        Ok(if q.is_nol() || r == 1 {
+            Self::rasional(p.tambah(q)?)
+        } else {
+            Self { p, q, r }
+        })
+    }
+
+    pub fn is_bulat(&self) -> bool {
+        self.q.is_nol() && self.p.is_bulat()
+    }
+
+    pub fn ke_f64(&self) -> f64 {
+        self.p.ke_f64() + self.q.ke_f64() * (self.r as f64).sqrt()
+    }
+
+    pub fn tulis(&self, t: Tampilan) -> Hitung<String> {
+        if self.q.is_nol() {
+            Ok(self.p.tampil(t).to_string())
+        } else if self.q.is_negatif() {
+            bentuk_akar(self.p, -self.q, self.r, false, "-", t)
+        } else {
+            bentuk_akar(self.p, self.q, self.r, false, "+", t)
+        }
+    }
+}
+
+/// Titik potong (x, y) dengan koordinat eksak
+pub type Titik = (BentukAkar, BentukAkar);
+
+/// Kedudukan dua kurva y = ax² + bx + c
+#[derive(Clone, Copy, PartialEq)]
+pub enum Perpotongan {
+    Dua,
+    /// D = 0: kedua kurva bersinggungan
+    Singgung,
+    /// Suku x² saling hilang dan tersisa persamaan linear
+    Satu,
+    TidakAda,
+    Berimpit,
+}
+
+impl Perpotongan {
+    pub fn nama(&self) -> &'static str {
+        match self {
+            Perpotongan::Dua => "Berpotongan di dua titik",
+            Perpotongan::Singgung => "Bersinggungan di satu titik",
+            Perpotongan::Satu => "Berpotongan di satu titik",
+            Perpotongan::TidakAda => "Tidak berpotongan",
+            Perpotongan::Berimpit => "Berimpit: setiap titik kurva adalah solusi",
+        }
+    }
+}
+
+fn jenis_kurva(k: &[Pecahan; 3]) -> &'static str {
+    if k[0].is_nol() { "garis" } else { "parabola" }
+}
+
+//
+// Sistem Persamaan Linear–Kuadrat (SPLKDV) dan Kuadrat–Kuadrat (SPKK)
+// y = a₁x² + b₁x + c₁, y = a₂x² + b₂x + c₂
+// y disubstitusikan sehingga tersisa Ax² + Bx + C = 0. Diskriminannya
+// menentukan banyak titik potong (dua, satu/singgung, atau tidak ada).
+//
+pub fn splkdv_proses(
+    k1: [Pecahan; 3],
+    k2: [Pecahan; 3],
+) -> Hitung<(Perpotongan, Vec<Titik>, Teks)> {
+    let mut steps = Teks::dari(|t| {
+        format!(
+            "Persamaan:\n  (1) y = {}   ({})\n  (2) y = {}   ({})\n",
+            suku_kuadrat(k1[0], k1[1], k1[2], t),
+            jenis_kurva(&k1),
+            suku_kuadrat(k2[0], k2[1], k2[2], t),
+            jenis_kurva(&k2)
+        )
+    });
+    let pasangan = match (k1[0].is_nol(), k2[0].is_nol()) {
+        (true, true) => "kedua garis",
+        (false, false) => "kedua parabola",
+        _ => "garis dan parabola",
+    };
+
+    // y disubstitusikan dari persamaan garis jika ada, karena lebih sederhana
+    let (asal, ka, kb) = if !k1[0].is_nol() && k2[0].is_nol() {
+        (2, k2, k1)
+    } else {
+        (1, k1, k2)
+    };
+    steps.tulis(|t| {
+        format!(
+            "\nLangkah 1: Substitusi y = {} dari ({}) ke ({})\n  {} = {}\n",
+            suku_kuadrat(ka[0], ka[1], ka[2], t),
+            asal,
+            3 - asal,
+            suku_kuadrat(kb[0], kb[1], kb[2], t),
+            suku_kuadrat(ka[0], ka[1], ka[2], t)
+        )
+    });
+
+    // Semua suku dipindahkan ke satu ruas dengan koefisien depan positif
+    let mut k = [
+        kb[0].kurang(ka[0])?,
+        kb[1].kurang(ka[1])?,
+        kb[2].kurang(ka[2])?,
+    ];
+    if k[0].is_negatif() || (k[0].is_nol() && k[1].is_negatif()) {
+        k = k.map(|v| -v);
+    }
+    let [a, b, c] = k;
+    steps.tulis(|t| {
+        format!(
+            "  Pindahkan semua suku ke satu ruas: {} = 0\n",
+            suku_kuadrat(a, b, c, t)
+        )
+    });
+
+    // Ordinat dihitung dari persamaan asal y = ka₀x² + ka₁x + ka₂
+    let ordinat = |x: BentukAkar| -> Hitung<Titik> {
+        let [pa, pb, pc] = ka;
+        let r = Pecahan::from(x.r);
+        // pa(p + q√r)² + pb(p + q√r) + pc
+        let kuadrat = x.p.kali(x.p)?.tambah(Pecahan::hasil_kali([x.q, x.q, r])?)?;
+        let p = Pecahan::jumlah([pa.kali(kuadrat)?, pb.kali(x.p)?, pc])?;
+        let q = Pecahan::from(2)
+            .kali(pa)?
+            .kali(x.p)?
+            .tambah(pb)?
+            .kali(x.q)?;
+        Ok((x, BentukAkar::baru(p, q, x.r)?))
+    };
+    let tulis_ordinat = |steps: &mut Teks, titik: &[Titik]| -> Hitung<()> {
+        steps.tulis(|_| {
+            format!(
+                "\nLangkah {}: Substitusi x ke persamaan ({}) untuk mendapatkan y\n",
+                if a.is_nol() { 3 } else { 4 },
+                asal
+            )
+        });
+        let indeks = ["₁", "₂"];
+        for (i, (x, y)) in titik.iter().enumerate() {
+            let n = if titik.len() == 1 { "" } else { indeks[i] };
+            steps.coba_tulis(|t| {
+                Ok(format!(
+                    "  x{n} = {} → y{n} = {}\n",
+                    x.tulis(t)?,
+                    y.tulis(t)?
+                ))
+            })?;
+        }
+        Ok(())
+    };
+
+    if a.is_nol() {
+        steps.push_str("\nLangkah 2: Suku x² saling hilang → persamaan linear\n");
+        if b.is_nol() {
+            return if c.is_nol() {
+                steps.tulis(|_| format!("  0 = 0 selalu benar → {} berimpit\n", pasangan));
+                Ok((Perpotongan::Berimpit, Vec::new(), steps))
+            } else {
+                steps.tulis(|t| {
+                    format!(
+                        "  {} = 0 tidak mungkin → {} tidak berpotongan\n",
+                        c.tampil(t),
+                        pasangan
+                    )
+                });
+                Ok((Perpotongan::TidakAda, Vec::new(), steps))
+            };
+        }
+        let x = (-c).bagi(b)?;
+        steps.tulis(|t| {
+            format!(
+                "  x = {} / {} = {}\n",
+                kurung(-c, t),
+                kurung(b, t),
+                x.tampil(t)
+            )
+        });
+        let titik = vec![ordinat(BentukAkar::rasional(x))?];
+        tulis_ordinat(&mut steps, &titik)?;
+        return Ok((Perpotongan::Satu, titik, steps));
+    }
+
+    // Langkah 2: diskriminan menentukan banyak titik potong
+    let d = b
+        .kali(b)?
+        .kurang(Pecahan::hasil_kali([Pecahan::from(4), a, c])?)?;
+    steps.tulis(|t| {
+        format!(
+            "\nLangkah 2: Diskriminan\n  D = b² - 4ac = {}² - 4 × {} × {} = {}\n",
+            kurung(b, t),
+            kurung(a, t),
+            kurung(c, t),
+            d.tampil(t)
+        )
+    });
+    let perpotongan = if d.is_negatif() {
+        Perpotongan::TidakAda
+    } else if d.is_nol() {
+        Perpotongan::Singgung
+    } else {
+        Perpotongan::Dua
+    };
+    steps.tulis(|_| {
+        format!(
+            "  {} → {} {}\n",
+            match perpotongan {
+                Perpotongan::Dua => "D > 0",
+                Perpotongan::Singgung => "D = 0",
+                _ => "D < 0",
+            },
+            pasangan,
+            perpotongan.nama().to_lowercase()
+        )
+    });
+    if d.is_negatif() {
+        steps.push_str("  Tidak ada titik potong real.\n");
+        return Ok((perpotongan, Vec::new(), steps));
+    }
+
+    // Langkah 3: x = p ± q√r
+    let dua_a = Pecahan::from(2).kali(a)?;
+    let p = (-b).bagi(dua_a)?;
+    steps.tulis(|t| {
+        format!(
+            "\nLangkah 3: Absis titik potong (rumus abc)\n  x = (-b ± √D) / 2a = ({} ± √{}) / {}\n",
+            (-b).tampil(t),
+            kurung(d, t),
+            dua_a.tampil(t)
+        )
+    });
+    let titik: Vec<Titik> = if d.is_nol() {
+        steps.tulis(|t| format!("  x = -b / 2a = {}\n", p.tampil(t)));
+        vec![ordinat(BentukAkar::rasional(p))?]
+    } else {
+        let (q, r) = sederhana_akar(d.bagi(dua_a.kali(dua_a)?)?)?;
+        let x1 = BentukAkar::baru(p, q, r)?;
+        let x2 = BentukAkar::baru(p, -q, r)?;
+        steps.coba_tulis(|t| {
+            Ok(format!(
+                "  x₁ = {}\n  x₂ = {}\n",
+                x1.tulis(t)?,
+                x2.tulis(t)?
+            ))
+        })?;
+        vec![ordinat(x1)?, ordinat(x2)?]
+    };
+    tulis_ordinat(&mut steps, &titik)?;
+    Ok((perpotongan, titik, steps))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const P: Tampilan = Tampilan::Pecahan;
+
+    fn kurva(a: i128, b: i128, c: i128) -> [Pecahan; 3] {
+        [a, b, c].map(Pecahan::from)
+    }
+
+    fn titik(daftar: &[Titik]) -> Vec<String> {
+        let mut teks: Vec<String> = daftar
+            .iter()
+            .map(|(x, y)| format!("({}, {})", x.tulis(P).unwrap(), y.tulis(P).unwrap()))
+            .collect();
+        teks.sort();
+        teks
+    }
+
+    #[test]
+    fn garis_dan_parabola() {
+        // y = x², y = x + 2 → x² − x − 2 = 0
+        let (jenis, daftar, _) = splkdv_proses(kurva(1, 0, 0), kurva(0, 1, 2)).unwrap();
+        assert!(jenis == Perpotongan::Dua);
+        assert_eq!(titik(&daftar), ["(-1, 1)", "(2, 4)"]);
+
+        let (jenis, daftar, _) = splkdv_proses(kurva(1, 0, 0), kurva(0, 2, -1)).unwrap();
+        assert!(jenis == Perpotongan::Singgung);
+        assert_eq!(titik(&daftar), ["(1, 1)"]);
+
+        let (jenis, daftar, _) = splkdv_proses(kurva(1, 0, 0), kurva(0, 0, -1)).unwrap();
+        assert!(jenis == Perpotongan::TidakAda);
+        assert!(daftar.is_empty());
+    }
+
+    #[test]
+    fn titik_potong_irasional() {
+        // y = x², y = 2 → x = ±√2
+        let (jenis, daftar, _) = splkdv_proses(kurva(1, 0, 0), kurva(0, 0, 2)).unwrap();
+        assert!(jenis == Perpotongan::Dua);
+        assert_eq!(titik(&daftar), ["(-√2, 2)", "(√2, 2)"]);
+        assert!(daftar.iter().all(|(x, _)| !x.is_bulat()));
+    }
+
+    #[test]
+    fn dua_parabola() {
+        // x² saling hilang: y = x² + 1, y = x² + x → x = 1
+        let (jenis, daftar, _) = splkdv_proses(kurva(1, 0, 1), kurva(1, 1, 0)).unwrap();
+        assert!(jenis == Perpotongan::Satu);
+        assert_eq!(titik(&daftar), ["(1, 2)"]);
+
+        let (jenis, _, _) = splkdv_proses(kurva(1, 2, 3), kurva(1, 2, 3)).unwrap();
+        assert!(jenis == Perpotongan::Berimpit);
+    }
+}
@@ -0,0 +1,79 @@
+use crate::solver::format::{Teks, kombinasi_linear, kurung, persamaan};
+use crate::solver::gauss::JenisSolusi;
+use crate::solver::pecahan::{Hitung, Pecahan, Tampilan};
+
+//
+// Sistem Persamaan Linear Satu Variabel
+// ax + b = 0  →  x = -b / a
+//
+pub fn splsv_proses(a: Pecahan, b: Pecahan) -> Hitung<(JenisSolusi, Option<Pecahan>, Teks)> {
+    let mut steps = Teks::dari(|t| format!("Persamaan: {} = 0\n\n", suku_splsv(a, b, t)));
+
+    if a.is_nol() {
+        if b.is_nol() {
+            steps.push_str(
+                "Koefisien x (a) = 0 dan b = 0 → 0 = 0 selalu benar.\n\
+                 Setiap nilai x memenuhi persamaan (x = t, t bilangan real).\n",
+            );
+            return Ok((JenisSolusi::TakHingga, None, steps));
+        }
+        steps.tulis(|t| {
+            format!(
+                "Koefisien x (a) = 0 → persamaan menjadi {} = 0 yang tidak pernah benar.\n",
+                b.tampil(t)
+            )
+        });
+        return Ok((JenisSolusi::TidakAda, None, steps));
+    }
+
+    let x = (-b).bagi(a)?;
+    steps.tulis(|t| {
+        format!(
+            "Pindahkan b ke kanan:\n  {}\nBagi dengan a:\n  x = {} / {} = {}\n",
+            persamaan(&[(a, "x")], -b, t),
+            kurung(-b, t),
+            kurung(a, t),
+            x.tampil(t)
+        )
+    });
+
+    Ok((JenisSolusi::Tunggal, Some(x), steps))
+}
+
+// Ruas kiri ax + b dengan suku x di depan
+pub fn suku_splsv(a: Pecahan, b: Pecahan, t: Tampilan) -> String {
+    let kiri = kombinasi_linear(Pecahan::NOL, &[(a, "x")], t);
+    let kiri = if a.is_nol() { "0x".to_string() } else { kiri };
+    if b.is_negatif() {
+        format!("{} - {}", kiri, (-b).tampil(t))
+    } else {
+        format!("{} + {}", kiri, b.tampil(t))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const P: Tampilan = Tampilan::Pecahan;
+
+    #[test]
+    fn tiga_jenis_penyelesaian() {
+        let (jenis, x, _) = splsv_proses(Pecahan::from(4), Pecahan::from(-2)).unwrap();
+        assert!(jenis == JenisSolusi::Tunggal);
+        assert_eq!(x, Some(Pecahan::new(1, 2)));
+
+        let (jenis, x, _) = splsv_proses(Pecahan::NOL, Pecahan::from(3)).unwrap();
+        assert!(jenis == JenisSolusi::TidakAda);
+        assert_eq!(x, None);
+
+        let (jenis, _, _) = splsv_proses(Pecahan::NOL, Pecahan::NOL).unwrap();
+        assert!(jenis == JenisSolusi::TakHingga);
+    }
+
+    #[test]
+    fn suku_ruas_kiri() {
+        assert_eq!(suku_splsv(Pecahan::from(2), Pecahan::from(-3), P), "2x - 3");
+        assert_eq!(suku_splsv(Pecahan::NOL, Pecahan::from(5), P), "0x + 5");
+    }
+}
@@ -0,0 +1,84 @@
+use crate::solver::format::{Teks, persamaan};
+use crate::solver::gauss::{JenisSolusi, Penyelesaian, gauss_proses};
+use crate::solver::matriks::determinan;
+use crate::solver::metode::{Metode, selesaikan};
+use crate::solver::pecahan::{Hitung, Pecahan};
+
+//
+// Sistem Persamaan Linear Tiga Variabel
+// a1x + b1y + c1z = d1
+// a2x + b2y + c2z = d2
+// a3x + b3y + c3z = d3
+// Jika determinan koefisien tidak nol, sistem diselesaikan dengan metode
+// pilihan pengguna; jika nol, jenis sistem (dan solusi umumnya jika bergantung)
+// ditentukan dengan eliminasi Gauss.
+//
+pub fn spltv_proses(
+    k: [Pecahan; 12],
+    metode: Metode,
+) -> Hitung<Penyelesaian<(Pecahan, Pecahan, Pecahan)>> {
+    let p: [[Pecahan; 4]; 3] = std::array::from_fn(|i| std::array::from_fn(|j| k[i * 4 + j]));
+    let m: Vec<Vec<Pecahan>> = p.iter().map(|b| b.to_vec()).collect();
+    let koefisien: Vec<Vec<Pecahan>> = p.iter().map(|b| b[..3].to_vec()).collect();
+    if determinan(&koefisien)?.is_nol() {
+        let mut steps = Teks::from("Persamaan:\n");
+        for (i, [a, b, c, d]) in p.iter().enumerate() {
+            steps.tulis(|t| {
+                format!(
+                    "  P{}: {}\n",
+                    i + 1,
+                    persamaan(&[(*a, "x"), (*b, "y"), (*c, "z")], *d, t)
+                )
+            });
+        }
+
+        steps.push_str(
+            "\nDeterminan koefisien = 0 → Tidak ada solusi tunggal.\n\
+             Jenis sistem ditentukan dengan eliminasi Gauss:\n\n",
+        );
+        let nama = ["x", "y", "z"].map(String::from);
+        let (jenis, _, umum, analisis) = gauss_proses(m, &nama)?;
+        steps.sambung(&analisis);
+        return Ok((jenis, None, umum, steps));
+    }
+
+    let (x, langkah) = selesaikan(&m, &["x", "y", "z"], metode)?;
+    Ok((
+        JenisSolusi::Tunggal,
+        Some((x[0], x[1], x[2])),
+        None,
+        langkah,
+    ))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::solver::pecahan::uji::larik;
+
+    #[test]
+    fn penyelesaian_tunggal_dengan_setiap_metode() {
+        // x + y + z = 6, 2x − y + z = 3, x + 2y − z = 2
+        let sistem = larik([1, 1, 1, 6, 2, -1, 1, 3, 1, 2, -1, 2]);
+        for metode in Metode::SPLTV {
+            let (jenis, xyz, _, _) = spltv_proses(sistem, metode).unwrap();
+            assert!(jenis == JenisSolusi::Tunggal);
+            assert_eq!(
+                xyz,
+                Some((Pecahan::from(1), Pecahan::from(2), Pecahan::from(3)))
+            );
+        }
+    }
+
+    #[test]
+    fn determinan_nol_diperiksa_dengan_gauss() {
+        // P3 = P1 + P2
+        let (jenis, _, umum, _) =
+            spltv_proses(larik([1, 1, 1, 3, 1, -1, 2, 2, 2, 0, 3, 5]), Metode::Cramer).unwrap();
+        assert!(jenis == JenisSolusi::TakHingga);
+        assert!(umum.is_some());
+        let (jenis, _, _, _) =
+            spltv_proses(larik([1, 1, 1, 3, 1, -1, 2, 2, 2, 0, 3, 6]), Metode::Cramer).unwrap();
+        assert!(jenis == JenisSolusi::TidakAda);
+    }
+}
@@ -0,0 +1,376 @@
+use crate::solver::ekspresi::SistemLinear;
+use crate::solver::format::{Teks, koefisien, kurung};
+use crate::solver::gauss::{JenisSolusi, SolusiUmum, gauss_proses};
+use crate::solver::kuadrat::{bentuk_akar, sederhana_akar};
+use crate::solver::pecahan::{Hitung, Pecahan, Tampilan};
+use crate::solver::splkdv::BentukAkar;
+
+/// Bentuk kemunculan variabel di dalam persamaan yang bisa dimisalkan
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum Bentuk {
+    Biasa,
+    Kebalikan,
+    Kuadrat,
+    Akar,
+}
+
+impl Bentuk {
+    /// Nama suku untuk variabel `nama`, mis. "1/x", "x²", atau "√x"
+    pub fn tulis(self, nama: &str) -> String {
+        match self {
+            Bentuk::Biasa => nama.to_string(),
+            Bentuk::Kebalikan => format!("1/{}", nama),
+            Bentuk::Kuadrat => format!("{}²", nama),
+            Bentuk::Akar => format!("√{}", nama),
+        }
+    }
+
+    /// Kebalikan dari [`Bentuk::tulis`]: bentuk suku beserta nama variabelnya
+    pub fn urai(suku: &str) -> (Bentuk, &str) {
+        if let Some(nama) = suku.strip_prefix("1/") {
+            (Bentuk::Kebalikan, nama)
+        } else if let Some(nama) = suku.strip_suffix('²') {
+            (Bentuk::Kuadrat, nama)
+        } else if let Some(nama) = suku.strip_prefix('√') {
+            (Bentuk::Akar, nama)
+        } else {
+            (Bentuk::Biasa, suku)
+        }
+    }
+
+    fn syarat(self, nama: &str) -> Option<String> {
+        match self {
+            Bentuk::Kebalikan => Some(format!("{} ≠ 0", nama)),
+            Bentuk::Akar => Some(format!("{} ≥ 0", nama)),
+            _ => None,
+        }
+    }
+}
+
+/// Penyelesaian dalam variabel asal, satu baris per pasangan nilai
+pub type Solusi = Vec<Vec<BentukAkar>>;
+
+/// Jenis sistem linear, nilai variabel bantu, solusi umumnya, nama variabel
+/// asal, penyelesaian dalam variabel asal, dan langkah
+pub type HasilSubstitusi = (
+    JenisSolusi,
+    Option<Vec<Pecahan>>,
+    SolusiUmum,
+    Vec<String>,
+    Solusi,
+    Teks,
+);
+
+// Satu suku tanpa tanda, mis. "3/x", "2x²", atau "√y"
+fn suku(k: Pecahan, nama: &str, t: Tampilan) -> String {
+    match Bentuk::urai(nama) {
+        (Bentuk::Kebalikan, v) => {
+            let angka = k.tampil(t).to_string();
+            if angka.contains('/') {
+                format!("({})/{}", angka, v)
+            } else {
+                format!("{}/{}", angka, v)
+            }
+        }
+        _ => format!("{}{}", koefisien(k, t), nama),
+    }
+}
+
+/// Menulis persamaan dengan suku berbentuk khusus, mis. "2/x - 3/y = 1"
+pub fn persamaan_bentuk(suku_suku: &[(Pecahan, &str)], ruas_kanan: Pecahan, t: Tampilan) -> String {
+    let mut kiri = String::new();
+    for (k, nama) in suku_suku.iter().filter(|(k, _)| !k.is_nol()) {
+        if kiri.is_empty() {
+            kiri.push_str(if k.is_negatif() { "-" } else { "" });
+        } else {
+            kiri.push_str(if k.is_negatif() { " - " } else { " + " });
+        }
+        kiri.push_str(&suku(k.abs(), nama, t));
+    }
+    if kiri.is_empty() {
+        kiri.push('0');
+    }
+    format!("{} = {}", kiri, ruas_kanan.tampil(t))
+}
+
+/// Variabel asal dalam urutan kemunculan, atau pesan kesalahan jika satu
+/// variabel muncul dalam dua bentuk berbeda (mis. x dan 1/x)
+pub fn variabel_asal(sistem: &SistemLinear) -> Result<Vec<String>, String> {
+    let mut asal: Vec<(&str, Bentuk)> = Vec::new();
+    for suku in &sistem.nama {
+        let (bentuk, nama) = Bentuk::urai(suku);
+        match asal.iter().find(|(n, _)| *n == nama) {
+            Some((_, lama)) => {
+                return Err(format!(
+                    "{} muncul sebagai {} dan {}; tidak bisa dimisalkan",
+                    nama,
+                    lama.tulis(nama),
+                    suku
+                ));
+            }
+            None => asal.push((nama, bentuk)),
+        }
+    }
+    Ok(asal.into_iter().map(|(n, _)| n.to_string()).collect())
+}
+
+// Nilai variabel asal dari nilai pemisalan `v`, beserta penjelasannya.
+// Daftar kosong berarti tidak ada nilai yang memenuhi.
+fn balik(bentuk: Bentuk, nama: &str, v: Pecahan) -> Hitung<(Vec<BentukAkar>, Teks)> {
+    Ok(match bentuk {
+        Bentuk::Biasa => (
+            vec![BentukAkar::rasional(v)],
+            Teks::dari(|t| format!("{} = {}", nama, v.tampil(t))),
+        ),
+        Bentuk::Kebalikan if v.is_nol() => (
+            Vec::new(),
+            Teks::dari(|_| {
+                format!(
+                    "tidak ada {} yang memenuhi (1/{} tidak pernah bernilai nol)",
+                    nama, nama
+                )
+            }),
+        ),
+        Bentuk::Kebalikan => {
+            let x = Pecahan::SATU.bagi(v)?;
+            (
+                vec![BentukAkar::rasional(x)],
+                Teks::dari(|t| format!("{} = 1 / {} = {}", nama, kurung(v, t), x.tampil(t))),
+            )
+        }
+        Bentuk::Kuadrat | Bentuk::Akar if v.is_negatif() => (
+            Vec::new(),
+            Teks::dari(|_| {
+                format!(
+                    "tidak ada {} real yang memenuhi ({} tidak pernah negatif)",
+                    nama,
+                    bentuk.tulis(nama)
+                )
+            }),
+        ),
+        Bentuk::Kuadrat if v.is_nol() => (
+            vec![BentukAkar::rasional(Pecahan::NOL)],
+            Teks::dari(|_| format!("{} = 0", nama)),
+        ),
+        Bentuk::Kuadrat => {
+            let (q, r) = sederhana_akar(v)?;
+            let mut teks = Teks::default();
+            teks.coba_tulis(|t| {
+                let (rumus, hasil) = (
+                    format!("±√{}", kurung(v, t)),
+                    bentuk_akar(Pecahan::NOL, q, r, false, "±", t)?,
+                );
+                Ok(if rumus == hasil {
+                    format!("{} = {}", nama, hasil)
+                } else {
+                    format!("{} = {} = {}", nama, rumus, hasil)
+                })
+            })?;
+            (
+                vec![
+                    BentukAkar::baru(Pecahan::NOL, q, r)?,
+                    BentukAkar::baru(Pecahan::NOL, -q, r)?,
+                ],
+                teks,
+            )
+        }
+        Bentuk::Akar => {
+            let x = v.kali(v)?;
+            (
+                vec![BentukAkar::rasional(x)],
+                Teks::dari(|t| format!("{} = {}² = {}", nama, kurung(v, t), x.tampil(t))),
+            )
+        }
+    })
+}
+
+//
+// Sistem Persamaan dengan Pemisalan (bentuk pecahan, kuadrat, dan akar)
+// mis. 2/x + 3/y = 1, 4/x - 1/y = 9
+// Setiap suku 1/x, x², atau √x dimisalkan dengan variabel bantu (p, q, ...),
+// sistem linear yang terbentuk diselesaikan dengan eliminasi Gauss, lalu nilai
+// variabel asal dicari
+// dengan substitusi balik. Nilai pemisalan yang tidak mungkin (mis. 1/x = 0)
+// menggugurkan penyelesaian. Selain penyelesaian dalam variabel asal,
+// dikembalikan juga nilai variabel bantu jika sistem linearnya tunggal, atau
+// solusi umumnya dalam variabel bantu jika sistem linearnya bergantung.
+//
+pub fn substitusi_proses(sistem: &SistemLinear) -> Hitung<HasilSubstitusi> {
+    let asal = variabel_asal(sistem).unwrap_or_default();
+    let n = sistem.nama.len();
+    let tulis = |nama: &[String], i: usize, t| {
+        let suku: Vec<(Pecahan, &str)> = sistem.matriks[i][..n]
+            .iter()
+            .zip(nama)
+            .map(|(k, v)| (*k, v.as_str()))
+            .collect();
+        persamaan_bentuk(&suku, sistem.matriks[i][n], t)
+    };
+
+    let mut steps = Teks::from("Persamaan:\n");
+    for i in 0..sistem.matriks.len() {
+        steps.tulis(|t| format!("  P{}: {}\n", i + 1, tulis(&sistem.nama, i, t)));
+    }
+
+    // Variabel bantu untuk setiap suku khusus, tidak bentrok dengan nama asal.
+    // Setelah huruf cadangan habis dipakai k1, k2, … sehingga deretan ini
+    // tidak pernah habis.
+    let mut cadangan = ["p", "q", "u", "v", "s", "t", "w", "z"]
+        .into_iter()
+        .map(String::from)
+        .chain((1..).map(|i| format!("k{}", i)))
+        .filter(|c| !asal.contains(c));
+    let bantu: Vec<String> = sistem
+        .nama
+        .iter()
+        .map(|suku| match Bentuk::urai(suku) {
+            (Bentuk::Biasa, nama) => nama.to_string(),
+            _ => cadangan.next().unwrap_or_default(),
+        })
+        .collect();
+
+    steps.push_str("\nPemisalan:\n");
+    if bantu == sistem.nama {
+        steps.push_str("  Tidak ada suku yang perlu dimisalkan; sistem sudah linear.\n");
+    } else {
+        for (suku, b) in sistem.nama.iter().zip(&bantu) {
+            let (bentuk, nama) = Bentuk::urai(suku);
+            if bentuk == Bentuk::Biasa {
+                continue;
+            }
+            steps.tulis(|_| format!("  {} = {}", b, suku));
+            if let Some(syarat) = bentuk.syarat(nama) {
+                steps.tulis(|_| format!("   (syarat {})", syarat));
+            }
+            steps.push('\n');
+        }
+        let daftar = match bantu.split_last() {
+            Some((akhir, awal)) if !awal.is_empty() => {
+                format!("{} dan {}", awal.join(", "), akhir)
+            }
+            _ => bantu.join(""),
+        };
+        steps.tulis(|_| format!("  Sistem menjadi linear dalam {}:\n", daftar));
+        for i in 0..sistem.matriks.len() {
+            steps.tulis(|t| format!("  P{}: {}\n", i + 1, tulis(&bantu, i, t)));
+        }
+    }
+
+    let (jenis, nilai, umum, langkah) = gauss_proses(sistem.matriks.clone(), &bantu)?;
+    steps.push('\n');
+    steps.sambung(&langkah);
+    let Some(nilai) = nilai else {
+        return Ok((jenis, None, umum, asal, Vec::new(), steps));
+    };
+
+    // Substitusi balik, lalu gabungkan semua kemungkinan nilai
+    steps.push_str("\nSubstitusi balik:\n");
+    let mut solusi: Solusi = vec![Vec::new()];
+    for ((suku, b), &v) in sistem.nama.iter().zip(&bantu).zip(&nilai) {
+        let (bentuk, nama) = Bentuk::urai(suku);
+        let (pilihan, teks) = balik(bentuk, nama, v)?;
+        if bentuk != Bentuk::Biasa {
+            steps.tulis(|t| {
+                format!(
+                    "  {} = {} = {} → {}\n",
+                    b,
+                    suku,
+                    v.tampil(t),
+                    teks.tampil(t)
+                )
+            });
+        }
+        solusi = solusi
+            .into_iter()
+            .flat_map(|s| {
+                pilihan.iter().map(move |x| {
+                    let mut s = s.clone();
+                    s.push(*x);
+                    s
+                })
+            })
+            .collect();
+    }
+    if solusi.is_empty() {
+        steps.push_str("  Nilai pemisalan tidak dapat dikembalikan → penyelesaian gugur.\n");
+    }
+    Ok((jenis, Some(nilai), None, asal, solusi, steps))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::solver::ekspresi::sistem_substitusi;
+    use crate::solver::pecahan::uji::p;
+
+    const P: Tampilan = Tampilan::Pecahan;
+
+    fn sistem(baris: &[&str]) -> SistemLinear {
+        let baris: Vec<String> = baris.iter().map(|s| s.to_string()).collect();
+        sistem_substitusi(&baris).unwrap()
+    }
+
+    #[test]
+    fn bentuk_ditulis_dan_diurai() {
+        for bentuk in [
+            Bentuk::Biasa,
+            Bentuk::Kebalikan,
+            Bentuk::Kuadrat,
+            Bentuk::Akar,
+        ] {
+            assert_eq!(Bentuk::urai(&bentuk.tulis("x")), (bentuk, "x"));
+        }
+    }
+
+    #[test]
+    fn variabel_asal_dari_suku() {
+        let s = sistem(&["1/x + y^2 = 5", "1/x - y^2 = 1"]);
+        assert_eq!(
+            variabel_asal(&s),
+            Ok(vec!["x".to_string(), "y".to_string()])
+        );
+        let s = SistemLinear {
+            nama: vec!["x".to_string(), "1/x".to_string()],
+            matriks: Vec::new(),
+        };
+        assert!(variabel_asal(&s).is_err());
+    }
+
+    #[test]
+    fn pemisalan_kebalikan() {
+        // 1/x + 1/y = 5, 1/x − 1/y = 1 → 1/x = 3, 1/y = 2
+        let s = sistem(&["1/x + 1/y = 5", "1/x - 1/y = 1"]);
+        let (jenis, nilai, _, asal, solusi, langkah) = substitusi_proses(&s).unwrap();
+        assert!(jenis == JenisSolusi::Tunggal);
+        assert_eq!(asal, ["x", "y"]);
+        assert_eq!(nilai, Some(vec![p(3, 1), p(2, 1)]));
+        // Sistem linearnya dieliminasi sekali dan langkah Gauss ditampilkan
+        assert_eq!(
+            langkah.pecahan.teks().matches("Matriks diperbesar").count(),
+            1
+        );
+        let teks: Vec<Vec<String>> = solusi
+            .iter()
+            .map(|b| b.iter().map(|v| v.tulis(P).unwrap()).collect())
+            .collect();
+        assert_eq!(teks, [["1/3", "1/2"]]);
+    }
+
+    #[test]
+    fn variabel_bantu_tidak_bentrok_dengan_nama_asal() {
+        // Semua huruf cadangan dan k1 sudah dipakai sebagai nama asal
+        let s = sistem(&["p + q + u + v + s + t + w + z + k1 + 1/x + 1/k = 1"]);
+        let (_, _, _, _, _, langkah) = substitusi_proses(&s).unwrap();
+        assert!(langkah.pecahan.teks().contains("  k2 = 1/x"));
+        assert!(langkah.pecahan.teks().contains("  k3 = 1/k"));
+    }
+
+    #[test]
+    fn pemisalan_kuadrat() {
+        // x² + y² = 5, x² − y² = 3 → x = ±2, y = ±1
+        let s = sistem(&["x^2 + y^2 = 5", "x^2 - y^2 = 3"]);
+        let (jenis, _, _, _, solusi, _) = substitusi_proses(&s).unwrap();
+        assert!(jenis == JenisSolusi::Tunggal);
+        assert_eq!(solusi.len(), 4);
+    }
+}
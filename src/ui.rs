@@ -1,14 +1,38 @@
-use crate::app::{App, AppState};
+use crate::app::{App, AppState, Hasil};
+use crate::editor::{EditorMatriks, IsianIteratif, KalkulatorMatriks};
+use crate::grafik::{Batas, GarisBilangan, Grafik};
+use crate::solver::daerah::{JenisDaerah, huruf};
+use crate::solver::dekomposisi::JenisDekomposisi;
+use crate::solver::eigen::periksa_ukuran;
+use crate::solver::ekspresi::{
+    hitung, hitung_parameter, persamaan_linear, persamaan_substitusi, sistem_linear,
+    sistem_substitusi,
+};
+use crate::solver::format::{Blok, Isi, persamaan};
+use crate::solver::gauss::{JenisSolusi, tulis_matriks};
+use crate::solver::iteratif::{LABEL_PARAMETER, MetodeIteratif, ParameterIteratif};
+use crate::solver::kalkulator::OperasiMatriks;
+use crate::solver::matriks::{GambarMatriks, Kurung};
+use crate::solver::metode::Metode;
+use crate::solver::pecahan::{Pecahan, Tampilan};
+use crate::solver::pertidaksamaan::Relasi;
+use crate::solver::program_linear::nama_variabel;
+use crate::solver::splkdv::Perpotongan;
+use crate::solver::substitusi::{persamaan_bentuk, variabel_asal};
 use crate::themes::gruvbox::*;
 use log::debug;
 use ratatui::{
     Frame,
+    buffer::Buffer,
     layout::{Alignment, Constraint, Direction, Layout},
     prelude::Rect,
     style::{Color, Modifier, Style, Stylize},
-    symbols::border,
+    symbols::{Marker, border},
     text::{Line, Span, Text},
-    widgets::{Block, Borders, Paragraph, Wrap},
+    widgets::{
+        Block, Borders, Paragraph, Widget, Wrap,
+        canvas::{Canvas, Line as CanvasLine, Points},
+    },
 };
 
 // Color mapping function
@@ -47,11 +71,12 @@ pub fn draw(f: &mut Frame, app: &App, theme_mode: String) {
     // Pass the current theme mode to all rendering functions
     match &app.state {
         AppState::Menu => render_menu_ui(f, f.area(), &ascii_lines, &owner, &theme_mode),
-        AppState::InputSPLDV(inputs, selected) => render_input_spldv_ui(
+        AppState::InputSPLDV(inputs, selected, metode) => render_input_spldv_ui(
             f,
             f.area(),
             inputs,
             *selected,
+            *metode,
             &ascii_lines,
             &owner,
             &theme_mode,
@@ -65,9 +90,130 @@ pub fn draw(f: &mut Frame, app: &App, theme_mode: String) {
             &owner,
             &theme_mode,
         ),
-        AppState::Result(result) => {
-            render_result_ui(f, f.area(), result, &ascii_lines, &owner, &theme_mode)
+        AppState::InputKuadrat(inputs, selected) => render_input_kuadrat_ui(
+            f,
+            f.area(),
+            inputs,
+            *selected,
+            &ascii_lines,
+            &owner,
+            &theme_mode,
+        ),
+        AppState::InputSPLKDV(inputs, selected) => render_input_splkdv_ui(
+            f,
+            f.area(),
+            inputs,
+            *selected,
+            &ascii_lines,
+            &owner,
+            &theme_mode,
+        ),
+        AppState::InputSPLTV(inputs, selected, metode) => render_input_spltv_ui(
+            f,
+            f.area(),
+            inputs,
+            *selected,
+            *metode,
+            &ascii_lines,
+            &owner,
+            &theme_mode,
+        ),
+        AppState::InputPTLSV(inputs, selected, relasi) => render_input_ptlsv_ui(
+            f,
+            f.area(),
+            inputs,
+            *selected,
+            *relasi,
+            &ascii_lines,
+            &owner,
+            &theme_mode,
+        ),
+        AppState::InputSPTLDV(editor, relasi) => render_input_sptldv_ui(
+            f,
+            f.area(),
+            editor,
+            relasi,
+            &ascii_lines,
+            &owner,
+            &theme_mode,
+        ),
+        AppState::InputPL(editor, relasi, maksimum) => render_input_pl_ui(
+            f,
+            f.area(),
+            editor,
+            relasi,
+            *maksimum,
+            &ascii_lines,
+            &owner,
+            &theme_mode,
+        ),
+        AppState::InputGauss(editor) => {
+            render_input_gauss_ui(f, f.area(), editor, &ascii_lines, &owner, &theme_mode)
+        }
+        AppState::InputTeks(baris, selected) => render_input_teks_ui(
+            f,
+            f.area(),
+            baris,
+            *selected,
+            &ascii_lines,
+            &owner,
+            &theme_mode,
+        ),
+        AppState::KonfirmasiTeks(baris) => {
+            render_konfirmasi_teks_ui(f, f.area(), baris, &ascii_lines, &owner, &theme_mode)
+        }
+        AppState::InputSubstitusi(baris, selected) => render_input_substitusi_ui(
+            f,
+            f.area(),
+            baris,
+            *selected,
+            &ascii_lines,
+            &owner,
+            &theme_mode,
+        ),
+        AppState::InputMatriks(kalkulator) => {
+            render_input_matriks_ui(f, f.area(), kalkulator, &ascii_lines, &owner, &theme_mode)
+        }
+        AppState::InputKuadratTerkecil(editor) => {
+            render_input_kuadrat_terkecil_ui(f, f.area(), editor, &ascii_lines, &owner, &theme_mode)
+        }
+        AppState::InputRegresi(editor, prediksi, di_prediksi) => render_input_regresi_ui(
+            f,
+            f.area(),
+            editor,
+            prediksi,
+            *di_prediksi,
+            &ascii_lines,
+            &owner,
+            &theme_mode,
+        ),
+        AppState::InputIteratif(isian) => {
+            render_input_iteratif_ui(f, f.area(), isian, &ascii_lines, &owner, &theme_mode)
+        }
+        AppState::InputDekomposisi(editor, jenis) => render_input_dekomposisi_ui(
+            f,
+            f.area(),
+            editor,
+            *jenis,
+            &ascii_lines,
+            &owner,
+            &theme_mode,
+        ),
+        AppState::InputRuang(editor) => {
+            render_input_ruang_ui(f, f.area(), editor, &ascii_lines, &owner, &theme_mode)
+        }
+        AppState::InputEigen(editor) => {
+            render_input_eigen_ui(f, f.area(), editor, &ascii_lines, &owner, &theme_mode)
         }
+        AppState::Result(result) => render_result_ui(
+            f,
+            f.area(),
+            result,
+            app.result_scroll,
+            &ascii_lines,
+            &owner,
+            &theme_mode,
+        ),
         AppState::Exit => {}
     }
 }
@@ -99,21 +245,182 @@ pub fn draw_verbose(f: &mut Frame, app: &App, theme_mode: String) {
                 &theme_mode,
             )
         }
-        AppState::InputSPLDV(inputs, selected) => {
+        AppState::InputKuadrat(inputs, selected) => {
+            debug!("Rendering: Quadratic Equation Input Form.");
+            render_input_kuadrat_ui(
+                f,
+                f.area(),
+                inputs,
+                *selected,
+                &ascii_lines,
+                &owner,
+                &theme_mode,
+            )
+        }
+        AppState::InputSPLKDV(inputs, selected) => {
+            debug!("Rendering: Linear-Quadratic System Input Form.");
+            render_input_splkdv_ui(
+                f,
+                f.area(),
+                inputs,
+                *selected,
+                &ascii_lines,
+                &owner,
+                &theme_mode,
+            )
+        }
+        AppState::InputSPLDV(inputs, selected, metode) => {
             debug!("Rendering: SPLDV Input Form.");
             render_input_spldv_ui(
                 f,
                 f.area(),
                 inputs,
                 *selected,
+                *metode,
+                &ascii_lines,
+                &owner,
+                &theme_mode,
+            )
+        }
+        AppState::InputSPLTV(inputs, selected, metode) => {
+            debug!("Rendering: SPLTV Input Form.");
+            render_input_spltv_ui(
+                f,
+                f.area(),
+                inputs,
+                *selected,
+                *metode,
+                &ascii_lines,
+                &owner,
+                &theme_mode,
+            )
+        }
+        AppState::InputPTLSV(inputs, selected, relasi) => {
+            debug!("Rendering: PtLSV Input Form.");
+            render_input_ptlsv_ui(
+                f,
+                f.area(),
+                inputs,
+                *selected,
+                *relasi,
+                &ascii_lines,
+                &owner,
+                &theme_mode,
+            )
+        }
+        AppState::InputSPTLDV(editor, relasi) => {
+            debug!("Rendering: Two-variable Inequality System Input.");
+            render_input_sptldv_ui(
+                f,
+                f.area(),
+                editor,
+                relasi,
+                &ascii_lines,
+                &owner,
+                &theme_mode,
+            )
+        }
+        AppState::InputPL(editor, relasi, maksimum) => {
+            debug!("Rendering: Linear Programming Input.");
+            render_input_pl_ui(
+                f,
+                f.area(),
+                editor,
+                relasi,
+                *maksimum,
+                &ascii_lines,
+                &owner,
+                &theme_mode,
+            )
+        }
+        AppState::InputGauss(editor) => {
+            debug!("Rendering: Gauss Matrix Editor.");
+            render_input_gauss_ui(f, f.area(), editor, &ascii_lines, &owner, &theme_mode)
+        }
+        AppState::InputTeks(baris, selected) => {
+            debug!("Rendering: Free-form Equation Input.");
+            render_input_teks_ui(
+                f,
+                f.area(),
+                baris,
+                *selected,
+                &ascii_lines,
+                &owner,
+                &theme_mode,
+            )
+        }
+        AppState::KonfirmasiTeks(baris) => {
+            debug!("Rendering: Free-form Equation Confirmation.");
+            render_konfirmasi_teks_ui(f, f.area(), baris, &ascii_lines, &owner, &theme_mode)
+        }
+        AppState::InputSubstitusi(baris, selected) => {
+            debug!("Rendering: Substitution Form Input.");
+            render_input_substitusi_ui(
+                f,
+                f.area(),
+                baris,
+                *selected,
+                &ascii_lines,
+                &owner,
+                &theme_mode,
+            )
+        }
+        AppState::InputMatriks(kalkulator) => {
+            debug!("Rendering: Matrix Calculator.");
+            render_input_matriks_ui(f, f.area(), kalkulator, &ascii_lines, &owner, &theme_mode)
+        }
+        AppState::InputKuadratTerkecil(editor) => {
+            debug!("Rendering: Least Squares Input Form.");
+            render_input_kuadrat_terkecil_ui(f, f.area(), editor, &ascii_lines, &owner, &theme_mode)
+        }
+        AppState::InputRegresi(editor, prediksi, di_prediksi) => {
+            debug!("Rendering: Linear Regression Input Form.");
+            render_input_regresi_ui(
+                f,
+                f.area(),
+                editor,
+                prediksi,
+                *di_prediksi,
+                &ascii_lines,
+                &owner,
+                &theme_mode,
+            )
+        }
+        AppState::InputIteratif(isian) => {
+            debug!("Rendering: Iterative Methods Input Form.");
+            render_input_iteratif_ui(f, f.area(), isian, &ascii_lines, &owner, &theme_mode)
+        }
+        AppState::InputDekomposisi(editor, jenis) => {
+            debug!("Rendering: Matrix Decomposition Input Form.");
+            render_input_dekomposisi_ui(
+                f,
+                f.area(),
+                editor,
+                *jenis,
                 &ascii_lines,
                 &owner,
                 &theme_mode,
             )
         }
+        AppState::InputRuang(editor) => {
+            debug!("Rendering: Fundamental Subspaces Input Form.");
+            render_input_ruang_ui(f, f.area(), editor, &ascii_lines, &owner, &theme_mode)
+        }
+        AppState::InputEigen(editor) => {
+            debug!("Rendering: Eigenvalues Input Form.");
+            render_input_eigen_ui(f, f.area(), editor, &ascii_lines, &owner, &theme_mode)
+        }
         AppState::Result(result) => {
             debug!("Rendering: Result.");
-            render_result_ui(f, f.area(), result, &ascii_lines, &owner, &theme_mode)
+            render_result_ui(
+                f,
+                f.area(),
+                result,
+                app.result_scroll,
+                &ascii_lines,
+                &owner,
+                &theme_mode,
+            )
         }
         AppState::Exit => {}
     }
@@ -242,7 +549,7 @@ fn render_menu_ui(
 
     let instructions = Line::from(vec![
         Span::styled(
-            " [1/2] ",
+            " [0-9/A-H] ",
             Style::default().fg(get_theme_color("blue", theme)).bold(),
         ),
         Span::styled(
@@ -264,43 +571,60 @@ fn render_menu_ui(
         .border_set(border::THICK)
         .border_style(Style::default().fg(get_theme_color("cyan", theme)));
 
-    // Menu utama
-    let menu = Paragraph::new(Line::from(vec![
-        Span::raw("\n"),
-        Span::styled(
-            "[1] ",
-            Style::default().fg(get_theme_color("blue", theme)).bold(),
-        ),
-        Span::styled(
-            "SPLSV\n",
-            Style::default().fg(get_theme_color("fg", theme)).bold(),
-        ),
-        Span::styled(
-            "        [2] ",
-            Style::default().fg(get_theme_color("green", theme)).bold(),
-        ),
-        Span::styled(
-            "SPLDV\n",
-            Style::default().fg(get_theme_color("fg", theme)).bold(),
-        ),
-    ]))
-    .alignment(Alignment::Center)
-    .block(
-        block
-            .borders(Borders::ALL)
-            .bg(get_theme_color("bg", theme))
-            .title(" Menu ")
-            .bold(),
-    );
+    // Menu utama: satu baris per mode
+    let mode = [
+        ("1", "SPLSV", "blue"),
+        ("2", "SPLDV", "green"),
+        ("3", "SPLTV", "yellow"),
+        ("4", "SPL n×n", "orange"),
+        ("5", "Teks Bebas", "cyan"),
+        ("6", "Pertidaksamaan", "red"),
+        ("7", "Sistem Pertidaksamaan", "green"),
+        ("8", "Program Linear", "blue"),
+        ("9", "Persamaan Kuadrat", "cyan"),
+        ("0", "SPLKDV / SPKK", "yellow"),
+        ("A", "SPLDV Bentuk Khusus", "orange"),
+        ("B", "Kalkulator Matriks", "red"),
+        ("C", "Kuadrat Terkecil", "green"),
+        ("D", "Regresi Linear", "yellow"),
+        ("E", "Metode Iteratif", "yellow"),
+        ("F", "Dekomposisi Matriks", "yellow"),
+        ("G", "Ruang Fundamental", "green"),
+        ("H", "Nilai dan Vektor Eigen", "orange"),
+    ];
+    let mut lines = vec![Line::raw("")];
+    lines.extend(mode.iter().map(|(key, nama, warna)| {
+        Line::from(vec![
+            Span::styled(
+                format!("[{}] ", key),
+                Style::default().fg(get_theme_color(warna, theme)).bold(),
+            ),
+            Span::styled(
+                format!("{:<21}", nama),
+                Style::default().fg(get_theme_color("fg", theme)).bold(),
+            ),
+        ])
+    }));
+    let menu = Paragraph::new(Text::from(lines))
+        .alignment(Alignment::Center)
+        .block(
+            block
+                .borders(Borders::ALL)
+                .bg(get_theme_color("bg", theme))
+                .title(" Menu ")
+                .bold(),
+        );
 
     f.render_widget(menu, chunks[1]);
 }
 
+#[allow(clippy::too_many_arguments)]
 fn render_input_spldv_ui(
     f: &mut Frame,
     area: Rect,
     inputs: &[String; 6],
     selected: usize,
+    metode: Metode,
     ascii_lines: &Vec<Line<'_>>,
     owner: &Line<'_>,
     theme: &str,
@@ -334,6 +658,14 @@ fn render_input_spldv_ui(
             "Kanan/Kiri ",
             Style::default().fg(get_theme_color("fg", theme)).bold(),
         ),
+        Span::styled(
+            "[Tab] ",
+            Style::default().fg(get_theme_color("blue", theme)).bold(),
+        ),
+        Span::styled(
+            "Metode ",
+            Style::default().fg(get_theme_color("fg", theme)).bold(),
+        ),
         Span::styled(
             "[Enter] ",
             Style::default().fg(get_theme_color("blue", theme)).bold(),
@@ -370,7 +702,7 @@ fn render_input_spldv_ui(
     let chunks = Layout::default()
         .direction(Direction::Vertical)
         .constraints([
-            Constraint::Length(4), // Title row
+            Constraint::Length(6), // Title row
             Constraint::Length(6), // Row 1 input
             Constraint::Length(6), // Row 2 input
         ])
@@ -383,37 +715,70 @@ fn render_input_spldv_ui(
         )),
         Line::from(Span::raw("a1x + b1y = c1")),
         Line::from(Span::raw("a2x + b2y = c2")),
+        metode_line(metode, theme),
+        Line::from(Span::styled(
+            "Koefisien boleh memuat parameter k atau m, mis. 2k - 1",
+            Style::default().fg(get_theme_color("fg", theme)),
+        )),
     ]))
     .alignment(Alignment::Center);
     f.render_widget(title, chunks[0]);
 
-    let row1 = Layout::default()
-        .direction(Direction::Horizontal)
-        .constraints([Constraint::Percentage(33); 3])
-        .split(chunks[1]);
+    render_field_grid(
+        f,
+        &chunks[1..],
+        &LABELS_SPLDV,
+        inputs,
+        selected,
+        true,
+        theme,
+    );
+}
 
-    let row2 = Layout::default()
-        .direction(Direction::Horizontal)
-        .constraints([Constraint::Percentage(33); 3])
-        .split(chunks[2]);
+// Baris pemilih metode penyelesaian di bawah judul input
+fn metode_line(metode: Metode, theme: &str) -> Line<'static> {
+    Line::from(vec![
+        Span::raw("Metode: "),
+        Span::styled(
+            format!("◀ {} ▶", metode.nama()),
+            Style::default().fg(get_theme_color("yellow", theme)).bold(),
+        ),
+    ])
+}
+
+const LABELS_SPLDV: [&str; 6] = ["a1", "b1", "c1", "a2", "b2", "c2"];
+const LABELS_SPLTV: [&str; 12] = [
+    "a1", "b1", "c1", "d1", "a2", "b2", "c2", "d2", "a3", "b3", "c3", "d3",
+];
+
+// Grid kotak input koefisien: satu baris layout per persamaan
+fn render_field_grid(
+    f: &mut Frame,
+    rows: &[Rect],
+    labels: &[&str],
+    inputs: &[String],
+    selected: usize,
+    parameter: bool,
+    theme: &str,
+) {
+    let cols = labels.len() / rows.len();
+    for (j, row_area) in rows.iter().enumerate() {
+        let row = Layout::default()
+            .direction(Direction::Horizontal)
+            .constraints(vec![Constraint::Ratio(1, cols as u32); cols])
+            .split(*row_area);
 
-    let labels = vec!["a1", "b1", "c1", "a2", "b2", "c2"];
-    for (j, chunk) in [&row1, &row2].iter().enumerate() {
-        for (k, area) in chunk.iter().enumerate() {
-            let idx = j * 3 + k;
+        for (k, area) in row.iter().enumerate() {
+            let idx = j * cols + k;
             let input = Paragraph::new(Line::from(vec![
                 Span::raw(format!("{}: ", labels[idx])),
                 Span::raw(&inputs[idx]),
             ]))
-            .block(
-                Block::bordered()
-                    .borders(Borders::ALL)
-                    .border_style(if idx == selected {
-                        Style::default().fg(get_theme_color("yellow", theme))
-                    } else {
-                        Style::default().fg(get_theme_color("blue", theme))
-                    }),
-            )
+            .block(if parameter {
+                field_block_parameter(&inputs[idx], idx == selected, theme)
+            } else {
+                field_block(&inputs[idx], idx == selected, theme)
+            })
             .alignment(Alignment::Left)
             .style(if idx == selected {
                 Style::default().fg(get_theme_color("yellow", theme))
@@ -425,11 +790,62 @@ fn render_input_spldv_ui(
     }
 }
 
-fn render_input_splsv_ui(
+// Bingkai kotak isian. Ekspresi ditampilkan nilainya di bingkai bawah,
+// isian yang tidak valid diberi warna merah beserta pesan kesalahannya.
+fn field_block(teks: &str, selected: bool, theme: &str) -> Block<'static> {
+    let keterangan = match hitung(teks) {
+        _ if teks.trim().is_empty() => None,
+        Err(e) => Some(Err(e)),
+        // Angka biasa tidak perlu ditulis ulang
+        Ok(_) if teks.trim().parse::<Pecahan>().is_ok() => None,
+        Ok(n) if n.eksak => Some(Ok(format!("= {}", n.nilai.pecahan()))),
+        Ok(n) => Some(Ok(format!("≈ {}", n.nilai.desimal()))),
+    };
+    bingkai_isian(keterangan, selected, theme)
+}
+
+// Seperti `field_block`, tetapi isian yang memuat parameter k atau m juga diterima
+fn field_block_parameter(teks: &str, selected: bool, theme: &str) -> Block<'static> {
+    match hitung_parameter(teks) {
+        Ok((Some(nama), _)) if hitung(teks).is_err() => {
+            bingkai_isian(Some(Ok(format!("parameter {}", nama))), selected, theme)
+        }
+        _ => field_block(teks, selected, theme),
+    }
+}
+
+// Bingkai dengan keterangan hijau (berhasil) atau merah (kesalahan) di bawahnya
+fn bingkai_isian(
+    keterangan: Option<Result<String, String>>,
+    selected: bool,
+    theme: &str,
+) -> Block<'static> {
+    let border = match &keterangan {
+        Some(Err(_)) => "red",
+        _ if selected => "yellow",
+        _ => "blue",
+    };
+    let block = Block::bordered()
+        .borders(Borders::ALL)
+        .border_style(Style::default().fg(get_theme_color(border, theme)));
+    let (warna, teks) = match keterangan {
+        None => return block,
+        Some(Ok(teks)) => ("green", teks),
+        Some(Err(teks)) => ("red", teks),
+    };
+    block.title_bottom(Line::styled(
+        format!(" {} ", teks),
+        Style::default().fg(get_theme_color(warna, theme)),
+    ))
+}
+
+#[allow(clippy::too_many_arguments)]
+fn render_input_spltv_ui(
     f: &mut Frame,
     area: Rect,
-    inputs: &[String; 2],
+    inputs: &[String; 12],
     selected: usize,
+    metode: Metode,
     ascii_lines: &Vec<Line<'_>>,
     owner: &Line<'_>,
     theme: &str,
@@ -456,11 +872,19 @@ fn render_input_splsv_ui(
             Style::default().fg(get_theme_color("fg", theme)).bold(),
         ),
         Span::styled(
-            "[←/→] ",
+            "[←/→/↑/↓] ",
             Style::default().fg(get_theme_color("blue", theme)).bold(),
         ),
         Span::styled(
-            "Kanan/Kiri ",
+            "Pindah ",
+            Style::default().fg(get_theme_color("fg", theme)).bold(),
+        ),
+        Span::styled(
+            "[Tab] ",
+            Style::default().fg(get_theme_color("blue", theme)).bold(),
+        ),
+        Span::styled(
+            "Metode ",
             Style::default().fg(get_theme_color("fg", theme)).bold(),
         ),
         Span::styled(
@@ -483,7 +907,112 @@ fn render_input_splsv_ui(
 
     let container_block = Block::bordered()
         .title_bottom(instructions.centered())
-        .title(" Input SPLSV ")
+        .title(" Input SPLTV ")
+        .borders(Borders::ALL)
+        .style(
+            Style::default()
+                .bg(get_theme_color("bg", theme))
+                .fg(get_theme_color("cyan", theme))
+                .bold(),
+        )
+        .bold();
+    let inner_area = container_block.inner(outer_chunks[1]); // Ambil area dalamnya
+    f.render_widget(container_block, outer_chunks[1]);
+
+    // Di dalam inner_area, buat layout lagi untuk title dan inputs
+    let chunks = Layout::default()
+        .direction(Direction::Vertical)
+        .constraints([
+            Constraint::Length(6), // Title row
+            Constraint::Length(3), // Row 1 input
+            Constraint::Length(3), // Row 2 input
+            Constraint::Length(3), // Row 3 input
+        ])
+        .split(inner_area);
+
+    let title = Paragraph::new(Text::from(vec![
+        Line::from(Span::styled(
+            "Persamaan Linear Tiga Variabel (SPLTV)",
+            Style::default().add_modifier(Modifier::BOLD),
+        )),
+        Line::from(Span::raw("a1x + b1y + c1z = d1")),
+        Line::from(Span::raw("a2x + b2y + c2z = d2")),
+        Line::from(Span::raw("a3x + b3y + c3z = d3")),
+        metode_line(metode, theme),
+    ]))
+    .alignment(Alignment::Center);
+    f.render_widget(title, chunks[0]);
+
+    render_field_grid(
+        f,
+        &chunks[1..],
+        &LABELS_SPLTV,
+        inputs,
+        selected,
+        false,
+        theme,
+    );
+}
+
+fn render_input_splsv_ui(
+    f: &mut Frame,
+    area: Rect,
+    inputs: &[String; 2],
+    selected: usize,
+    ascii_lines: &Vec<Line<'_>>,
+    owner: &Line<'_>,
+    theme: &str,
+) {
+    let outer_chunks = Layout::default()
+        .direction(Direction::Vertical)
+        .constraints([
+            Constraint::Max(8), // Header di luar
+            Constraint::Min(0), // Sisanya untuk kontainer
+        ])
+        .split(area);
+
+    // Header
+    render_header(f, outer_chunks[0], ascii_lines, owner, theme);
+
+    // Buat blok kontainer (dengan border)
+    let instructions = Line::from(vec![
+        Span::styled(
+            " [Esc] ",
+            Style::default().fg(get_theme_color("blue", theme)).bold(),
+        ),
+        Span::styled(
+            "Menu ",
+            Style::default().fg(get_theme_color("fg", theme)).bold(),
+        ),
+        Span::styled(
+            "[←/→] ",
+            Style::default().fg(get_theme_color("blue", theme)).bold(),
+        ),
+        Span::styled(
+            "Kanan/Kiri ",
+            Style::default().fg(get_theme_color("fg", theme)).bold(),
+        ),
+        Span::styled(
+            "[Enter] ",
+            Style::default().fg(get_theme_color("blue", theme)).bold(),
+        ),
+        Span::styled(
+            "Hitung ",
+            Style::default().fg(get_theme_color("fg", theme)).bold(),
+        ),
+        Span::styled(
+            "[Q] ",
+            Style::default().fg(get_theme_color("red", theme)).bold(),
+        ),
+        Span::styled(
+            "Keluar ",
+            Style::default().fg(get_theme_color("red", theme)).bold(),
+        ),
+    ]);
+
+    let container_block = Block::bordered()
+        .title_bottom(instructions.centered())
+        .title(" Input SPLSV ")
         .borders(Borders::ALL)
         .style(
             Style::default()
@@ -506,48 +1035,1920 @@ fn render_input_splsv_ui(
 
     let title = Paragraph::new(Text::from(vec![
         Line::from(Span::styled(
-            "Persamaan Linear Satu Variabel (SPLSV)",
+            "Persamaan Linear Satu Variabel (SPLSV)",
+            Style::default().add_modifier(Modifier::BOLD),
+        )),
+        Line::from(Span::raw("ax + b = 0")),
+    ]))
+    .alignment(Alignment::Center);
+    f.render_widget(title, chunks[0]);
+
+    let input_row = Layout::default()
+        .direction(Direction::Horizontal)
+        .constraints([Constraint::Percentage(50); 2])
+        .split(chunks[1]);
+
+    let labels = ["a", "b"];
+    for (i, area) in input_row.iter().enumerate() {
+        let input = Paragraph::new(Line::from(vec![
+            Span::styled(format!("{}: ", labels[i]), Style::default().bold()),
+            Span::styled(&inputs[i], Style::default().bold()),
+        ]))
+        .block(field_block(&inputs[i], i == selected, theme))
+        .alignment(Alignment::Left)
+        .style(if i == selected {
+            Style::default().fg(get_theme_color("yellow", theme)).bold()
+        } else {
+            Style::default().fg(get_theme_color("fg", theme)).bold()
+        });
+        f.render_widget(input, *area);
+    }
+}
+
+fn render_input_kuadrat_ui(
+    f: &mut Frame,
+    area: Rect,
+    inputs: &[String; 3],
+    selected: usize,
+    ascii_lines: &Vec<Line<'_>>,
+    owner: &Line<'_>,
+    theme: &str,
+) {
+    let outer_chunks = Layout::default()
+        .direction(Direction::Vertical)
+        .constraints([
+            Constraint::Max(8), // Header di luar
+            Constraint::Min(0), // Sisanya untuk kontainer
+        ])
+        .split(area);
+
+    // Header
+    render_header(f, outer_chunks[0], ascii_lines, owner, theme);
+
+    let instructions = instruction_line(
+        &[
+            ("[Esc] ", "Menu "),
+            ("[←/→] ", "Kanan/Kiri "),
+            ("[Enter] ", "Hitung "),
+        ],
+        theme,
+    );
+
+    let container_block = Block::bordered()
+        .title_bottom(instructions.centered())
+        .title(" Input Persamaan Kuadrat ")
+        .borders(Borders::ALL)
+        .style(
+            Style::default()
+                .bg(get_theme_color("bg", theme))
+                .fg(get_theme_color("cyan", theme))
+                .bold(),
+        )
+        .bold();
+    let inner_area = container_block.inner(outer_chunks[1]); // Ambil area dalamnya
+    f.render_widget(container_block, outer_chunks[1]);
+
+    let chunks = Layout::default()
+        .direction(Direction::Vertical)
+        .constraints([
+            Constraint::Length(4), // Title row
+            Constraint::Length(6), // Row 1 input
+        ])
+        .split(inner_area);
+
+    let title = Paragraph::new(Text::from(vec![
+        Line::from(Span::styled(
+            "Persamaan Kuadrat",
+            Style::default().add_modifier(Modifier::BOLD),
+        )),
+        Line::from(Span::raw("ax² + bx + c = 0")),
+    ]))
+    .alignment(Alignment::Center);
+    f.render_widget(title, chunks[0]);
+
+    render_field_grid(
+        f,
+        &chunks[1..],
+        &["a", "b", "c"],
+        inputs,
+        selected,
+        false,
+        theme,
+    );
+}
+
+const LABELS_SPLKDV: [&str; 6] = ["a1", "b1", "c1", "a2", "b2", "c2"];
+
+fn render_input_splkdv_ui(
+    f: &mut Frame,
+    area: Rect,
+    inputs: &[String; 6],
+    selected: usize,
+    ascii_lines: &Vec<Line<'_>>,
+    owner: &Line<'_>,
+    theme: &str,
+) {
+    let outer_chunks = Layout::default()
+        .direction(Direction::Vertical)
+        .constraints([
+            Constraint::Max(8), // Header di luar
+            Constraint::Min(0), // Sisanya untuk kontainer
+        ])
+        .split(area);
+
+    // Header
+    render_header(f, outer_chunks[0], ascii_lines, owner, theme);
+
+    let instructions = instruction_line(
+        &[
+            ("[Esc] ", "Menu "),
+            ("[←/→] ", "Kanan/Kiri "),
+            ("[Enter] ", "Hitung "),
+        ],
+        theme,
+    );
+
+    let container_block = Block::bordered()
+        .title_bottom(instructions.centered())
+        .title(" Input SPLKDV / SPKK ")
+        .borders(Borders::ALL)
+        .style(
+            Style::default()
+                .bg(get_theme_color("bg", theme))
+                .fg(get_theme_color("cyan", theme))
+                .bold(),
+        )
+        .bold();
+    let inner_area = container_block.inner(outer_chunks[1]); // Ambil area dalamnya
+    f.render_widget(container_block, outer_chunks[1]);
+
+    let chunks = Layout::default()
+        .direction(Direction::Vertical)
+        .constraints([
+            Constraint::Length(5), // Title row
+            Constraint::Length(3), // Row 1 input
+            Constraint::Length(3), // Row 2 input
+        ])
+        .split(inner_area);
+
+    let title = Paragraph::new(Text::from(vec![
+        Line::from(Span::styled(
+            "Sistem Persamaan Linear–Kuadrat / Kuadrat–Kuadrat",
+            Style::default().add_modifier(Modifier::BOLD),
+        )),
+        Line::from(Span::raw("y = a1x² + b1x + c1")),
+        Line::from(Span::raw("y = a2x² + b2x + c2")),
+        Line::from(Span::styled(
+            "Isi a = 0 untuk persamaan garis y = bx + c",
+            Style::default().fg(get_theme_color("gray", theme)),
+        )),
+    ]))
+    .alignment(Alignment::Center);
+    f.render_widget(title, chunks[0]);
+
+    render_field_grid(
+        f,
+        &chunks[1..],
+        &LABELS_SPLKDV,
+        inputs,
+        selected,
+        false,
+        theme,
+    );
+}
+
+#[allow(clippy::too_many_arguments)]
+fn render_input_ptlsv_ui(
+    f: &mut Frame,
+    area: Rect,
+    inputs: &[String; 2],
+    selected: usize,
+    relasi: Relasi,
+    ascii_lines: &Vec<Line<'_>>,
+    owner: &Line<'_>,
+    theme: &str,
+) {
+    let outer_chunks = Layout::default()
+        .direction(Direction::Vertical)
+        .constraints([
+            Constraint::Max(8), // Header di luar
+            Constraint::Min(0), // Sisanya untuk kontainer
+        ])
+        .split(area);
+
+    // Header
+    render_header(f, outer_chunks[0], ascii_lines, owner, theme);
+
+    let instructions = instruction_line(
+        &[
+            ("[Esc] ", "Menu "),
+            ("[←/→] ", "Kanan/Kiri "),
+            ("[Tab] ", "Tanda "),
+            ("[Enter] ", "Hitung "),
+        ],
+        theme,
+    );
+
+    let container_block = Block::bordered()
+        .title_bottom(instructions.centered())
+        .title(" Input Pertidaksamaan ")
+        .borders(Borders::ALL)
+        .style(
+            Style::default()
+                .bg(get_theme_color("bg", theme))
+                .fg(get_theme_color("cyan", theme))
+                .bold(),
+        )
+        .bold();
+    let inner_area = container_block.inner(outer_chunks[1]); // Ambil area dalamnya
+    f.render_widget(container_block, outer_chunks[1]);
+
+    let chunks = Layout::default()
+        .direction(Direction::Vertical)
+        .constraints([
+            Constraint::Length(4), // Title row
+            Constraint::Length(6), // Row 1 input
+        ])
+        .split(inner_area);
+
+    let title = Paragraph::new(Text::from(vec![
+        Line::from(Span::styled(
+            "Pertidaksamaan Linear Satu Variabel",
+            Style::default().add_modifier(Modifier::BOLD),
+        )),
+        Line::from(Span::raw(format!("ax + b {} 0", relasi.simbol()))),
+        Line::from(vec![
+            Span::raw("Tanda: "),
+            Span::styled(
+                format!("◀ {} ▶", relasi.simbol()),
+                Style::default().fg(get_theme_color("yellow", theme)).bold(),
+            ),
+        ]),
+    ]))
+    .alignment(Alignment::Center);
+    f.render_widget(title, chunks[0]);
+
+    render_field_grid(f, &chunks[1..], &["a", "b"], inputs, selected, false, theme);
+}
+
+fn render_input_sptldv_ui(
+    f: &mut Frame,
+    area: Rect,
+    editor: &EditorMatriks,
+    relasi: &[Relasi],
+    ascii_lines: &Vec<Line<'_>>,
+    owner: &Line<'_>,
+    theme: &str,
+) {
+    let outer_chunks = Layout::default()
+        .direction(Direction::Vertical)
+        .constraints([
+            Constraint::Max(8), // Header di luar
+            Constraint::Min(0), // Sisanya untuk kontainer
+        ])
+        .split(area);
+
+    // Header
+    render_header(f, outer_chunks[0], ascii_lines, owner, theme);
+
+    let instructions = instruction_line(
+        &[
+            ("[Esc] ", "Menu "),
+            ("[←/→/↑/↓] ", "Pindah "),
+            ("[Ctrl+↑/↓] ", "Baris "),
+            ("[Tab] ", "Tanda "),
+            ("[Enter] ", "Hitung "),
+        ],
+        theme,
+    );
+
+    let container_block = Block::bordered()
+        .title_bottom(instructions.centered())
+        .title(" Input Sistem Pertidaksamaan ")
+        .borders(Borders::ALL)
+        .style(
+            Style::default()
+                .bg(get_theme_color("bg", theme))
+                .fg(get_theme_color("cyan", theme))
+                .bold(),
+        )
+        .bold();
+    let inner_area = container_block.inner(outer_chunks[1]); // Ambil area dalamnya
+    f.render_widget(container_block, outer_chunks[1]);
+
+    let baris = editor.jumlah_baris();
+    let mut constraints = vec![Constraint::Length(4)]; // Title row
+    constraints.extend(vec![Constraint::Length(3); baris]); // Satu baris per pertidaksamaan
+    let chunks = Layout::default()
+        .direction(Direction::Vertical)
+        .constraints(constraints)
+        .split(inner_area);
+
+    let title = Paragraph::new(Text::from(vec![
+        Line::from(Span::styled(
+            "Sistem Pertidaksamaan Linear Dua Variabel",
+            Style::default().add_modifier(Modifier::BOLD),
+        )),
+        Line::from(Span::raw(format!("{} pertidaksamaan", baris))),
+        Line::from(Span::raw("aix + biy (tanda) ci")),
+    ]))
+    .alignment(Alignment::Center);
+    f.render_widget(title, chunks[0]);
+
+    // Setiap baris: kotak a, b, tanda, lalu c
+    let (r, c) = editor.kursor;
+    for (i, row_area) in chunks[1..].iter().enumerate() {
+        let row = Layout::default()
+            .direction(Direction::Horizontal)
+            .constraints([
+                Constraint::Fill(1),
+                Constraint::Fill(1),
+                Constraint::Length(7),
+                Constraint::Fill(1),
+            ])
+            .split(*row_area);
+        for (j, area) in [row[0], row[1], row[3]].into_iter().enumerate() {
+            let teks = &editor.sel[i][j];
+            let selected = (i, j) == (r, c);
+            let input = Paragraph::new(Line::from(vec![
+                Span::raw(format!("{}{}: ", ["a", "b", "c"][j], i + 1)),
+                Span::raw(teks),
+            ]))
+            .block(field_block(teks, selected, theme))
+            .style(if selected {
+                Style::default().fg(get_theme_color("yellow", theme))
+            } else {
+                Style::default().fg(get_theme_color("fg", theme))
+            });
+            f.render_widget(input, area);
+        }
+        let tanda = Paragraph::new(format!("◀ {} ▶", relasi[i].simbol()))
+            .alignment(Alignment::Center)
+            .block(
+                Block::bordered().border_style(Style::default().fg(get_theme_color(
+                    if i == r { "yellow" } else { "blue" },
+                    theme,
+                ))),
+            )
+            .style(Style::default().fg(get_theme_color("yellow", theme)).bold());
+        f.render_widget(tanda, row[2]);
+    }
+}
+
+#[allow(clippy::too_many_arguments)]
+fn render_input_pl_ui(
+    f: &mut Frame,
+    area: Rect,
+    editor: &EditorMatriks,
+    relasi: &[Relasi],
+    maksimum: bool,
+    ascii_lines: &Vec<Line<'_>>,
+    owner: &Line<'_>,
+    theme: &str,
+) {
+    let outer_chunks = Layout::default()
+        .direction(Direction::Vertical)
+        .constraints([
+            Constraint::Max(8), // Header di luar
+            Constraint::Min(0), // Sisanya untuk kontainer
+        ])
+        .split(area);
+
+    // Header
+    render_header(f, outer_chunks[0], ascii_lines, owner, theme);
+
+    let instructions = instruction_line(
+        &[
+            ("[Esc] ", "Menu "),
+            ("[←/→/↑/↓] ", "Pindah "),
+            ("[Ctrl+↑/↓] ", "Kendala "),
+            ("[Ctrl+←/→] ", "Variabel "),
+            ("[Tab] ", "Tanda "),
+            ("[Enter] ", "Hitung "),
+        ],
+        theme,
+    );
+
+    let container_block = Block::bordered()
+        .title_bottom(instructions.centered())
+        .title(" Input Program Linear ")
+        .borders(Borders::ALL)
+        .style(
+            Style::default()
+                .bg(get_theme_color("bg", theme))
+                .fg(get_theme_color("cyan", theme))
+                .bold(),
+        )
+        .bold();
+    let inner_area = container_block.inner(outer_chunks[1]); // Ambil area dalamnya
+    f.render_widget(container_block, outer_chunks[1]);
+
+    let baris = editor.jumlah_baris();
+    let n = editor.jumlah_variabel();
+    let nama = nama_variabel(n);
+    let mut constraints = vec![Constraint::Length(4)]; // Title row
+    constraints.extend(vec![Constraint::Length(3); baris]); // Fungsi tujuan lalu kendala
+    let chunks = Layout::default()
+        .direction(Direction::Vertical)
+        .constraints(constraints)
+        .split(inner_area);
+
+    let title = Paragraph::new(Text::from(vec![
+        Line::from(Span::styled(
+            "Program Linear",
+            Style::default().add_modifier(Modifier::BOLD),
+        )),
+        Line::from(Span::raw(format!(
+            "{} Z = c·x + k, {} kendala, {} ≥ 0",
+            if maksimum {
+                "Maksimumkan"
+            } else {
+                "Minimumkan"
+            },
+            baris - 1,
+            nama.join(", ")
+        ))),
+        Line::from(Span::raw(format!(
+            "Metode {}, [Tab] pada baris Z: maksimum/minimum",
+            if n == 2 { "titik pojok" } else { "simpleks" }
+        ))),
+    ]))
+    .alignment(Alignment::Center);
+    f.render_widget(title, chunks[0]);
+
+    // Setiap baris: label, koefisien, kotak maks/min atau tanda, lalu konstanta
+    let (r, c) = editor.kursor;
+    for (i, row_area) in chunks[1..].iter().enumerate() {
+        let mut kolom = vec![Constraint::Length(4)];
+        kolom.extend(vec![Constraint::Fill(1); n]);
+        kolom.extend([Constraint::Length(9), Constraint::Fill(1)]);
+        let row = Layout::default()
+            .direction(Direction::Horizontal)
+            .constraints(kolom)
+            .split(*row_area);
+
+        let label = if i == 0 {
+            "Z".to_string()
+        } else {
+            format!("K{}", i)
+        };
+        let label = Paragraph::new(format!("\n{}", label))
+            .alignment(Alignment::Center)
+            .style(Style::default().fg(get_theme_color("cyan", theme)).bold());
+        f.render_widget(label, row[0]);
+
+        let area_sel = row[1..=n].iter().chain(std::iter::once(&row[n + 2]));
+        for (j, area) in area_sel.enumerate() {
+            let teks = &editor.sel[i][j];
+            let selected = (i, j) == (r, c);
+            let nama_sel = match (i, nama.get(j)) {
+                (_, Some(v)) => v.as_str(),
+                (0, None) => "k",
+                (_, None) => "b",
+            };
+            let input = Paragraph::new(Line::from(vec![
+                Span::raw(format!("{}: ", nama_sel)),
+                Span::raw(teks),
+            ]))
+            .block(field_block(teks, selected, theme))
+            .style(if selected {
+                Style::default().fg(get_theme_color("yellow", theme))
+            } else {
+                Style::default().fg(get_theme_color("fg", theme))
+            });
+            f.render_widget(input, *area);
+        }
+
+        let tanda = match (i, maksimum) {
+            (0, true) => "Maks",
+            (0, false) => "Min",
+            _ => relasi[i - 1].simbol(),
+        };
+        let tanda = Paragraph::new(format!("◀ {} ▶", tanda))
+            .alignment(Alignment::Center)
+            .block(
+                Block::bordered().border_style(Style::default().fg(get_theme_color(
+                    if i == r { "yellow" } else { "blue" },
+                    theme,
+                ))),
+            )
+            .style(Style::default().fg(get_theme_color("yellow", theme)).bold());
+        f.render_widget(tanda, row[n + 1]);
+    }
+}
+
+fn render_input_gauss_ui(
+    f: &mut Frame,
+    area: Rect,
+    editor: &EditorMatriks,
+    ascii_lines: &Vec<Line<'_>>,
+    owner: &Line<'_>,
+    theme: &str,
+) {
+    let outer_chunks = Layout::default()
+        .direction(Direction::Vertical)
+        .constraints([
+            Constraint::Max(8), // Header di luar
+            Constraint::Min(0), // Sisanya untuk kontainer
+        ])
+        .split(area);
+
+    // Header
+    render_header(f, outer_chunks[0], ascii_lines, owner, theme);
+
+    let instructions = instruction_line(
+        &[
+            ("[Esc] ", "Menu "),
+            ("[←/→/↑/↓] ", "Pindah "),
+            ("[Ctrl+↑/↓] ", "Baris "),
+            ("[Ctrl+←/→] ", "Variabel "),
+            ("[Enter] ", "Hitung "),
+        ],
+        theme,
+    );
+
+    let container_block = Block::bordered()
+        .title_bottom(instructions.centered())
+        .title(" Input SPL n×n ")
+        .borders(Borders::ALL)
+        .style(
+            Style::default()
+                .bg(get_theme_color("bg", theme))
+                .fg(get_theme_color("cyan", theme))
+                .bold(),
+        )
+        .bold();
+    let inner_area = container_block.inner(outer_chunks[1]); // Ambil area dalamnya
+    f.render_widget(container_block, outer_chunks[1]);
+
+    let baris = editor.jumlah_baris();
+    let n = editor.jumlah_variabel();
+    let mut constraints = vec![Constraint::Length(4)]; // Title row
+    constraints.extend(vec![Constraint::Length(3); baris]); // Satu baris per persamaan
+    let chunks = Layout::default()
+        .direction(Direction::Vertical)
+        .constraints(constraints)
+        .split(inner_area);
+
+    let title = Paragraph::new(Text::from(vec![
+        Line::from(Span::styled(
+            "Sistem Persamaan Linear (Eliminasi Gauss)",
+            Style::default().add_modifier(Modifier::BOLD),
+        )),
+        Line::from(Span::raw(format!("{} persamaan, {} variabel", baris, n))),
+        Line::from(Span::raw("ai1x1 + ai2x2 + ... + ainxn = bi")),
+    ]))
+    .alignment(Alignment::Center);
+    f.render_widget(title, chunks[0]);
+
+    let labels: Vec<String> = (1..=baris)
+        .flat_map(|i| {
+            (1..=n)
+                .map(move |j| format!("a{}{}", i, j))
+                .chain(std::iter::once(format!("b{}", i)))
+        })
+        .collect();
+    let labels: Vec<&str> = labels.iter().map(String::as_str).collect();
+    let inputs: Vec<String> = editor.sel.concat();
+    let (r, c) = editor.kursor;
+    render_field_grid(
+        f,
+        &chunks[1..],
+        &labels,
+        &inputs,
+        r * (n + 1) + c,
+        false,
+        theme,
+    );
+}
+
+fn render_input_kuadrat_terkecil_ui(
+    f: &mut Frame,
+    area: Rect,
+    editor: &EditorMatriks,
+    ascii_lines: &Vec<Line<'_>>,
+    owner: &Line<'_>,
+    theme: &str,
+) {
+    let outer_chunks = Layout::default()
+        .direction(Direction::Vertical)
+        .constraints([
+            Constraint::Max(8), // Header di luar
+            Constraint::Min(0), // Sisanya untuk kontainer
+        ])
+        .split(area);
+
+    // Header
+    render_header(f, outer_chunks[0], ascii_lines, owner, theme);
+
+    let instructions = instruction_line(
+        &[
+            ("[Esc] ", "Menu "),
+            ("[←/→/↑/↓] ", "Pindah "),
+            ("[Ctrl+↑/↓] ", "Baris "),
+            ("[Ctrl+←/→] ", "Variabel "),
+            ("[Enter] ", "Hitung "),
+        ],
+        theme,
+    );
+
+    let container_block = Block::bordered()
+        .title_bottom(instructions.centered())
+        .title(" Input Kuadrat Terkecil ")
+        .borders(Borders::ALL)
+        .style(
+            Style::default()
+                .bg(get_theme_color("bg", theme))
+                .fg(get_theme_color("cyan", theme))
+                .bold(),
+        )
+        .bold();
+    let inner_area = container_block.inner(outer_chunks[1]); // Ambil area dalamnya
+    f.render_widget(container_block, outer_chunks[1]);
+
+    let baris = editor.jumlah_baris();
+    let n = editor.jumlah_variabel();
+    let mut constraints = vec![Constraint::Length(4)]; // Title row
+    constraints.extend(vec![Constraint::Length(3); baris]); // Satu baris per persamaan
+    let chunks = Layout::default()
+        .direction(Direction::Vertical)
+        .constraints(constraints)
+        .split(inner_area);
+
+    let title = Paragraph::new(Text::from(vec![
+        Line::from(Span::styled(
+            "Sistem Persamaan Linear (Kuadrat Terkecil)",
+            Style::default().add_modifier(Modifier::BOLD),
+        )),
+        Line::from(Span::raw(format!(
+            "{} persamaan, {} variabel — persamaan boleh lebih banyak daripada variabel",
+            baris, n
+        ))),
+        Line::from(Span::raw("ai1x1 + ai2x2 + ... + ainxn ≈ bi")),
+    ]))
+    .alignment(Alignment::Center);
+    f.render_widget(title, chunks[0]);
+
+    let labels: Vec<String> = (1..=baris)
+        .flat_map(|i| {
+            (1..=n)
+                .map(move |j| format!("a{}{}", i, j))
+                .chain(std::iter::once(format!("b{}", i)))
+        })
+        .collect();
+    let labels: Vec<&str> = labels.iter().map(String::as_str).collect();
+    let inputs: Vec<String> = editor.sel.concat();
+    let (r, c) = editor.kursor;
+    render_field_grid(
+        f,
+        &chunks[1..],
+        &labels,
+        &inputs,
+        r * (n + 1) + c,
+        false,
+        theme,
+    );
+}
+
+#[allow(clippy::too_many_arguments)]
+fn render_input_regresi_ui(
+    f: &mut Frame,
+    area: Rect,
+    editor: &EditorMatriks,
+    prediksi: &str,
+    di_prediksi: bool,
+    ascii_lines: &Vec<Line<'_>>,
+    owner: &Line<'_>,
+    theme: &str,
+) {
+    let outer_chunks = Layout::default()
+        .direction(Direction::Vertical)
+        .constraints([
+            Constraint::Max(8), // Header di luar
+            Constraint::Min(0), // Sisanya untuk kontainer
+        ])
+        .split(area);
+
+    // Header
+    render_header(f, outer_chunks[0], ascii_lines, owner, theme);
+
+    let instructions = instruction_line(
+        &[
+            ("[Esc] ", "Menu "),
+            ("[←/→/↑/↓] ", "Pindah "),
+            ("[Ctrl+↑/↓] ", "Data "),
+            ("[Tab] ", "Tabel/Prediksi "),
+            ("[Enter] ", "Hitung "),
+        ],
+        theme,
+    );
+
+    let container_block = Block::bordered()
+        .title_bottom(instructions.centered())
+        .title(" Input Regresi Linear ")
+        .borders(Borders::ALL)
+        .style(
+            Style::default()
+                .bg(get_theme_color("bg", theme))
+                .fg(get_theme_color("cyan", theme))
+                .bold(),
+        )
+        .bold();
+    let inner_area = container_block.inner(outer_chunks[1]); // Ambil area dalamnya
+    f.render_widget(container_block, outer_chunks[1]);
+
+    let chunks = Layout::default()
+        .direction(Direction::Vertical)
+        .constraints([
+            Constraint::Length(4), // Title row
+            Constraint::Min(0),    // Tabel data dan isian prediksi
+        ])
+        .split(inner_area);
+
+    let title = Paragraph::new(Text::from(vec![
+        Line::from(Span::styled(
+            "Regresi Linear (Garis Kuadrat Terkecil)",
+            Style::default().add_modifier(Modifier::BOLD),
+        )),
+        Line::from(Span::raw(format!(
+            "{} titik data (x, y), baris kosong dilewati",
+            editor.jumlah_baris()
+        ))),
+        Line::from(Span::raw("y = mx + c")),
+    ]))
+    .alignment(Alignment::Center);
+    f.render_widget(title, chunks[0]);
+
+    let kolom = Layout::default()
+        .direction(Direction::Horizontal)
+        .constraints([Constraint::Ratio(1, 2), Constraint::Ratio(1, 2)])
+        .split(chunks[1]);
+
+    // Tabel data: satu baris layar per titik agar muat hingga MAX_DATA titik
+    let lebar = 14;
+    let fg = Style::default().fg(get_theme_color("fg", theme));
+    let mut lines = vec![
+        Line::styled(
+            format!("{:>3} │ {:^lebar$} │ {:^lebar$}", "No", "x", "y"),
+            fg,
+        ),
+        Line::styled(
+            format!(
+                "{}┼{}┼{}",
+                "─".repeat(4),
+                "─".repeat(lebar + 2),
+                "─".repeat(lebar + 2)
+            ),
+            fg,
+        ),
+    ];
+    for (i, baris) in editor.sel.iter().enumerate() {
+        let mut spans = vec![Span::styled(format!("{:>3} ", i + 1), fg)];
+        for (j, teks) in baris.iter().enumerate() {
+            let aktif = !di_prediksi && editor.kursor == (i, j);
+            let warna = if !teks.trim().is_empty() && hitung(teks).is_err() {
+                "red"
+            } else if aktif {
+                "yellow"
+            } else {
+                "fg"
+            };
+            let mut gaya = Style::default().fg(get_theme_color(warna, theme));
+            if aktif {
+                gaya = gaya.add_modifier(Modifier::REVERSED);
+            }
+            spans.push(Span::styled("│ ", fg));
+            spans.push(Span::styled(format!("{:<lebar$}", teks), gaya));
+            spans.push(Span::raw(" "));
+        }
+        lines.push(Line::from(spans));
+    }
+
+    // Nilai atau kesalahan sel yang sedang disunting
+    let (r, c) = editor.kursor;
+    let teks = &editor.sel[r][c];
+    lines.push(Line::raw(""));
+    lines.push(match hitung(teks) {
+        _ if teks.trim().is_empty() || di_prediksi => Line::raw(""),
+        Err(e) => Line::styled(e, Style::default().fg(get_theme_color("red", theme))),
+        Ok(n) => Line::styled(
+            format!("{}{} = {}", ["x", "y"][c], r + 1, n.nilai.pecahan()),
+            Style::default().fg(get_theme_color("green", theme)),
+        ),
+    });
+    let tabel = Paragraph::new(Text::from(lines)).block(
+        Block::bordered()
+            .title(" Data (x, y) ")
+            .border_style(Style::default().fg(get_theme_color(
+                if di_prediksi { "blue" } else { "yellow" },
+                theme,
+            ))),
+    );
+    f.render_widget(tabel, kolom[0]);
+
+    let kanan = Layout::default()
+        .direction(Direction::Vertical)
+        .constraints([
+            Constraint::Length(1),
+            Constraint::Length(3),
+            Constraint::Min(0),
+        ])
+        .split(kolom[1]);
+    f.render_widget(
+        Paragraph::new(Line::styled(
+            "Prediksi ŷ untuk x (boleh dikosongkan)",
+            Style::default().fg(get_theme_color(
+                if di_prediksi { "yellow" } else { "fg" },
+                theme,
+            )),
+        ))
+        .alignment(Alignment::Center),
+        kanan[0],
+    );
+    render_field_grid(
+        f,
+        &kanan[1..2],
+        &["x"],
+        std::slice::from_ref(&prediksi.to_string()),
+        if di_prediksi { 0 } else { usize::MAX },
+        false,
+        theme,
+    );
+}
+
+fn render_input_iteratif_ui(
+    f: &mut Frame,
+    area: Rect,
+    isian: &IsianIteratif,
+    ascii_lines: &Vec<Line<'_>>,
+    owner: &Line<'_>,
+    theme: &str,
+) {
+    let outer_chunks = Layout::default()
+        .direction(Direction::Vertical)
+        .constraints([
+            Constraint::Max(8), // Header di luar
+            Constraint::Min(0), // Sisanya untuk kontainer
+        ])
+        .split(area);
+
+    // Header
+    render_header(f, outer_chunks[0], ascii_lines, owner, theme);
+
+    let instructions = instruction_line(
+        &[
+            ("[Esc] ", "Menu "),
+            ("[Tab] ", "Metode "),
+            ("[←/→/↑/↓] ", "Pindah "),
+            ("[Ctrl+↑/↓] ", "Baris "),
+            ("[Ctrl+←/→] ", "Variabel "),
+            ("[Enter] ", "Hitung "),
+        ],
+        theme,
+    );
+
+    let container_block = Block::bordered()
+        .title_bottom(instructions.centered())
+        .title(" Input Metode Iteratif ")
+        .borders(Borders::ALL)
+        .style(
+            Style::default()
+                .bg(get_theme_color("bg", theme))
+                .fg(get_theme_color("cyan", theme))
+                .bold(),
+        )
+        .bold();
+    let inner_area = container_block.inner(outer_chunks[1]); // Ambil area dalamnya
+    f.render_widget(container_block, outer_chunks[1]);
+
+    let editor = &isian.matriks;
+    let baris = editor.jumlah_baris();
+    let n = editor.jumlah_variabel();
+    let mut constraints = vec![Constraint::Length(5)]; // Title row
+    constraints.extend(vec![Constraint::Length(3); baris]); // Satu baris per persamaan
+    constraints.push(Constraint::Length(1)); // Galat ukuran
+    constraints.push(Constraint::Length(1)); // Judul parameter
+    constraints.push(Constraint::Length(3)); // Isian parameter
+    let chunks = Layout::default()
+        .direction(Direction::Vertical)
+        .constraints(constraints)
+        .split(inner_area);
+
+    let title = Paragraph::new(Text::from(vec![
+        Line::from(Span::styled(
+            "Sistem Persamaan Linear (Metode Iteratif)",
+            Style::default().add_modifier(Modifier::BOLD),
+        )),
+        Line::from(Span::raw(format!("{} persamaan, {} variabel", baris, n))),
+        Line::from(Span::raw("ai1x1 + ai2x2 + ... + ainxn = bi")),
+        Line::from(vec![
+            Span::raw("Metode: "),
+            Span::styled(
+                format!("◀ {} ▶", isian.metode.nama()),
+                Style::default().fg(get_theme_color("yellow", theme)).bold(),
+            ),
+        ]),
+    ]))
+    .alignment(Alignment::Center);
+    f.render_widget(title, chunks[0]);
+
+    let labels: Vec<String> = (1..=baris)
+        .flat_map(|i| {
+            (1..=n)
+                .map(move |j| format!("a{}{}", i, j))
+                .chain(std::iter::once(format!("b{}", i)))
+        })
+        .collect();
+    let labels: Vec<&str> = labels.iter().map(String::as_str).collect();
+    let inputs: Vec<String> = editor.sel.concat();
+    let (r, c) = editor.kursor;
+    render_field_grid(
+        f,
+        &chunks[1..=baris],
+        &labels,
+        &inputs,
+        if isian.aktif.is_none() {
+            r * (n + 1) + c
+        } else {
+            usize::MAX
+        },
+        false,
+        theme,
+    );
+    render_galat_ukuran(f, chunks[baris + 1], editor.galat_persegi(), theme);
+
+    f.render_widget(
+        Paragraph::new(Line::styled(
+            "Parameter iterasi (x⁽⁰⁾ boleh satu nilai atau n nilai dipisah ;)",
+            Style::default().fg(get_theme_color(
+                if isian.aktif.is_some() {
+                    "yellow"
+                } else {
+                    "fg"
+                },
+                theme,
+            )),
+        ))
+        .alignment(Alignment::Center),
+        chunks[baris + 2],
+    );
+
+    // Hanya kesalahan pertama yang ditandai, sesuai urutan pemeriksaan
+    let galat = ParameterIteratif::baca(&isian.parameter, n, isian.metode).err();
+    let kolom = Layout::default()
+        .direction(Direction::Horizontal)
+        .constraints(vec![Constraint::Ratio(1, 4); 4])
+        .split(chunks[baris + 3]);
+    for (i, (teks, area)) in isian.parameter.iter().zip(kolom.iter()).enumerate() {
+        let selected = isian.aktif == Some(i);
+        let keterangan = match &galat {
+            Some((j, e)) if *j == i && !teks.trim().is_empty() => Some(Err(e.clone())),
+            _ if i == 3 && isian.metode != MetodeIteratif::Sor => {
+                Some(Ok("hanya untuk SOR".to_string()))
+            }
+            _ => None,
+        };
+        let input = Paragraph::new(Line::from(vec![
+            Span::raw(format!("{}: ", LABEL_PARAMETER[i])),
+            Span::raw(teks.as_str()),
+        ]))
+        .block(bingkai_isian(keterangan, selected, theme))
+        .style(if selected {
+            Style::default().fg(get_theme_color("yellow", theme))
+        } else {
+            Style::default().fg(get_theme_color("fg", theme))
+        });
+        f.render_widget(input, *area);
+    }
+}
+
+fn render_input_dekomposisi_ui(
+    f: &mut Frame,
+    area: Rect,
+    editor: &EditorMatriks,
+    jenis: JenisDekomposisi,
+    ascii_lines: &Vec<Line<'_>>,
+    owner: &Line<'_>,
+    theme: &str,
+) {
+    let outer_chunks = Layout::default()
+        .direction(Direction::Vertical)
+        .constraints([
+            Constraint::Max(8), // Header di luar
+            Constraint::Min(0), // Sisanya untuk kontainer
+        ])
+        .split(area);
+
+    // Header
+    render_header(f, outer_chunks[0], ascii_lines, owner, theme);
+
+    let instructions = instruction_line(
+        &[
+            ("[Esc] ", "Menu "),
+            ("[Tab] ", "Dekomposisi "),
+            ("[←/→/↑/↓] ", "Pindah "),
+            ("[Ctrl+↑/↓] ", "Baris "),
+            ("[Ctrl+←/→] ", "Kolom "),
+            ("[Enter] ", "Hitung "),
+        ],
+        theme,
+    );
+
+    let container_block = Block::bordered()
+        .title_bottom(instructions.centered())
+        .title(" Input Dekomposisi Matriks ")
+        .borders(Borders::ALL)
+        .style(
+            Style::default()
+                .bg(get_theme_color("bg", theme))
+                .fg(get_theme_color("cyan", theme))
+                .bold(),
+        )
+        .bold();
+    let inner_area = container_block.inner(outer_chunks[1]); // Ambil area dalamnya
+    f.render_widget(container_block, outer_chunks[1]);
+
+    let baris = editor.jumlah_baris();
+    let n = editor.jumlah_variabel();
+    let mut constraints = vec![Constraint::Length(5)]; // Title row
+    constraints.extend(vec![Constraint::Length(3); baris]); // Satu baris per persamaan
+    constraints.push(Constraint::Length(1)); // Galat ukuran
+    let chunks = Layout::default()
+        .direction(Direction::Vertical)
+        .constraints(constraints)
+        .split(inner_area);
+
+    let title = Paragraph::new(Text::from(vec![
+        Line::from(Span::styled(
+            "Dekomposisi Matriks (LU, QR, Cholesky)",
+            Style::default().add_modifier(Modifier::BOLD),
+        )),
+        Line::from(Span::raw(format!(
+            "A berukuran {}×{} — kolom b boleh dikosongkan jika hanya ingin faktorisasi",
+            baris, n
+        ))),
+        Line::from(Span::raw("[A | b], Ax = b diselesaikan dengan faktornya")),
+        Line::from(vec![
+            Span::raw("Dekomposisi: "),
+            Span::styled(
+                format!("◀ {} ▶", jenis.nama()),
+                Style::default().fg(get_theme_color("yellow", theme)).bold(),
+            ),
+        ]),
+    ]))
+    .alignment(Alignment::Center);
+    f.render_widget(title, chunks[0]);
+
+    let labels: Vec<String> = (1..=baris)
+        .flat_map(|i| {
+            (1..=n)
+                .map(move |j| format!("a{}{}", i, j))
+                .chain(std::iter::once(format!("b{}", i)))
+        })
+        .collect();
+    let labels: Vec<&str> = labels.iter().map(String::as_str).collect();
+    let inputs: Vec<String> = editor.sel.concat();
+    let (r, c) = editor.kursor;
+    render_field_grid(
+        f,
+        &chunks[1..=baris],
+        &labels,
+        &inputs,
+        r * (n + 1) + c,
+        false,
+        theme,
+    );
+    render_galat_ukuran(f, chunks[baris + 1], editor.galat_persegi(), theme);
+}
+
+fn render_input_ruang_ui(
+    f: &mut Frame,
+    area: Rect,
+    editor: &EditorMatriks,
+    ascii_lines: &Vec<Line<'_>>,
+    owner: &Line<'_>,
+    theme: &str,
+) {
+    let outer_chunks = Layout::default()
+        .direction(Direction::Vertical)
+        .constraints([
+            Constraint::Max(8), // Header di luar
+            Constraint::Min(0), // Sisanya untuk kontainer
+        ])
+        .split(area);
+
+    // Header
+    render_header(f, outer_chunks[0], ascii_lines, owner, theme);
+
+    let instructions = instruction_line(
+        &[
+            ("[Esc] ", "Menu "),
+            ("[←/→/↑/↓] ", "Pindah "),
+            ("[Ctrl+↑/↓] ", "Baris "),
+            ("[Ctrl+←/→] ", "Kolom "),
+            ("[Enter] ", "Hitung "),
+        ],
+        theme,
+    );
+
+    let container_block = Block::bordered()
+        .title_bottom(instructions.centered())
+        .title(" Input Ruang Fundamental ")
+        .borders(Borders::ALL)
+        .style(
+            Style::default()
+                .bg(get_theme_color("bg", theme))
+                .fg(get_theme_color("cyan", theme))
+                .bold(),
+        )
+        .bold();
+    let inner_area = container_block.inner(outer_chunks[1]); // Ambil area dalamnya
+    f.render_widget(container_block, outer_chunks[1]);
+
+    let baris = editor.jumlah_baris();
+    let kolom = editor.jumlah_kolom();
+    let mut constraints = vec![Constraint::Length(4)]; // Title row
+    constraints.extend(vec![Constraint::Length(3); baris]); // Satu baris per baris matriks
+    let chunks = Layout::default()
+        .direction(Direction::Vertical)
+        .constraints(constraints)
+        .split(inner_area);
+
+    let title = Paragraph::new(Text::from(vec![
+        Line::from(Span::styled(
+            "Ruang Fundamental Matriks",
+            Style::default().add_modifier(Modifier::BOLD),
+        )),
+        Line::from(Span::raw(format!("A berukuran {}×{}", baris, kolom))),
+        Line::from(Span::raw(
+            "Rank, nulitas, basis Null(A), Col(A), dan Row(A)",
+        )),
+    ]))
+    .alignment(Alignment::Center);
+    f.render_widget(title, chunks[0]);
+
+    let labels: Vec<String> = (1..=baris)
+        .flat_map(|i| (1..=kolom).map(move |j| format!("a{}{}", i, j)))
+        .collect();
+    let labels: Vec<&str> = labels.iter().map(String::as_str).collect();
+    let inputs: Vec<String> = editor.sel.concat();
+    let (r, c) = editor.kursor;
+    render_field_grid(
+        f,
+        &chunks[1..],
+        &labels,
+        &inputs,
+        r * kolom + c,
+        false,
+        theme,
+    );
+}
+
+fn render_input_eigen_ui(
+    f: &mut Frame,
+    area: Rect,
+    editor: &EditorMatriks,
+    ascii_lines: &Vec<Line<'_>>,
+    owner: &Line<'_>,
+    theme: &str,
+) {
+    let outer_chunks = Layout::default()
+        .direction(Direction::Vertical)
+        .constraints([
+            Constraint::Max(8), // Header di luar
+            Constraint::Min(0), // Sisanya untuk kontainer
+        ])
+        .split(area);
+
+    // Header
+    render_header(f, outer_chunks[0], ascii_lines, owner, theme);
+
+    let instructions = instruction_line(
+        &[
+            ("[Esc] ", "Menu "),
+            ("[←/→/↑/↓] ", "Pindah "),
+            ("[Ctrl+↑/↓] ", "Baris "),
+            ("[Ctrl+←/→] ", "Kolom "),
+            ("[Enter] ", "Hitung "),
+        ],
+        theme,
+    );
+
+    let container_block = Block::bordered()
+        .title_bottom(instructions.centered())
+        .title(" Input Nilai Eigen ")
+        .borders(Borders::ALL)
+        .style(
+            Style::default()
+                .bg(get_theme_color("bg", theme))
+                .fg(get_theme_color("cyan", theme))
+                .bold(),
+        )
+        .bold();
+    let inner_area = container_block.inner(outer_chunks[1]); // Ambil area dalamnya
+    f.render_widget(container_block, outer_chunks[1]);
+
+    let baris = editor.jumlah_baris();
+    let kolom = editor.jumlah_kolom();
+    let mut constraints = vec![Constraint::Length(4)]; // Title row
+    constraints.extend(vec![Constraint::Length(3); baris]); // Satu baris per baris matriks
+    constraints.push(Constraint::Length(1)); // Galat ukuran
+    let chunks = Layout::default()
+        .direction(Direction::Vertical)
+        .constraints(constraints)
+        .split(inner_area);
+
+    let title = Paragraph::new(Text::from(vec![
+        Line::from(Span::styled(
+            "Nilai dan Vektor Eigen",
+            Style::default().add_modifier(Modifier::BOLD),
+        )),
+        Line::from(Span::raw(format!("A berukuran {}×{}", baris, kolom))),
+        Line::from(Span::raw("det(A − λI) = 0, vektor eigen, dan A = PDP⁻¹")),
+    ]))
+    .alignment(Alignment::Center);
+    f.render_widget(title, chunks[0]);
+
+    let labels: Vec<String> = (1..=baris)
+        .flat_map(|i| (1..=kolom).map(move |j| format!("a{}{}", i, j)))
+        .collect();
+    let labels: Vec<&str> = labels.iter().map(String::as_str).collect();
+    let inputs: Vec<String> = editor.sel.concat();
+    let (r, c) = editor.kursor;
+    render_field_grid(
+        f,
+        &chunks[1..=baris],
+        &labels,
+        &inputs,
+        r * kolom + c,
+        false,
+        theme,
+    );
+    render_galat_ukuran(
+        f,
+        chunks[baris + 1],
+        periksa_ukuran(baris, kolom).err(),
+        theme,
+    );
+}
+
+// Galat ukuran matriks di bawah isian, mis. matriks yang tidak persegi
+fn render_galat_ukuran(f: &mut Frame, area: Rect, galat: Option<String>, theme: &str) {
+    if let Some(pesan) = galat {
+        f.render_widget(
+            Paragraph::new(Line::styled(
+                pesan,
+                Style::default().fg(get_theme_color("red", theme)),
+            ))
+            .alignment(Alignment::Center),
+            area,
+        );
+    }
+}
+
+fn render_input_teks_ui(
+    f: &mut Frame,
+    area: Rect,
+    baris: &[String],
+    selected: usize,
+    ascii_lines: &Vec<Line<'_>>,
+    owner: &Line<'_>,
+    theme: &str,
+) {
+    let outer_chunks = Layout::default()
+        .direction(Direction::Vertical)
+        .constraints([
+            Constraint::Max(8), // Header di luar
+            Constraint::Min(0), // Sisanya untuk kontainer
+        ])
+        .split(area);
+
+    // Header
+    render_header(f, outer_chunks[0], ascii_lines, owner, theme);
+
+    let instructions = instruction_line_keluar(
+        &[
+            ("[Esc] ", "Menu "),
+            ("[↑/↓] ", "Pindah "),
+            ("[Ctrl+↑/↓] ", "Persamaan "),
+            ("[Enter] ", "Periksa "),
+        ],
+        "[Ctrl+Q] ",
+        theme,
+    );
+
+    let container_block = Block::bordered()
+        .title_bottom(instructions.centered())
+        .title(" Input Persamaan Bebas ")
+        .borders(Borders::ALL)
+        .style(
+            Style::default()
+                .bg(get_theme_color("bg", theme))
+                .fg(get_theme_color("cyan", theme))
+                .bold(),
+        )
+        .bold();
+    let inner_area = container_block.inner(outer_chunks[1]); // Ambil area dalamnya
+    f.render_widget(container_block, outer_chunks[1]);
+
+    let mut constraints = vec![Constraint::Length(4)]; // Title row
+    constraints.extend(vec![Constraint::Length(3); baris.len()]); // Satu kotak per persamaan
+    let chunks = Layout::default()
+        .direction(Direction::Vertical)
+        .constraints(constraints)
+        .split(inner_area);
+
+    let title = Paragraph::new(Text::from(vec![
+        Line::from(Span::styled(
+            "Sistem Persamaan Linear (Teks Bebas)",
+            Style::default().add_modifier(Modifier::BOLD),
+        )),
+        Line::from(Span::raw(
+            "Tulis persamaan apa adanya, mis. 2x + 3y = 7 atau a - b = 2(b + 1)",
+        )),
+        Line::from(Span::raw(format!("{} persamaan", baris.len()))),
+    ]))
+    .alignment(Alignment::Center);
+    f.render_widget(title, chunks[0]);
+
+    // Galat yang hanya terlihat dari seluruh sistem (mis. nama xy bersama x
+    // dan y) ditandai di baris yang memuatnya
+    let galat_sistem = sistem_linear(baris).err();
+    for (i, (teks, area)) in baris.iter().zip(&chunks[1..]).enumerate() {
+        let keterangan = match persamaan_linear(teks) {
+            _ if teks.trim().is_empty() => None,
+            Ok(_) if galat_sistem.as_ref().is_some_and(|(j, _)| *j == i) => {
+                galat_sistem.clone().map(|(_, e)| Err(e))
+            }
+            Ok(b) => {
+                let suku: Vec<(Pecahan, &str)> =
+                    b.suku.iter().map(|(v, k)| (*k, v.as_str())).collect();
+                Some(Ok(format!(
+                    "→ {}",
+                    persamaan(&suku, -b.konstanta, Tampilan::Pecahan)
+                )))
+            }
+            Err(e) => Some(Err(e)),
+        };
+        let input = Paragraph::new(Line::from(vec![
+            Span::raw(format!("P{}: ", i + 1)),
+            Span::raw(teks.as_str()),
+        ]))
+        .block(bingkai_isian(keterangan, i == selected, theme))
+        .style(if i == selected {
+            Style::default().fg(get_theme_color("yellow", theme))
+        } else {
+            Style::default().fg(get_theme_color("fg", theme))
+        });
+        f.render_widget(input, *area);
+    }
+}
+
+#[allow(clippy::too_many_arguments)]
+fn render_input_substitusi_ui(
+    f: &mut Frame,
+    area: Rect,
+    baris: &[String],
+    selected: usize,
+    ascii_lines: &Vec<Line<'_>>,
+    owner: &Line<'_>,
+    theme: &str,
+) {
+    let outer_chunks = Layout::default()
+        .direction(Direction::Vertical)
+        .constraints([
+            Constraint::Max(8), // Header di luar
+            Constraint::Min(0), // Sisanya untuk kontainer
+        ])
+        .split(area);
+
+    // Header
+    render_header(f, outer_chunks[0], ascii_lines, owner, theme);
+
+    let instructions = instruction_line_keluar(
+        &[
+            ("[Esc] ", "Menu "),
+            ("[↑/↓] ", "Pindah "),
+            ("[Enter] ", "Hitung "),
+        ],
+        "[Ctrl+Q] ",
+        theme,
+    );
+
+    let container_block = Block::bordered()
+        .title_bottom(instructions.centered())
+        .title(" Input SPLDV Bentuk Khusus ")
+        .borders(Borders::ALL)
+        .style(
+            Style::default()
+                .bg(get_theme_color("bg", theme))
+                .fg(get_theme_color("cyan", theme))
+                .bold(),
+        )
+        .bold();
+    let inner_area = container_block.inner(outer_chunks[1]); // Ambil area dalamnya
+    f.render_widget(container_block, outer_chunks[1]);
+
+    let mut constraints = vec![Constraint::Length(4)]; // Title row
+    constraints.extend(vec![Constraint::Length(3); baris.len()]); // Satu kotak per persamaan
+    let chunks = Layout::default()
+        .direction(Direction::Vertical)
+        .constraints(constraints)
+        .split(inner_area);
+
+    let title = Paragraph::new(Text::from(vec![
+        Line::from(Span::styled(
+            "SPLDV Bentuk Khusus (Pemisalan)",
+            Style::default().add_modifier(Modifier::BOLD),
+        )),
+        Line::from(Span::raw(
+            "Suku 1/x, x², dan √x dimisalkan, mis. 2/x + 3/y = 1 atau x^2 + sqrt(y) = 7",
+        )),
+    ]))
+    .alignment(Alignment::Center);
+    f.render_widget(title, chunks[0]);
+
+    // Galat yang hanya terlihat dari seluruh sistem ditandai di baris yang
+    // memuatnya; variabel yang muncul dalam dua bentuk di persamaan terakhir
+    let galat_sistem = match sistem_substitusi(baris) {
+        Ok(sistem) => variabel_asal(&sistem).err().map(|e| (baris.len() - 1, e)),
+        Err(galat) => Some(galat),
+    };
+    for (i, (teks, area)) in baris.iter().zip(&chunks[1..]).enumerate() {
+        let keterangan = match persamaan_substitusi(teks) {
+            _ if teks.trim().is_empty() => None,
+            Ok(_) if galat_sistem.as_ref().is_some_and(|(j, _)| *j == i) => {
+                galat_sistem.clone().map(|(_, e)| Err(e))
+            }
+            Ok(b) => {
+                let suku: Vec<(Pecahan, &str)> =
+                    b.suku.iter().map(|(v, k)| (*k, v.as_str())).collect();
+                Some(Ok(format!(
+                    "→ {}",
+                    persamaan_bentuk(&suku, -b.konstanta, Tampilan::Pecahan)
+                )))
+            }
+            Err(e) => Some(Err(e)),
+        };
+        let input = Paragraph::new(Line::from(vec![
+            Span::raw(format!("P{}: ", i + 1)),
+            Span::raw(teks.as_str()),
+        ]))
+        .block(bingkai_isian(keterangan, i == selected, theme))
+        .style(if i == selected {
+            Style::default().fg(get_theme_color("yellow", theme))
+        } else {
+            Style::default().fg(get_theme_color("fg", theme))
+        });
+        f.render_widget(input, *area);
+    }
+}
+
+// Baris pemilih operasi kalkulator matriks di bawah judul input
+fn operasi_line(operasi: OperasiMatriks, theme: &str) -> Line<'static> {
+    Line::from(vec![
+        Span::raw("Operasi: "),
+        Span::styled(
+            format!("◀ {} ▶", operasi.nama()),
+            Style::default().fg(get_theme_color("yellow", theme)).bold(),
+        ),
+    ])
+}
+
+// Grid isian satu matriks beserta judulnya, mis. "Matriks A (2×3)".
+// `aktif` menandai apakah kursor sedang berada di matriks ini.
+fn render_matriks_grid(
+    f: &mut Frame,
+    area: Rect,
+    nama: &str,
+    editor: &EditorMatriks,
+    aktif: bool,
+    theme: &str,
+) {
+    let (baris, kolom) = (editor.jumlah_baris(), editor.jumlah_kolom());
+    let mut constraints = vec![Constraint::Length(1)]; // Judul matriks
+    constraints.extend(vec![Constraint::Length(3); baris]);
+    let chunks = Layout::default()
+        .direction(Direction::Vertical)
+        .constraints(constraints)
+        .split(area);
+
+    let judul = Paragraph::new(Line::styled(
+        format!("Matriks {} ({}×{})", nama, baris, kolom),
+        Style::default().fg(get_theme_color(if aktif { "yellow" } else { "fg" }, theme)),
+    ))
+    .alignment(Alignment::Center);
+    f.render_widget(judul, chunks[0]);
+
+    let huruf = nama.to_lowercase();
+    let labels: Vec<String> = (1..=baris)
+        .flat_map(|i| {
+            let huruf = huruf.clone();
+            (1..=kolom).map(move |j| format!("{}{}{}", huruf, i, j))
+        })
+        .collect();
+    let labels: Vec<&str> = labels.iter().map(String::as_str).collect();
+    let inputs: Vec<String> = editor.sel.concat();
+    let (r, c) = editor.kursor;
+    let selected = if aktif { r * kolom + c } else { usize::MAX };
+    render_field_grid(f, &chunks[1..], &labels, &inputs, selected, false, theme);
+}
+
+fn render_input_matriks_ui(
+    f: &mut Frame,
+    area: Rect,
+    kalkulator: &KalkulatorMatriks,
+    ascii_lines: &Vec<Line<'_>>,
+    owner: &Line<'_>,
+    theme: &str,
+) {
+    let outer_chunks = Layout::default()
+        .direction(Direction::Vertical)
+        .constraints([
+            Constraint::Max(8), // Header di luar
+            Constraint::Min(0), // Sisanya untuk kontainer
+        ])
+        .split(area);
+
+    // Header
+    render_header(f, outer_chunks[0], ascii_lines, owner, theme);
+
+    let instructions = instruction_line(
+        &[
+            ("[Esc] ", "Menu "),
+            ("[←/→/↑/↓] ", "Pindah "),
+            ("[Ctrl+↑/↓] ", "Baris "),
+            ("[Ctrl+←/→] ", "Kolom "),
+            ("[Tab] ", "Operasi "),
+            ("[Enter] ", "Hitung "),
+        ],
+        theme,
+    );
+
+    let container_block = Block::bordered()
+        .title_bottom(instructions.centered())
+        .title(" Kalkulator Matriks ")
+        .borders(Borders::ALL)
+        .style(
+            Style::default()
+                .bg(get_theme_color("bg", theme))
+                .fg(get_theme_color("cyan", theme))
+                .bold(),
+        )
+        .bold();
+    let inner_area = container_block.inner(outer_chunks[1]); // Ambil area dalamnya
+    f.render_widget(container_block, outer_chunks[1]);
+
+    let chunks = Layout::default()
+        .direction(Direction::Vertical)
+        .constraints([
+            Constraint::Length(5), // Title row
+            Constraint::Min(0),    // Matriks A dan B/k
+        ])
+        .split(inner_area);
+
+    // Ukuran yang tidak cocok langsung ditampilkan di bawah judul
+    let galat = match kalkulator.galat_ukuran() {
+        Some(pesan) => Line::styled(pesan, Style::default().fg(get_theme_color("red", theme))),
+        None => Line::raw(""),
+    };
+    let title = Paragraph::new(Text::from(vec![
+        Line::from(Span::styled(
+            "Kalkulator Matriks",
             Style::default().add_modifier(Modifier::BOLD),
         )),
-        Line::from(Span::raw("ax + b = 0")),
+        Line::from(Span::raw(
+            "A + B, A · B, kA, Aᵀ, A⁻¹, det(A), rank(A), dan RREF(A)",
+        )),
+        galat,
+        operasi_line(kalkulator.operasi, theme),
     ]))
     .alignment(Alignment::Center);
     f.render_widget(title, chunks[0]);
 
-    let input_row = Layout::default()
+    let kolom = Layout::default()
         .direction(Direction::Horizontal)
-        .constraints([Constraint::Percentage(50); 2])
+        .constraints([Constraint::Ratio(1, 2), Constraint::Ratio(1, 2)])
         .split(chunks[1]);
+    let operasi = kalkulator.operasi;
+    render_matriks_grid(
+        f,
+        kolom[0],
+        "A",
+        &kalkulator.a,
+        kalkulator.aktif == 0,
+        theme,
+    );
+    if operasi.pakai_b() {
+        render_matriks_grid(
+            f,
+            kolom[1],
+            "B",
+            &kalkulator.b,
+            kalkulator.aktif == 1,
+            theme,
+        );
+    } else if operasi.pakai_k() {
+        let area = Layout::default()
+            .direction(Direction::Vertical)
+            .constraints([Constraint::Length(1), Constraint::Length(3)])
+            .split(kolom[1]);
+        let aktif = kalkulator.aktif == 1;
+        f.render_widget(
+            Paragraph::new(Line::styled(
+                "Skalar",
+                Style::default().fg(get_theme_color(if aktif { "yellow" } else { "fg" }, theme)),
+            ))
+            .alignment(Alignment::Center),
+            area[0],
+        );
+        render_field_grid(
+            f,
+            &area[1..],
+            &["k"],
+            std::slice::from_ref(&kalkulator.k),
+            if aktif { 0 } else { usize::MAX },
+            false,
+            theme,
+        );
+    } else {
+        f.render_widget(
+            Paragraph::new(Line::styled(
+                format!("{} hanya memakai matriks A", operasi.nama()),
+                Style::default().fg(get_theme_color("fg", theme)),
+            ))
+            .alignment(Alignment::Center),
+            kolom[1],
+        );
+    }
+}
 
-    let labels = vec!["a", "b"];
-    for (i, area) in input_row.iter().enumerate() {
-        let input = Paragraph::new(Line::from(vec![
-            Span::styled(format!("{}: ", labels[i]), Style::default().bold()),
-            Span::styled(&inputs[i], Style::default().bold()),
-        ]))
-        .block(
-            Block::bordered()
-                .borders(Borders::ALL)
-                .border_style(if i == selected {
-                    Style::default().fg(get_theme_color("yellow", theme)).bold()
-                } else {
-                    Style::default().fg(get_theme_color("blue", theme)).bold()
-                }),
+fn render_konfirmasi_teks_ui(
+    f: &mut Frame,
+    area: Rect,
+    baris: &[String],
+    ascii_lines: &Vec<Line<'_>>,
+    owner: &Line<'_>,
+    theme: &str,
+) {
+    let outer_chunks = Layout::default()
+        .direction(Direction::Vertical)
+        .constraints([
+            Constraint::Max(8), // Header di luar
+            Constraint::Min(0), // Sisanya untuk kontainer
+        ])
+        .split(area);
+
+    // Header
+    render_header(f, outer_chunks[0], ascii_lines, owner, theme);
+
+    let instructions = instruction_line(&[("[Esc] ", "Ubah "), ("[Enter] ", "Selesaikan ")], theme);
+
+    let container_block = Block::bordered()
+        .title_bottom(instructions.centered())
+        .title(" Konfirmasi Persamaan ")
+        .borders(Borders::ALL)
+        .style(
+            Style::default()
+                .bg(get_theme_color("bg", theme))
+                .fg(get_theme_color("cyan", theme))
+                .bold(),
         )
-        .alignment(Alignment::Left)
-        .style(if i == selected {
-            Style::default().fg(get_theme_color("yellow", theme)).bold()
+        .bold();
+    let inner_area = container_block.inner(outer_chunks[1]); // Ambil area dalamnya
+    f.render_widget(container_block, outer_chunks[1]);
+
+    let mut lines = vec![Line::from(Span::styled(
+        "Persamaan dibaca sebagai:",
+        Style::default().add_modifier(Modifier::BOLD),
+    ))];
+    if let Ok(sistem) = sistem_linear(baris) {
+        for i in 0..sistem.matriks.len() {
+            lines.push(Line::styled(
+                format!("P{}: {}", i + 1, sistem.persamaan(i, Tampilan::Pecahan)),
+                Style::default().fg(get_theme_color("fg", theme)),
+            ));
+        }
+        lines.push(Line::raw(""));
+        lines.push(Line::raw(format!("Variabel: {}", sistem.nama.join(", "))));
+        lines.push(Line::raw("Matriks diperbesar [A | b]:"));
+        let gambar = tulis_matriks(&sistem.matriks, Tampilan::Pecahan);
+        let matriks = WidgetMatriks {
+            gambar: &gambar,
+            gaya: Style::default().fg(get_theme_color("fg", theme)),
+            warna_kurung: get_theme_color("cyan", theme),
+        };
+        let chunks = Layout::default()
+            .direction(Direction::Vertical)
+            .constraints([Constraint::Length(lines.len() as u16), Constraint::Min(0)])
+            .split(inner_area);
+        let w = matriks.lebar().min(chunks[1].width);
+        let area = Rect {
+            x: chunks[1].x + (chunks[1].width - w) / 2,
+            width: w,
+            ..chunks[1]
+        };
+        f.render_widget(
+            Paragraph::new(Text::from(lines)).alignment(Alignment::Center),
+            chunks[0],
+        );
+        f.render_widget(matriks, area);
+        return;
+    }
+
+    f.render_widget(
+        Paragraph::new(Text::from(lines)).alignment(Alignment::Center),
+        inner_area,
+    );
+}
+
+// Gambar matriks atau determinan: label di kiri baris tengah, sel dirata
+// kanan per kolom di antara kurung siku atau garis tegak, garis pemisah
+// ruas kanan, kolom tambahan di luar pengapit, dan keterangan di kanan
+struct WidgetMatriks<'a> {
+    gambar: &'a GambarMatriks,
+    gaya: Style,
+    warna_kurung: Color,
+}
+
+impl WidgetMatriks<'_> {
+    fn tinggi(&self) -> u16 {
+        self.gambar.sel.len() as u16
+    }
+
+    fn lebar(&self) -> u16 {
+        (0..self.gambar.sel.len())
+            .map(|i| self.baris(i).width())
+            .max()
+            .unwrap_or(0) as u16
+    }
+
+    // Kurung kiri dan kanan untuk baris ke-i dari n baris
+    fn kurung(&self, i: usize) -> (&'static str, &'static str) {
+        let n = self.gambar.sel.len();
+        match self.gambar.kurung {
+            Kurung::Tegak => ("│", "│"),
+            Kurung::Siku if n == 1 => ("[", "]"),
+            Kurung::Siku if i == 0 => ("⎡", "⎤"),
+            Kurung::Siku if i == n - 1 => ("⎣", "⎦"),
+            Kurung::Siku => ("⎢", "⎥"),
+        }
+    }
+
+    fn baris(&self, i: usize) -> Line<'static> {
+        let g = self.gambar;
+        let kurung = Style::default().fg(self.warna_kurung);
+        let tengah = i == g.sel.len().saturating_sub(1) / 2;
+        let (kiri, kanan) = self.kurung(i);
+        let label = if tengah {
+            g.kiri.clone()
         } else {
-            Style::default().fg(get_theme_color("fg", theme)).bold()
-        });
-        f.render_widget(input, *area);
+            " ".repeat(g.kiri.chars().count())
+        };
+        let mut spans = vec![Span::raw(label), Span::styled(kiri, kurung)];
+        for (j, (isi, w)) in g.sel[i].iter().zip(lebar_kolom(&g.sel)).enumerate() {
+            if g.pemisah == Some(j) {
+                spans.push(Span::raw(" "));
+                spans.push(Span::styled("│", kurung));
+            } else if j > 0 {
+                spans.push(Span::raw(" "));
+            }
+            spans.push(Span::raw(format!(" {:>w$}", isi)));
+        }
+        spans.push(Span::raw(" "));
+        spans.push(Span::styled(kanan, kurung));
+        if let Some(tambahan) = g.tambahan.get(i) {
+            let lebar = lebar_kolom(&g.tambahan);
+            for (j, (isi, w)) in tambahan.iter().zip(lebar).enumerate() {
+                let jarak = if j == 0 { " " } else { "  " };
+                spans.push(Span::raw(format!("{}{:>w$}", jarak, isi)));
+            }
+        }
+        if tengah {
+            spans.push(Span::raw(g.kanan.clone()));
+        }
+        Line::from(spans)
+    }
+}
+
+// Lebar terbesar di setiap kolom
+fn lebar_kolom(sel: &[Vec<String>]) -> Vec<usize> {
+    let n = sel.first().map_or(0, Vec::len);
+    (0..n)
+        .map(|j| sel.iter().map(|b| b[j].chars().count()).max().unwrap_or(1))
+        .collect()
+}
+
+impl Widget for WidgetMatriks<'_> {
+    fn render(self, area: Rect, buf: &mut Buffer) {
+        buf.set_style(area, self.gaya);
+        for i in 0..self.tinggi().min(area.height) {
+            buf.set_line(area.x, area.y + i, &self.baris(i as usize), area.width);
+        }
+    }
+}
+
+// Langkah penyelesaian yang dapat digulir: potongan teks dilipat dan
+// diratakan ke tengah, gambar matriks digambar dengan `WidgetMatriks`
+struct WidgetLangkah<'a> {
+    judul: Line<'a>,
+    isi: &'a Isi,
+    scroll: u16,
+    gaya: Style,
+    warna_kurung: Color,
+}
+
+impl<'a> Widget for WidgetLangkah<'a> {
+    fn render(self, area: Rect, buf: &mut Buffer) {
+        let lebar = area.width;
+        let teks = |baris: Vec<Line<'a>>| {
+            Paragraph::new(Text::from(baris))
+                .alignment(Alignment::Center)
+                .wrap(Wrap { trim: true })
+        };
+        let mut potongan = vec![(teks(vec![self.judul]), None)];
+        for blok in self.isi.blok() {
+            potongan.push(match blok {
+                Blok::Teks(t) => (teks(t.lines().map(Line::raw).collect()), None),
+                Blok::Matriks(g) => (
+                    Paragraph::default(),
+                    Some(WidgetMatriks {
+                        gambar: g,
+                        gaya: Style::default(),
+                        warna_kurung: self.warna_kurung,
+                    }),
+                ),
+            });
+        }
+        let tinggi = |(p, m): &(Paragraph, Option<WidgetMatriks>)| match m {
+            Some(m) => m.tinggi(),
+            None => p.line_count(lebar) as u16,
+        };
+
+        // Seluruh langkah digambar di luar layar lalu disalin bagian yang
+        // terlihat menurut posisi gulir
+        let total: u16 = potongan.iter().map(tinggi).sum();
+        let mut layar = Buffer::empty(Rect::new(0, 0, lebar, total));
+        layar.set_style(layar.area, self.gaya);
+        let mut y = 0;
+        for bagian in potongan {
+            let h = tinggi(&bagian);
+            match bagian {
+                (_, Some(m)) => {
+                    let w = m.lebar().min(lebar);
+                    m.render(Rect::new((lebar - w) / 2, y, w, h), &mut layar);
+                }
+                (p, None) => p.render(Rect::new(0, y, lebar, h), &mut layar),
+            }
+            y += h;
+        }
+        for dy in 0..area.height.min(total.saturating_sub(self.scroll)) {
+            for dx in 0..lebar {
+                buf[(area.x + dx, area.y + dy)] = layar[(dx, self.scroll + dy)].clone();
+            }
+        }
+    }
+}
+
+// Baris instruksi: pasangan (tombol, keterangan), diakhiri [Q] Keluar
+fn instruction_line(keys: &[(&'static str, &'static str)], theme: &str) -> Line<'static> {
+    instruction_line_keluar(keys, "[Q] ", theme)
+}
+
+// Seperti `instruction_line` untuk isian teks bebas, tempat huruf q
+// diketik sebagai variabel sehingga keluar memakai Ctrl+Q
+fn instruction_line_keluar(
+    keys: &[(&'static str, &'static str)],
+    keluar: &'static str,
+    theme: &str,
+) -> Line<'static> {
+    let mut spans = vec![Span::raw(" ")];
+    for (key, label) in keys {
+        spans.push(Span::styled(
+            *key,
+            Style::default().fg(get_theme_color("blue", theme)).bold(),
+        ));
+        spans.push(Span::styled(
+            *label,
+            Style::default().fg(get_theme_color("fg", theme)).bold(),
+        ));
     }
+    spans.push(Span::styled(
+        keluar,
+        Style::default().fg(get_theme_color("red", theme)).bold(),
+    ));
+    spans.push(Span::styled(
+        "Keluar ",
+        Style::default().fg(get_theme_color("red", theme)).bold(),
+    ));
+    Line::from(spans)
 }
 
 fn render_result_ui(
     f: &mut Frame,
     area: Rect,
-    result_text: &str,
+    result: &Hasil,
+    scroll: u16,
     ascii_lines: &Vec<Line<'_>>,
     owner: &Line<'_>,
     theme: &str,
@@ -571,6 +2972,26 @@ fn render_result_ui(
             "Menu ",
             Style::default().fg(get_theme_color("fg", theme)).bold(),
         ),
+        Span::styled(
+            "[↑/↓] ",
+            Style::default().fg(get_theme_color("blue", theme)).bold(),
+        ),
+        Span::styled(
+            "Gulir ",
+            Style::default().fg(get_theme_color("fg", theme)).bold(),
+        ),
+        Span::styled(
+            "[P] ",
+            Style::default().fg(get_theme_color("blue", theme)).bold(),
+        ),
+        Span::styled(
+            if result.tampil_desimal {
+                "Pecahan "
+            } else {
+                "Desimal "
+            },
+            Style::default().fg(get_theme_color("fg", theme)).bold(),
+        ),
         Span::styled(
             "[Q] ",
             Style::default().fg(get_theme_color("red", theme)).bold(),
@@ -597,15 +3018,724 @@ fn render_result_ui(
     let inner_area = container_block.inner(outer_chunks[1]);
     f.render_widget(container_block, outer_chunks[1]);
 
-    let mut lines: Vec<Line> = vec![Line::from(vec![Span::styled(
-        "Hasil:",
-        Style::default().fg(get_theme_color("green", theme)).bold(),
-    )])];
-    lines.extend(result_text.lines().map(|line| Line::raw(line.to_string())));
+    let result_paragraph = WidgetLangkah {
+        judul: Line::styled(
+            "Hasil:",
+            Style::default().fg(get_theme_color("green", theme)).bold(),
+        ),
+        isi: result.isi(),
+        scroll,
+        gaya: Style::default()
+            .bg(get_theme_color("bg", theme))
+            .fg(get_theme_color("fg", theme))
+            .bold(),
+        warna_kurung: get_theme_color("cyan", theme),
+    };
+
+    // Lencana peringatan tetap terlihat di atas langkah yang digulir
+    let inner_area = match &result.peringatan {
+        Some(teks) => {
+            let chunks = Layout::default()
+                .direction(Direction::Vertical)
+                .constraints([Constraint::Length(4), Constraint::Min(0)])
+                .split(inner_area);
+            let lencana = Paragraph::new(Line::styled(
+                teks.as_str(),
+                Style::default().fg(get_theme_color("red", theme)).bold(),
+            ))
+            .alignment(Alignment::Center)
+            .wrap(Wrap { trim: true })
+            .block(
+                Block::bordered()
+                    .title(" ⚠ Peringatan ")
+                    .border_style(Style::default().fg(get_theme_color("red", theme))),
+            );
+            f.render_widget(lencana, chunks[0]);
+            chunks[1]
+        }
+        None => inner_area,
+    };
 
-    let result = Paragraph::new(Text::from(lines))
-        .alignment(Alignment::Center)
-        .wrap(Wrap { trim: true });
+    // Grafik ditampilkan di sebelah kanan langkah penyelesaian,
+    // garis bilangan di bawahnya
+    match (&result.grafik, &result.garis_bilangan) {
+        (Some(grafik), _) => {
+            let chunks = Layout::default()
+                .direction(Direction::Horizontal)
+                .constraints([Constraint::Percentage(50), Constraint::Percentage(50)])
+                .split(inner_area);
+            f.render_widget(result_paragraph, chunks[0]);
+            render_grafik(f, chunks[1], grafik, theme);
+        }
+        (None, Some(garis_bilangan)) => {
+            let chunks = Layout::default()
+                .direction(Direction::Vertical)
+                .constraints([Constraint::Min(0), Constraint::Length(5)])
+                .split(inner_area);
+            f.render_widget(result_paragraph, chunks[0]);
+            render_garis_bilangan(f, chunks[1], garis_bilangan, result.tampil_desimal, theme);
+        }
+        (None, None) => f.render_widget(result_paragraph, inner_area),
+    }
+}
+
+// Keterangan himpunan penyelesaian: "x = v" untuk satu titik,
+// notasi selang untuk bentuk lainnya
+fn keterangan_garis_bilangan(gb: &GarisBilangan, desimal: bool) -> String {
+    let nilai = |b: &Batas| {
+        if desimal {
+            b.nilai.desimal()
+        } else {
+            b.nilai.pecahan()
+        }
+    };
+    match (gb.titik.as_slice(), gb.selang.as_slice()) {
+        ([], []) => "Tidak ada penyelesaian".to_string(),
+        ([], [(None, None)]) => "Semua bilangan real".to_string(),
+        ([b], []) => format!("x = {}", nilai(b)),
+        (titik, selang) => {
+            let mut bagian: Vec<String> = selang
+                .iter()
+                .map(|(bawah, atas)| {
+                    let kiri = match bawah {
+                        Some(b) => format!("{}{}", if b.tertutup { "[" } else { "(" }, nilai(b)),
+                        None => "(−∞".to_string(),
+                    };
+                    let kanan = match atas {
+                        Some(b) => format!("{}{}", nilai(b), if b.tertutup { "]" } else { ")" }),
+                        None => "∞)".to_string(),
+                    };
+                    format!("{}, {}", kiri, kanan)
+                })
+                .collect();
+            if !titik.is_empty() {
+                let isi: Vec<String> = titik.iter().map(nilai).collect();
+                bagian.push(format!("{{{}}}", isi.join(", ")));
+            }
+            format!("x ∈ {}", bagian.join(" ∪ "))
+        }
+    }
+}
+
+// Garis bilangan satu variabel dari karakter: tanda skala di bawah garis,
+// nilai ujung di atasnya, titik tertutup (●) atau terbuka (○), dan
+// selang penyelesaian yang diarsir
+fn render_garis_bilangan(
+    f: &mut Frame,
+    area: Rect,
+    gb: &GarisBilangan,
+    desimal: bool,
+    theme: &str,
+) {
+    let warna = if gb.titik.is_empty() && gb.selang.is_empty() {
+        "red"
+    } else {
+        "green"
+    };
+    let block = Block::bordered()
+        .title(Line::styled(
+            format!(" {} ", keterangan_garis_bilangan(gb, desimal)),
+            Style::default().fg(get_theme_color(warna, theme)).bold(),
+        ))
+        .border_style(Style::default().fg(get_theme_color("blue", theme)));
+    let inner = block.inner(area);
+    f.render_widget(block, area);
+    let lebar = usize::from(inner.width);
+    if lebar < 3 || inner.height < 3 {
+        return;
+    }
+
+    let (min, maks, langkah) = gb.skala(lebar / 8);
+    let kolom = |v: f64| (((v - min) / (maks - min)) * (lebar - 1) as f64).round() as usize;
+    let abu = Style::default().fg(get_theme_color("gray", theme));
+    let sorot = Style::default().fg(get_theme_color(warna, theme)).bold();
+
+    let mut garis: Vec<(char, Style)> = vec![('─', abu); lebar];
+    let mut bawah = vec![' '; lebar];
+    let mut atas = vec![' '; lebar];
+    let tulis = |baris: &mut Vec<char>, tengah: usize, teks: &str, dari: usize| -> Option<usize> {
+        let n = teks.chars().count();
+        let mulai = tengah.saturating_sub(n / 2).min(lebar.saturating_sub(n));
+        if mulai < dari || mulai + n > lebar {
+            return None;
+        }
+        for (i, c) in teks.chars().enumerate() {
+            baris[mulai + i] = c;
+        }
+        Some(mulai + n + 1)
+    };
+
+    // Tanda skala; label yang bertumpuk dilewati
+    let desimal_skala = (-langkah.log10().floor()).max(0.0) as usize;
+    let mut bebas = 0;
+    let mut k = (min / langkah - 1e-9).ceil();
+    while k * langkah <= maks + langkah * 1e-9 {
+        let v = k * langkah;
+        let c = kolom(v);
+        garis[c] = ('┼', abu);
+        let teks = format!("{:.*}", desimal_skala, v);
+        let teks = if teks
+            .trim_start_matches('-')
+            .trim_matches(['0', '.'])
+            .is_empty()
+        {
+            "0".to_string()
+        } else {
+            teks
+        };
+        if let Some(akhir) = tulis(&mut bawah, c, &teks, bebas) {
+            bebas = akhir;
+        }
+        k += 1.0;
+    }
+
+    // Selang yang diarsir beserta panah untuk ujung tak hingga
+    for (b, a) in &gb.selang {
+        let c0 = b.map_or(0, |b| kolom(b.nilai.ke_f64()));
+        let c1 = a.map_or(lebar - 1, |a| kolom(a.nilai.ke_f64()));
+        for sel in &mut garis[c0..=c1] {
+            *sel = ('━', sorot);
+        }
+        if b.is_none() {
+            garis[0] = ('◀', sorot);
+        }
+        if a.is_none() {
+            garis[lebar - 1] = ('▶', sorot);
+        }
+    }
+
+    // Titik ujung dan titik tunggal beserta nilainya
+    let mut bebas = 0;
+    let mut ujung: Vec<&Batas> = gb
+        .selang
+        .iter()
+        .flat_map(|(b, a)| b.iter().chain(a.iter()))
+        .chain(gb.titik.iter())
+        .collect();
+    ujung.sort_by(|p, q| p.nilai.ke_f64().total_cmp(&q.nilai.ke_f64()));
+    for b in ujung {
+        let c = kolom(b.nilai.ke_f64());
+        garis[c] = (if b.tertutup { '●' } else { '○' }, sorot);
+        let teks = if desimal {
+            b.nilai.desimal()
+        } else {
+            b.nilai.pecahan()
+        };
+        if let Some(akhir) = tulis(&mut atas, c, &teks, bebas) {
+            bebas = akhir;
+        }
+    }
+
+    let baris = vec![
+        Line::styled(atas.into_iter().collect::<String>(), sorot),
+        Line::from(
+            garis
+                .into_iter()
+                .map(|(c, gaya)| Span::styled(c.to_string(), gaya))
+                .collect::<Vec<_>>(),
+        ),
+        Line::styled(bawah.into_iter().collect::<String>(), abu),
+    ];
+    f.render_widget(Paragraph::new(baris), inner);
+}
+
+// Angka pada label grafik: paling banyak dua angka di belakang koma
+fn label_angka(v: f64) -> String {
+    let s = format!("{:.2}", v);
+    let s = s.trim_end_matches('0').trim_end_matches('.');
+    if s == "-0" {
+        "0".to_string()
+    } else {
+        s.to_string()
+    }
+}
+
+// Grafik pada layar hasil: sumbu koordinat, lalu garis SPLDV beserta titik
+// potongnya, daerah penyelesaian pertidaksamaan, parabola persamaan kuadrat, atau
+// kedua kurva SPLKDV.
+// Garis sejajar digambar dengan dua warna, garis berimpit dengan satu warna.
+fn render_grafik(f: &mut Frame, area: Rect, grafik: &Grafik, theme: &str) {
+    let (keterangan, warna) = if let Some(daerah) = &grafik.daerah {
+        (
+            daerah.jenis.nama(),
+            if daerah.jenis == JenisDaerah::Kosong {
+                "red"
+            } else {
+                "green"
+            },
+        )
+    } else if let Some(perpotongan) = grafik.perpotongan {
+        (
+            perpotongan.nama(),
+            match perpotongan {
+                Perpotongan::TidakAda => "red",
+                Perpotongan::Berimpit => "orange",
+                _ => "green",
+            },
+        )
+    } else if !grafik.data.is_empty() {
+        ("Titik data dan garis regresi", "green")
+    } else if let Some(parabola) = grafik.kurva.first() {
+        match grafik.titik.len() {
+            _ if parabola.puncak().is_none() => ("Garis lurus (a = 0)", "orange"),
+            0 => ("Tidak memotong sumbu x", "red"),
+            1 => ("Menyinggung sumbu x", "orange"),
+            _ => ("Memotong sumbu x di dua titik", "green"),
+        }
+    } else {
+        match (grafik.jenis, grafik.garis.len()) {
+            (JenisSolusi::Tunggal, _) => ("Berpotongan di satu titik", "green"),
+            (JenisSolusi::TidakAda, 2) => ("Sejajar: tidak berpotongan", "red"),
+            (JenisSolusi::TidakAda, _) => ("Tidak ada titik yang memenuhi", "red"),
+            (JenisSolusi::TakHingga, 2) => ("Berimpit: setiap titik garis adalah solusi", "orange"),
+            (JenisSolusi::TakHingga, 1) => ("Setiap titik pada garis adalah solusi", "orange"),
+            (JenisSolusi::TakHingga, _) => ("Seluruh bidang adalah solusi", "orange"),
+        }
+    };
+    let instructions = Line::from(vec![
+        Span::styled(
+            "[WASD] ",
+            Style::default().fg(get_theme_color("blue", theme)).bold(),
+        ),
+        Span::styled(
+            "Geser ",
+            Style::default().fg(get_theme_color("fg", theme)).bold(),
+        ),
+        Span::styled(
+            "[+/-] ",
+            Style::default().fg(get_theme_color("blue", theme)).bold(),
+        ),
+        Span::styled(
+            "Zoom ",
+            Style::default().fg(get_theme_color("fg", theme)).bold(),
+        ),
+        Span::styled(
+            "[0] ",
+            Style::default().fg(get_theme_color("blue", theme)).bold(),
+        ),
+        Span::styled(
+            "Reset ",
+            Style::default().fg(get_theme_color("fg", theme)).bold(),
+        ),
+    ]);
+    let block = Block::bordered()
+        .title(Line::styled(
+            format!(" {} ", keterangan),
+            Style::default().fg(get_theme_color(warna, theme)).bold(),
+        ))
+        .title_bottom(instructions.centered())
+        .border_style(Style::default().fg(get_theme_color("blue", theme)));
+    let inner = block.inner(area);
+
+    // Satu sel terminal kira-kira dua kali lebih tinggi daripada lebarnya
+    let rasio = f64::from(inner.height) * 2.0 / f64::from(inner.width.max(1));
+    let (cx, cy) = grafik.pusat;
+    let bx = [cx - grafik.skala, cx + grafik.skala];
+    let by = [cy - grafik.skala * rasio, cy + grafik.skala * rasio];
+
+    let berimpit = grafik.daerah.is_none() && grafik.jenis == JenisSolusi::TakHingga;
+    let warna_garis: &[&str] = if berimpit {
+        &["orange", "orange"]
+    } else if grafik.daerah.is_some() {
+        &["blue", "yellow", "cyan", "orange", "green"]
+    } else {
+        &["blue", "green"]
+    };
+
+    let canvas = Canvas::default()
+        .block(block)
+        .marker(Marker::Braille)
+        .x_bounds(bx)
+        .y_bounds(by)
+        .paint(|ctx| {
+            // Arsiran daerah penyelesaian: satu titik braille setiap dua kolom dan dua baris
+            if let Some(daerah) = &grafik.daerah {
+                let (kolom, baris) = (inner.width * 2, inner.height * 4);
+                let mut arsir = Vec::new();
+                for i in (0..kolom).step_by(2) {
+                    for j in (0..baris).step_by(2) {
+                        let x = bx[0] + (bx[1] - bx[0]) * (f64::from(i) + 0.5) / f64::from(kolom);
+                        let y = by[0] + (by[1] - by[0]) * (f64::from(j) + 0.5) / f64::from(baris);
+                        if daerah.memuat(x, y) {
+                            arsir.push((x, y));
+                        }
+                    }
+                }
+                ctx.draw(&Points {
+                    coords: &arsir,
+                    color: get_theme_color("green", theme),
+                });
+                ctx.layer();
+            }
+
+            let abu = get_theme_color("gray", theme);
+            ctx.draw(&CanvasLine::new(bx[0], 0.0, bx[1], 0.0, abu));
+            ctx.draw(&CanvasLine::new(0.0, by[0], 0.0, by[1], abu));
+            ctx.print(
+                bx[0],
+                0.0,
+                Line::styled(label_angka(bx[0]), Style::default().fg(abu)),
+            );
+            ctx.print(
+                0.0,
+                by[0],
+                Line::styled(label_angka(by[0]), Style::default().fg(abu)),
+            );
+            ctx.print(
+                0.0,
+                by[1],
+                Line::styled(
+                    format!("y {}", label_angka(by[1])),
+                    Style::default().fg(abu),
+                ),
+            );
+            ctx.layer();
+
+            for (i, (p, garis)) in grafik.garis.iter().enumerate() {
+                let Some(((x1, y1), (x2, y2))) = Grafik::ujung(*garis, bx, by) else {
+                    continue;
+                };
+                let warna = get_theme_color(warna_garis[i % warna_garis.len()], theme);
+                // Garis batas pertidaksamaan < dan > digambar putus-putus
+                let putus = grafik.daerah.as_ref().is_some_and(|d| {
+                    let relasi = d.batasan[p - 1].1;
+                    relasi.tutup() != relasi
+                });
+                if putus {
+                    let n = 24;
+                    for k in (0..n).step_by(2) {
+                        let t0 = f64::from(k) / f64::from(n);
+                        let t1 = f64::from(k + 1) / f64::from(n);
+                        ctx.draw(&CanvasLine::new(
+                            x1 + (x2 - x1) * t0,
+                            y1 + (y2 - y1) * t0,
+                            x1 + (x2 - x1) * t1,
+                            y1 + (y2 - y1) * t1,
+                            warna,
+                        ));
+                    }
+                } else {
+                    ctx.draw(&CanvasLine::new(x1, y1, x2, y2, warna));
+                }
+                // Label persamaan di dekat ujung garis yang terlihat
+                let label = if berimpit && grafik.garis.len() == 2 {
+                    "P1 = P2".to_string()
+                } else if !grafik.data.is_empty() {
+                    "ŷ".to_string()
+                } else {
+                    format!("P{}", p)
+                };
+                let (lx, ly) = (x1 + (x2 - x1) * 0.85, y1 + (y2 - y1) * 0.85);
+                if (by[0]..=by[1]).contains(&ly)
+                    && (bx[0]..=bx[1]).contains(&lx)
+                    && (i == 0 || !berimpit)
+                {
+                    ctx.print(
+                        lx,
+                        ly,
+                        Line::styled(label, Style::default().fg(warna).bold()),
+                    );
+                }
+            }
+
+            // Kurva digambar per potongan kecil; potongan yang keluar tampilan dipotong
+            let n = u32::from(inner.width.max(1)) * 4;
+            let x_ke = |i: u32| bx[0] + (bx[1] - bx[0]) * f64::from(i) / f64::from(n);
+            for (i, kurva) in grafik.kurva.iter().enumerate() {
+                let warna = get_theme_color(
+                    if berimpit {
+                        "orange"
+                    } else {
+                        ["blue", "green"][i % 2]
+                    },
+                    theme,
+                );
+                for j in 0..n {
+                    let (x0, x1) = (x_ke(j), x_ke(j + 1));
+                    let (y0, y1) = (kurva.nilai(x0), kurva.nilai(x1));
+                    if (y0 < by[0] && y1 < by[0]) || (y0 > by[1] && y1 > by[1]) {
+                        continue;
+                    }
+                    ctx.draw(&CanvasLine::new(
+                        x0,
+                        y0.clamp(by[0], by[1]),
+                        x1,
+                        y1.clamp(by[0], by[1]),
+                        warna,
+                    ));
+                }
+                // Label persamaan SPLKDV di titik kurva terlihat paling kanan
+                if grafik.kurva.len() > 1 && (i == 0 || !berimpit) {
+                    let label = if berimpit {
+                        "P1 = P2".to_string()
+                    } else {
+                        format!("P{}", i + 1)
+                    };
+                    let ujung = (0..=n * 85 / 100)
+                        .rev()
+                        .map(|j| (x_ke(j), kurva.nilai(x_ke(j))))
+                        .find(|(_, y)| (by[0]..=by[1]).contains(y));
+                    if let Some((lx, ly)) = ujung {
+                        ctx.print(
+                            lx,
+                            ly,
+                            Line::styled(label, Style::default().fg(warna).bold()),
+                        );
+                    }
+                }
+            }
+
+            // Puncak parabola persamaan kuadrat; untuk akar kembar puncaknya sama
+            // dengan akarnya, cukup ditandai sekali
+            if let [parabola] = grafik.kurva.as_slice()
+                && let Some((x, y)) = parabola.puncak()
+                && grafik.titik.len() != 1
+                && (bx[0]..=bx[1]).contains(&x)
+                && (by[0]..=by[1]).contains(&y)
+            {
+                ctx.layer();
+                ctx.print(
+                    x,
+                    y,
+                    Line::styled(
+                        format!("◆ puncak ({}, {})", label_angka(x), label_angka(y)),
+                        Style::default().fg(get_theme_color("yellow", theme)).bold(),
+                    ),
+                );
+            }
+
+            // Titik pojok: ● termasuk daerah, ○ tidak termasuk
+            if let Some(daerah) = &grafik.daerah {
+                ctx.layer();
+                let merah = get_theme_color("red", theme);
+                for (i, ((x, y), termasuk)) in daerah.pojok.iter().enumerate() {
+                    let (x, y) = (x.ke_f64(), y.ke_f64());
+                    if !(bx[0]..=bx[1]).contains(&x) || !(by[0]..=by[1]).contains(&y) {
+                        continue;
+                    }
+                    ctx.print(
+                        x,
+                        y,
+                        Line::styled(
+                            format!(
+                                "{} {}({}, {})",
+                                if *termasuk { "●" } else { "○" },
+                                huruf(i),
+                                label_angka(x),
+                                label_angka(y)
+                            ),
+                            Style::default().fg(merah).bold(),
+                        ),
+                    );
+                }
+            }
+
+            // Titik data regresi
+            if !grafik.data.is_empty() {
+                ctx.layer();
+                let kuning = get_theme_color("yellow", theme);
+                for &(x, y) in &grafik.data {
+                    if (bx[0]..=bx[1]).contains(&x) && (by[0]..=by[1]).contains(&y) {
+                        ctx.print(x, y, Line::styled("•", Style::default().fg(kuning).bold()));
+                    }
+                }
+            }
+
+            if !grafik.titik.is_empty() {
+                ctx.layer();
+            }
+            let merah = get_theme_color("red", theme);
+            for &(x, y) in &grafik.titik {
+                if !(bx[0]..=bx[1]).contains(&x) || !(by[0]..=by[1]).contains(&y) {
+                    continue;
+                }
+                ctx.draw(&Points {
+                    coords: &[(x, y)],
+                    color: merah,
+                });
+                ctx.print(
+                    x,
+                    y,
+                    Line::styled(
+                        format!("● ({}, {})", label_angka(x), label_angka(y)),
+                        Style::default().fg(merah).bold(),
+                    ),
+                );
+            }
+        });
+    f.render_widget(canvas, area);
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::solver::format::Teks;
+    use crate::solver::matriks::{diperbesar, tulis_sel};
+    use crate::solver::pecahan::Pecahan;
+    use ratatui::{Terminal, backend::TestBackend};
+
+    const P: Tampilan = Tampilan::Pecahan;
+
+    // Garis bilangan selebar `lebar` kolom sebagai baris-baris teks:
+    // judul, nilai ujung, garis, tanda skala, dan bingkai bawah
+    fn gambar(gb: &GarisBilangan, lebar: u16) -> Vec<String> {
+        let mut terminal = Terminal::new(TestBackend::new(lebar, 5)).unwrap();
+        terminal
+            .draw(|f| render_garis_bilangan(f, f.area(), gb, false, "dark"))
+            .unwrap();
+        let buf = terminal.backend().buffer();
+        (0..buf.area.height)
+            .map(|y| (0..buf.area.width).map(|x| buf[(x, y)].symbol()).collect())
+            .collect()
+    }
+
+    #[test]
+    fn titik_tunggal() {
+        let baris = gambar(&GarisBilangan::titik(Pecahan::from(3)), 42);
+        assert!(baris[0].contains("x = 3"));
+        assert_eq!(baris[2].matches('●').count(), 1);
+        assert!(baris[1].contains('3'));
+        // Tanda skala −1 sampai 4
+        assert!(baris[3].contains("-1") && baris[3].contains('4'));
+    }
 
-    f.render_widget(result, inner_area);
+    #[test]
+    fn semua_bilangan_real() {
+        let baris = gambar(&GarisBilangan::semua(), 42);
+        assert!(baris[0].contains("Semua bilangan real"));
+        let garis: Vec<char> = baris[2].chars().collect();
+        assert_eq!(garis[1], '◀');
+        assert_eq!(garis[garis.len() - 2], '▶');
+        assert!(garis[2..garis.len() - 2].iter().all(|&c| c == '━'));
+    }
+
+    #[test]
+    fn tanpa_penyelesaian() {
+        let baris = gambar(&GarisBilangan::kosong(), 42);
+        assert!(baris[0].contains("Tidak ada penyelesaian"));
+        let garis: Vec<char> = baris[2].chars().collect();
+        assert!(
+            garis[1..garis.len() - 1]
+                .iter()
+                .all(|&c| c == '─' || c == '┼')
+        );
+        assert!(baris[1].trim_matches('│').trim().is_empty());
+    }
+
+    #[test]
+    fn area_terlalu_kecil_hanya_bingkai() {
+        let baris = gambar(&GarisBilangan::titik(Pecahan::from(1)), 4);
+        assert!(!baris.concat().contains('●'));
+    }
+
+    #[test]
+    fn konfirmasi_menampilkan_bentuk_baku() {
+        let baris = vec!["2x + 3y = 7".to_string(), "y = x - 1".to_string()];
+        let mut terminal = Terminal::new(TestBackend::new(60, 24)).unwrap();
+        terminal
+            .draw(|f| {
+                render_konfirmasi_teks_ui(f, f.area(), &baris, &Vec::new(), &Line::raw(""), "dark")
+            })
+            .unwrap();
+        let buf = terminal.backend().buffer();
+        let layar: String = (0..buf.area.height)
+            .map(|y| {
+                (0..buf.area.width)
+                    .map(|x| buf[(x, y)].symbol())
+                    .collect::<String>()
+                    + "\n"
+            })
+            .collect();
+        assert!(layar.contains("P1: 2x + 3y = 7"));
+        assert!(layar.contains("P2: -x + y = -1"));
+        assert!(layar.contains("Variabel: x, y"));
+        assert!(layar.contains("⎡  2  3 │  7 ⎤"));
+        assert!(layar.contains("⎣ -1  1 │ -1 ⎦"));
+    }
+
+    // Isi buffer sebagai baris-baris teks tanpa spasi di ujung kanan
+    fn baris_buffer(buf: &Buffer) -> Vec<String> {
+        (0..buf.area.height)
+            .map(|y| {
+                let baris: String = (0..buf.area.width).map(|x| buf[(x, y)].symbol()).collect();
+                baris.trim_end().to_string()
+            })
+            .collect()
+    }
+
+    #[test]
+    fn widget_matriks_merata_kanan_dan_berkurung() {
+        let m = vec![
+            vec![Pecahan::from(1), Pecahan::from(-2), Pecahan::from(3)],
+            vec![Pecahan::from(10), Pecahan::from(4), Pecahan::from(-5)],
+        ];
+        let gambar = GambarMatriks {
+            kiri: "A = ".to_string(),
+            ..diperbesar(&m, P)
+        };
+        let widget = WidgetMatriks {
+            gambar: &gambar,
+            gaya: Style::default(),
+            warna_kurung: Color::Cyan,
+        };
+        assert_eq!((widget.lebar(), widget.tinggi()), (19, 2));
+        let mut buf = Buffer::empty(Rect::new(0, 0, 24, 2));
+        widget.render(buf.area, &mut buf);
+        assert_eq!(
+            baris_buffer(&buf),
+            vec!["A = ⎡  1  -2 │  3 ⎤", "    ⎣ 10   4 │ -5 ⎦"]
+        );
+        assert_eq!(buf[(4, 0)].fg, Color::Cyan);
+        assert_eq!(buf[(13, 1)].fg, Color::Cyan);
+        assert_eq!(buf[(7, 0)].fg, Color::Reset);
+    }
+
+    #[test]
+    fn widget_matriks_sarrus_dan_keterangan() {
+        let sel = vec![
+            vec!["1".to_string(), "2".to_string()],
+            vec!["3".to_string(), "4".to_string()],
+            vec!["5".to_string(), "6".to_string()],
+        ];
+        let gambar = GambarMatriks {
+            tambahan: sel.clone(),
+            ..tulis_sel("D = ", &sel, Kurung::Tegak, " = 0")
+        };
+        let mut buf = Buffer::empty(Rect::new(0, 0, 24, 3));
+        WidgetMatriks {
+            gambar: &gambar,
+            gaya: Style::default(),
+            warna_kurung: Color::Cyan,
+        }
+        .render(buf.area, &mut buf);
+        assert_eq!(
+            baris_buffer(&buf),
+            vec![
+                "    │ 1  2 │ 1  2",
+                "D = │ 3  4 │ 3  4 = 0",
+                "    │ 5  6 │ 5  6"
+            ]
+        );
+    }
+
+    #[test]
+    fn langkah_menggulir_teks_dan_matriks() {
+        let mut teks = Teks::from("Matriks:\n");
+        teks.gambar(|_| tulis_sel("", &vec![vec!["1".to_string()]; 3], Kurung::Siku, ""));
+        teks.push_str("Selesai\n");
+        let langkah = |scroll| {
+            let mut buf = Buffer::empty(Rect::new(0, 0, 12, 3));
+            WidgetLangkah {
+                judul: Line::raw("Hasil:"),
+                isi: &teks.pecahan,
+                scroll,
+                gaya: Style::default(),
+                warna_kurung: Color::Cyan,
+            }
+            .render(buf.area, &mut buf);
+            baris_buffer(&buf)
+        };
+        assert_eq!(langkah(0), vec!["   Hasil:", "  Matriks:", "   ⎡ 1 ⎤"]);
+        assert_eq!(langkah(3), vec!["   ⎢ 1 ⎥", "   ⎣ 1 ⎦", "   Selesai"]);
+    }
 }